@@ -11,7 +11,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .file_descriptor_set_path(&attestor_descriptor_path)
         .build_server(true)
         .compile_protos(
-            &["../../proto/ibc_attestor/ibc_attestor.proto"],
+            &[
+                "../../proto/ibc_attestor/ibc_attestor.proto",
+                "../../proto/ibc_attestor/errors.proto",
+                "../../proto/ibc_attestor/admin.proto",
+            ],
             &["../../proto"],
         )?;
 
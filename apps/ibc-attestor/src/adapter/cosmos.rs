@@ -1,21 +1,63 @@
+use alloy_primitives::Bytes;
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::{Packet, Payload};
 use ibc_eureka_utils::rpc::TendermintRpcExt;
+use ibc_proto_eureka::ibc::core::channel::v2::Packet as ProtoPacket;
+use prost::Message;
 use serde::Deserialize;
-use tendermint::block::Height;
-use tendermint_rpc::{Client, HttpClient, Url};
+use tendermint::{Hash, abci::Event, block::Height, hash::Algorithm, node::info::TxIndexStatus};
+use tendermint_rpc::{Client, HttpClient, Order, Url, query::Query};
+use tokio::sync::OnceCell;
 use tracing::{debug, error, info};
 
 use crate::{
     adapter::{
-        AdapterBuilder, AttestationAdapter, AttestationAdapterError, retry::with_retry_backoff,
+        AdapterBuilder, AttestationAdapter, AttestationAdapterError, ChainInfo, ConsensusHeader,
+        PacketEvent, PacketEventKind, TxPacketEvents, retry::with_retry_backoff,
     },
     rpc::api::CommitmentType,
 };
 
+/// Event emitted by the IBC v2 core module when a packet is sent
+const SEND_PACKET_EVENT: &str = "send_packet";
+/// Event emitted by the IBC v2 core module when an acknowledgement is written
+const WRITE_ACK_EVENT: &str = "write_acknowledgement";
+/// Event attribute holding the packet source client ID
+const SOURCE_CLIENT_ATTRIBUTE: &str = "packet_source_client";
+/// Event attribute holding the packet destination client ID
+const DEST_CLIENT_ATTRIBUTE: &str = "packet_dest_client";
+/// Event attribute holding the packet sequence
+const SEQUENCE_ATTRIBUTE: &str = "packet_sequence";
+/// Event attribute holding the hex encoded protobuf packet
+const ENCODED_PACKET_ATTRIBUTE: &str = "encoded_packet_hex";
+/// Number of transactions requested per `tx_search` page
+const TX_SEARCH_PAGE_SIZE: u8 = 100;
+
 /// Configuration for the Cosmos blockchain client adapter.
 #[derive(Clone, Debug, Deserialize)]
 pub struct CosmosAdapterConfig {
     /// The URL of the Tendermint RPC endpoint.
     pub url: Url,
+
+    /// Store key prefixes that may be attested. Any other key is rejected,
+    /// so the attestor can't be used to sign arbitrary data.
+    #[serde(default)]
+    pub store_allowlist: Vec<AllowedStorePrefix>,
+}
+
+/// Store key prefix permitted by [`CosmosAdapterConfig::store_allowlist`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct AllowedStorePrefix {
+    /// Module store name, e.g. `bank`
+    pub store: String,
+    /// Hex-encoded key prefix, e.g. `0x00` for bank supplies
+    pub prefix: Bytes,
+}
+
+impl AllowedStorePrefix {
+    /// Whether this entry permits reading `key` of `store`.
+    fn permits(&self, store: &str, key: &[u8]) -> bool {
+        self.store == store && key.starts_with(&self.prefix)
+    }
 }
 
 /// Builder for creating Cosmos adapter instances
@@ -32,6 +74,7 @@ impl AdapterBuilder for CosmosAdapterBuilder {
     fn build(config: Self::Config) -> Result<Self::Adapter, AttestationAdapterError> {
         info!(
             rpcUrl = %config.url,
+            storeAllowlist = config.store_allowlist.len(),
             "initializing Cosmos adapter"
         );
 
@@ -48,7 +91,11 @@ impl AdapterBuilder for CosmosAdapterBuilder {
 
         info!("Cosmos adapter initialized successfully");
 
-        Ok(CosmosAdapter { client })
+        Ok(CosmosAdapter {
+            client,
+            store_allowlist: config.store_allowlist,
+            tx_index: OnceCell::new(),
+        })
     }
 }
 
@@ -56,9 +103,44 @@ impl AdapterBuilder for CosmosAdapterBuilder {
 #[derive(Debug)]
 pub struct CosmosAdapter {
     client: HttpClient,
+    store_allowlist: Vec<AllowedStorePrefix>,
+    tx_index: OnceCell<TxIndexStatus>,
 }
 
 impl CosmosAdapter {
+    /// Fail unless the node indexes transactions. Event searches rely on the
+    /// indexer, and a node running with `indexer = "null"` must not pass for
+    /// a chain without packets.
+    async fn ensure_tx_index(&self, operation: &str) -> Result<(), AttestationAdapterError> {
+        let tx_index = self
+            .tx_index
+            .get_or_try_init(|| {
+                with_retry_backoff("cosmos.ensure_tx_index.status", || async {
+                    self.client
+                        .status()
+                        .await
+                        .map(|status| status.node_info.other.tx_index)
+                        .map_err(|err| {
+                            // error log emitted by retry module
+                            debug!(error = %err, "failed to fetch node status from Cosmos chain");
+                            AttestationAdapterError::RetrievalError(err.to_string())
+                        })
+                })
+            })
+            .await?;
+
+        if *tx_index == TxIndexStatus::Off {
+            error!(
+                operation,
+                "transaction indexing is disabled on the Cosmos node"
+            );
+            return Err(AttestationAdapterError::Unsupported(format!(
+                "{operation}: transaction indexing is disabled on the node"
+            )));
+        }
+        Ok(())
+    }
+
     async fn get_packet_commitment(
         &self,
         client_id: String,
@@ -171,6 +253,163 @@ impl CosmosAdapter {
             Ok(None)
         }
     }
+
+    /// Page through the transactions in `from_height..=to_height` that emitted
+    /// an `event_kind` event whose `client_attribute` is `client_id`, and
+    /// decode the packets of the matching events.
+    async fn search_packet_events(
+        &self,
+        operation: &'static str,
+        event_kind: &str,
+        client_attribute: &str,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        self.ensure_tx_index(operation).await?;
+
+        let query = Query::eq(format!("{event_kind}.{client_attribute}"), client_id)
+            .and_gte("tx.height", from_height)
+            .and_lte("tx.height", to_height);
+
+        let mut packets = Vec::new();
+        let mut scanned = 0;
+        let mut page = 1;
+        loop {
+            let response = with_retry_backoff(operation, || {
+                let query = query.clone();
+                async move {
+                    self.client
+                        .tx_search(query, false, page, TX_SEARCH_PAGE_SIZE, Order::Ascending)
+                        .await
+                        .map_err(|err| {
+                            // error log emitted by retry module
+                            debug!(error = %err, "failed to search transactions on Cosmos chain");
+                            AttestationAdapterError::RetrievalError(err.to_string())
+                        })
+                }
+            })
+            .await?;
+
+            for tx in &response.txs {
+                for event in &tx.tx_result.events {
+                    // A transaction may carry events of other clients
+                    let matches_client = event.attributes.iter().any(|attribute| {
+                        attribute.key_str().is_ok_and(|key| key == client_attribute)
+                            && attribute.value_str().is_ok_and(|value| value == client_id)
+                    });
+                    if matches_client && let Some(packet) = decode_packet_event(event, event_kind)?
+                    {
+                        packets.push(packet);
+                    }
+                }
+            }
+
+            scanned += response.txs.len();
+            if response.txs.is_empty()
+                || u32::try_from(scanned).is_ok_and(|scanned| scanned >= response.total_count)
+            {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(packets)
+    }
+}
+
+/// Decode the packet carried by an IBC v2 packet event of kind `event_kind`.
+///
+/// Note: Returns Ok(None) if the event is of a different kind.
+fn decode_packet_event(
+    event: &Event,
+    event_kind: &str,
+) -> Result<Option<Packet>, AttestationAdapterError> {
+    if event.kind != event_kind {
+        return Ok(None);
+    }
+
+    let encoded = event
+        .attributes
+        .iter()
+        .find(|attribute| {
+            attribute
+                .key_str()
+                .is_ok_and(|key| key == ENCODED_PACKET_ATTRIBUTE)
+        })
+        .and_then(|attribute| attribute.value_str().ok())
+        .ok_or_else(|| {
+            error!(
+                eventKind = event_kind,
+                "packet event is missing encoded packet"
+            );
+            AttestationAdapterError::MalformedEvent(format!(
+                "`{event_kind}` event is missing `{ENCODED_PACKET_ATTRIBUTE}` attribute"
+            ))
+        })?;
+
+    let bytes = hex::decode(encoded).map_err(|err| {
+        error!(error = %err, "encoded packet is not valid hex");
+        AttestationAdapterError::MalformedEvent(format!("Malformed encoded packet: {err}"))
+    })?;
+    let packet = ProtoPacket::decode(bytes.as_slice()).map_err(|err| {
+        error!(error = %err, "encoded packet is not a valid protobuf packet");
+        AttestationAdapterError::MalformedEvent(format!("Malformed encoded packet: {err}"))
+    })?;
+
+    Ok(Some(packet_from_proto(packet)))
+}
+
+/// Decode all IBC v2 packet events emitted by a transaction.
+fn decode_tx_packet_events(events: &[Event]) -> Result<Vec<PacketEvent>, AttestationAdapterError> {
+    let mut packet_events = Vec::new();
+    for event in events {
+        if let Some(packet) = decode_packet_event(event, SEND_PACKET_EVENT)? {
+            packet_events.push(PacketEvent {
+                kind: PacketEventKind::Send,
+                packet,
+            });
+        } else if let Some(packet) = decode_packet_event(event, WRITE_ACK_EVENT)? {
+            packet_events.push(PacketEvent {
+                kind: PacketEventKind::WriteAck,
+                packet,
+            });
+        }
+    }
+    Ok(packet_events)
+}
+
+/// Convert a protobuf IBC v2 packet into its ABI representation.
+fn packet_from_proto(packet: ProtoPacket) -> Packet {
+    Packet {
+        sequence: packet.sequence,
+        sourceClient: packet.source_client,
+        destClient: packet.destination_client,
+        timeoutTimestamp: packet.timeout_timestamp,
+        payloads: packet
+            .payloads
+            .into_iter()
+            .map(|payload| Payload {
+                sourcePort: payload.source_port,
+                destPort: payload.destination_port,
+                version: payload.version,
+                encoding: payload.encoding,
+                value: payload.value.into(),
+            })
+            .collect(),
+    }
+}
+
+/// Convert a header hash field into 32 bytes, failing on empty or non
+/// SHA-256 hashes.
+fn hash_bytes(field: &str, bytes: &[u8]) -> Result<[u8; 32], AttestationAdapterError> {
+    bytes.try_into().map_err(|_| {
+        error!(field, len = bytes.len(), "unexpected header hash length");
+        AttestationAdapterError::RetrievalError(format!(
+            "expected 32-byte {field}, got {} bytes",
+            bytes.len()
+        ))
+    })
 }
 
 #[async_trait::async_trait]
@@ -221,6 +460,109 @@ impl AttestationAdapter for CosmosAdapter {
         Ok(timestamp)
     }
 
+    #[tracing::instrument(skip(self), fields(chain = "cosmos", height))]
+    async fn get_block_header(
+        &self,
+        height: u64,
+    ) -> Result<ConsensusHeader, AttestationAdapterError> {
+        debug!("fetching block header from Cosmos chain");
+
+        let tm_height = Height::try_from(height).map_err(|_| {
+            error!("invalid height for Cosmos chain");
+            AttestationAdapterError::InvalidHeight
+        })?;
+
+        let block = with_retry_backoff("cosmos.get_block_header.commit", || async {
+            self.client.commit(tm_height).await.map_err(|err| {
+                // error log emitted by retry module
+                debug!(error = %err, "failed to fetch block from Cosmos chain");
+                AttestationAdapterError::RetrievalError(err.to_string())
+            })
+        })
+        .await?;
+
+        let header = &block.signed_header.header;
+        let timestamp = u64::try_from(header.time.unix_timestamp()).map_err(|err| {
+            error!(error = %err, "failed to convert timestamp to u64");
+            AttestationAdapterError::RetrievalError(err.to_string())
+        })?;
+
+        // The app hash is the state after the previous block, which is what
+        // ICS-23 proofs queried at `height - 1` verify against
+        let header = ConsensusHeader {
+            height,
+            timestamp,
+            block_hash: hash_bytes("block hash", header.hash().as_bytes())?,
+            root: hash_bytes("app hash", header.app_hash.as_bytes())?,
+            validators_hash: Some(hash_bytes(
+                "validators hash",
+                header.validators_hash.as_bytes(),
+            )?),
+        };
+        debug!(
+            blockHash = %hex::encode(header.block_hash),
+            appHash = %hex::encode(header.root),
+            "retrieved block header"
+        );
+        Ok(header)
+    }
+
+    #[tracing::instrument(skip(self, key), fields(chain = "cosmos", height, store, key = %hex::encode(key)))]
+    async fn get_store_value(
+        &self,
+        height: u64,
+        store: &str,
+        key: &[u8],
+    ) -> Result<Vec<u8>, AttestationAdapterError> {
+        if !self
+            .store_allowlist
+            .iter()
+            .any(|allowed| allowed.permits(store, key))
+        {
+            error!("store key is not allowlisted");
+            return Err(AttestationAdapterError::NotAllowed(format!(
+                "key {} of store {store}",
+                hex::encode(key)
+            )));
+        }
+
+        debug!("querying store key from Cosmos chain");
+
+        let tm_height = Height::try_from(height).map_err(|_| {
+            error!("invalid height for Cosmos chain");
+            AttestationAdapterError::InvalidHeight
+        })?;
+        let path = format!("store/{store}/key");
+
+        let response = with_retry_backoff("cosmos.get_store_value.abci_query", || async {
+            self.client
+                .abci_query(Some(path.clone()), key, Some(tm_height), false)
+                .await
+                .map_err(|err| {
+                    // error log emitted by retry module
+                    debug!(error = %err, "failed to query store key from Cosmos chain");
+                    AttestationAdapterError::RetrievalError(err.to_string())
+                })
+        })
+        .await?;
+
+        if response.code.is_err() {
+            error!(
+                code = response.code.value(),
+                log = %response.log,
+                "store query failed"
+            );
+            return Err(AttestationAdapterError::RetrievalError(format!(
+                "store query failed with code {}: {}",
+                response.code.value(),
+                response.log
+            )));
+        }
+
+        debug!(value = %hex::encode(&response.value), "store key retrieved");
+        Ok(response.value)
+    }
+
     #[tracing::instrument(
         skip(self, _commitment_path),
         fields(chain = "cosmos", clientId = %client_id, height, sequence, commitmentType = ?commitment_type)
@@ -265,4 +607,316 @@ impl AttestationAdapter for CosmosAdapter {
         debug!("commitment retrieved successfully");
         Ok(Some(commitment))
     }
+
+    #[tracing::instrument(skip(self), fields(chain = "cosmos", clientId = %client_id, sequence, height))]
+    async fn find_send_packet(
+        &self,
+        client_id: &str,
+        sequence: u64,
+        height: u64,
+    ) -> Result<Option<Packet>, AttestationAdapterError> {
+        debug!("searching send packet events on Cosmos chain");
+
+        let query = Query::eq(
+            format!("{SEND_PACKET_EVENT}.{SOURCE_CLIENT_ATTRIBUTE}"),
+            client_id,
+        )
+        .and_eq(
+            format!("{SEND_PACKET_EVENT}.{SEQUENCE_ATTRIBUTE}"),
+            sequence.to_string(),
+        )
+        .and_lte("tx.height", height);
+
+        let response = with_retry_backoff("cosmos.find_send_packet.tx_search", || {
+            let query = query.clone();
+            async move {
+                self.client
+                    .tx_search(query, false, 1, 1, Order::Descending)
+                    .await
+                    .map_err(|err| {
+                        // error log emitted by retry module
+                        debug!(error = %err, "failed to search transactions on Cosmos chain");
+                        AttestationAdapterError::RetrievalError(err.to_string())
+                    })
+            }
+        })
+        .await?;
+
+        for tx in &response.txs {
+            for event in &tx.tx_result.events {
+                if let Some(packet) = decode_packet_event(event, SEND_PACKET_EVENT)?
+                    && packet.sourceClient == client_id
+                    && packet.sequence == sequence
+                {
+                    debug!(txHeight = tx.height.value(), "send packet event found");
+                    return Ok(Some(packet));
+                }
+            }
+        }
+
+        debug!("send packet event not found");
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "cosmos", txHash = %tx_hash))]
+    async fn get_tx_packet_events(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Option<TxPacketEvents>, AttestationAdapterError> {
+        debug!("fetching transaction from Cosmos chain");
+
+        let hash = Hash::from_hex_upper(
+            Algorithm::Sha256,
+            &tx_hash.trim_start_matches("0x").to_uppercase(),
+        )
+        .map_err(|err| {
+            error!(error = %err, "invalid Cosmos transaction hash");
+            AttestationAdapterError::InvalidTxHash(format!("{tx_hash}: {err}"))
+        })?;
+
+        self.ensure_tx_index("cosmos.get_tx_packet_events.tx_search")
+            .await?;
+
+        // The `tx` endpoint reports unknown hashes as a generic internal RPC
+        // error, while a search on the always indexed `tx.hash` returns no
+        // results, so a missing transaction is told apart without parsing
+        // error messages
+        let query = Query::eq("tx.hash", hash.to_string());
+        let response = with_retry_backoff("cosmos.get_tx_packet_events.tx_search", || {
+            let query = query.clone();
+            async move {
+                self.client
+                    .tx_search(query, false, 1, 1, Order::Ascending)
+                    .await
+                    .map_err(|err| {
+                        // error log emitted by retry module
+                        debug!(error = %err, "failed to fetch transaction from Cosmos chain");
+                        AttestationAdapterError::RetrievalError(err.to_string())
+                    })
+            }
+        })
+        .await?;
+
+        let Some(response) = response.txs.into_iter().next() else {
+            debug!("transaction not found");
+            return Ok(None);
+        };
+
+        let height = response.height.value();
+        let events = decode_tx_packet_events(&response.tx_result.events)?;
+
+        debug!(
+            txHeight = height,
+            numEvents = events.len(),
+            "decoded packet events"
+        );
+        Ok(Some(TxPacketEvents { height, events }))
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "cosmos", clientId = %client_id, fromHeight = from_height, toHeight = to_height))]
+    async fn get_send_packets(
+        &self,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        debug!("scanning send packet events on Cosmos chain");
+
+        let packets = self
+            .search_packet_events(
+                "cosmos.get_send_packets.tx_search",
+                SEND_PACKET_EVENT,
+                SOURCE_CLIENT_ATTRIBUTE,
+                client_id,
+                from_height,
+                to_height,
+            )
+            .await?;
+
+        debug!(numPackets = packets.len(), "scanned send packet events");
+        Ok(packets)
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "cosmos", clientId = %client_id, fromHeight = from_height, toHeight = to_height))]
+    async fn get_write_ack_packets(
+        &self,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        debug!("scanning write acknowledgement events on Cosmos chain");
+
+        let packets = self
+            .search_packet_events(
+                "cosmos.get_write_ack_packets.tx_search",
+                WRITE_ACK_EVENT,
+                DEST_CLIENT_ATTRIBUTE,
+                client_id,
+                from_height,
+                to_height,
+            )
+            .await?;
+
+        debug!(
+            numPackets = packets.len(),
+            "scanned write acknowledgement events"
+        );
+        Ok(packets)
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "cosmos"))]
+    async fn get_chain_info(&self) -> Result<ChainInfo, AttestationAdapterError> {
+        debug!("fetching node status from Cosmos chain");
+
+        let status = with_retry_backoff("cosmos.get_chain_info.status", || async {
+            self.client.status().await.map_err(|err| {
+                // error log emitted by retry module
+                debug!(error = %err, "failed to fetch node status from Cosmos chain");
+                AttestationAdapterError::RetrievalError(err.to_string())
+            })
+        })
+        .await?;
+
+        let chain_id = status.node_info.network.to_string();
+        debug!(chainId = %chain_id, "retrieved chain ID");
+        Ok(ChainInfo {
+            chain_id,
+            // IBC is a native module, there is no router contract
+            router: String::new(),
+            finality: "instant finality, latest committed block".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibc_proto_eureka::ibc::core::channel::v2::Payload as ProtoPayload;
+    use tendermint::abci::EventAttributeIndexExt;
+
+    #[test]
+    fn store_allowlist_permits_keys_under_prefix() {
+        let allowed = AllowedStorePrefix {
+            store: "bank".to_string(),
+            prefix: Bytes::from_static(&[0x00]),
+        };
+
+        assert!(allowed.permits("bank", b"\x00uatom"));
+        assert!(!allowed.permits("bank", b"\x02uatom"));
+        assert!(!allowed.permits("bank", b""));
+        assert!(!allowed.permits("tokenfactory", b"\x00uatom"));
+    }
+
+    #[test]
+    fn store_allowlist_is_parsed_from_config() {
+        let config: CosmosAdapterConfig = toml::from_str(
+            r#"
+            url = "http://localhost:26657"
+
+            [[store_allowlist]]
+            store = "bank"
+            prefix = "0x00"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.store_allowlist,
+            vec![AllowedStorePrefix {
+                store: "bank".to_string(),
+                prefix: Bytes::from_static(&[0x00]),
+            }]
+        );
+    }
+
+    #[test]
+    fn hash_bytes_requires_32_bytes() {
+        assert_eq!(hash_bytes("app hash", &[7; 32]).unwrap(), [7; 32]);
+        assert!(matches!(
+            hash_bytes("app hash", &[]),
+            Err(AttestationAdapterError::RetrievalError(_))
+        ));
+    }
+
+    fn proto_packet() -> ProtoPacket {
+        ProtoPacket {
+            sequence: 5,
+            source_client: "07-tendermint-0".to_string(),
+            destination_client: "attestations-0".to_string(),
+            timeout_timestamp: 1_700_000_600,
+            payloads: vec![ProtoPayload {
+                source_port: "transfer".to_string(),
+                destination_port: "transfer".to_string(),
+                version: "ics20-1".to_string(),
+                encoding: "application/x-solidity-abi".to_string(),
+                value: vec![1, 2, 3],
+            }],
+        }
+    }
+
+    fn packet_event(kind: &str, packet: &ProtoPacket) -> Event {
+        Event::new(
+            kind,
+            [(
+                ENCODED_PACKET_ATTRIBUTE,
+                hex::encode(packet.encode_to_vec()),
+            )
+                .no_index()],
+        )
+    }
+
+    #[test]
+    fn decode_packet_event_converts_proto_packet() {
+        let event = packet_event(SEND_PACKET_EVENT, &proto_packet());
+
+        let packet = decode_packet_event(&event, SEND_PACKET_EVENT)
+            .unwrap()
+            .expect("send packet event must decode");
+
+        assert_eq!(packet.sequence, 5);
+        assert_eq!(packet.sourceClient, "07-tendermint-0");
+        assert_eq!(packet.destClient, "attestations-0");
+        assert_eq!(packet.timeoutTimestamp, 1_700_000_600);
+        assert_eq!(packet.payloads.len(), 1);
+        assert_eq!(packet.payloads[0].destPort, "transfer");
+        assert_eq!(packet.payloads[0].value.as_ref(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn decode_packet_event_skips_other_kinds() {
+        let event = packet_event("recv_packet", &proto_packet());
+        assert!(
+            decode_packet_event(&event, SEND_PACKET_EVENT)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn decode_packet_event_rejects_missing_packet() {
+        let event = Event::new(SEND_PACKET_EVENT, [("packet_sequence", "5").no_index()]);
+        assert!(matches!(
+            decode_packet_event(&event, SEND_PACKET_EVENT),
+            Err(AttestationAdapterError::MalformedEvent(_))
+        ));
+    }
+
+    #[test]
+    fn decode_tx_packet_events_collects_send_and_ack_events() {
+        let mut acked = proto_packet();
+        acked.sequence = 6;
+        let events = vec![
+            packet_event(SEND_PACKET_EVENT, &proto_packet()),
+            Event::new("message", [("module", "ibc").no_index()]),
+            packet_event(WRITE_ACK_EVENT, &acked),
+        ];
+
+        let decoded = decode_tx_packet_events(&events).unwrap();
+
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].kind, PacketEventKind::Send);
+        assert_eq!(decoded[0].packet.sequence, 5);
+        assert_eq!(decoded[1].kind, PacketEventKind::WriteAck);
+        assert_eq!(decoded[1].packet.sequence, 6);
+    }
 }
@@ -1,15 +1,22 @@
-use alloy::{consensus::BlockHeader, eips::BlockId};
-use alloy_primitives::{Address, keccak256};
+use alloy::{consensus::BlockHeader, eips::BlockId, sol_types::SolEvent};
+use alloy_primitives::{Address, B256, Selector, U256, keccak256};
 use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types_eth::{Filter, Log, TransactionInput, TransactionRequest};
+use std::str::FromStr;
 use tracing::{debug, error, info};
 
-use ibc_eureka_solidity_types::ics26::router::routerInstance;
+use ibc_eureka_solidity_types::ics26::{
+    IICS26RouterMsgs::Packet,
+    router::{SendPacket, WriteAcknowledgement, routerInstance},
+};
 use serde::Deserialize;
 use url::Url;
 
 use crate::{
     adapter::{
-        AdapterBuilder, AttestationAdapter, AttestationAdapterError, retry::with_retry_backoff,
+        AdapterBuilder, AttestationAdapter, AttestationAdapterError, ChainInfo, ConsensusHeader,
+        ContractRead, EventLog, PacketEvent, PacketEventKind, TxPacketEvents,
+        retry::with_retry_backoff,
     },
     rpc::api::CommitmentType,
 };
@@ -29,8 +36,73 @@ pub struct EvmAdapterConfig {
     /// Then we take `latest` block height and subtract the finality offset. If
     /// it's None then we use `finalized` block and its height.
     pub finality_offset: Option<u64>,
+
+    /// Number of blocks below the requested height that are searched when
+    /// looking up router events by client ID and sequence.
+    #[serde(default = "default_event_lookback_blocks")]
+    pub event_lookback_blocks: u64,
+
+    /// Contract state reads that may be attested. Any other read is
+    /// rejected, so the attestor can't be used to sign arbitrary data.
+    #[serde(default)]
+    pub contract_state_allowlist: Vec<AllowedContractRead>,
+
+    /// Contracts whose event logs may be attested.
+    #[serde(default)]
+    pub event_log_allowlist: Vec<Address>,
+}
+
+/// Contract state read permitted by [`EvmAdapterConfig::contract_state_allowlist`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AllowedContractRead {
+    /// View calls of `selector` on `address`, with any arguments
+    Call {
+        /// Called contract
+        address: Address,
+        /// Four-byte function selector
+        selector: Selector,
+    },
+    /// Reads of a single storage slot of `address`
+    Storage {
+        /// Contract owning the storage
+        address: Address,
+        /// Storage slot
+        slot: B256,
+    },
+}
+
+impl AllowedContractRead {
+    /// Whether this entry permits `read`.
+    fn permits(&self, read: &ContractRead) -> bool {
+        match (self, read) {
+            (
+                Self::Call { address, selector },
+                ContractRead::Call {
+                    address: target,
+                    calldata,
+                },
+            ) => address == target && calldata.starts_with(selector.as_slice()),
+            (
+                Self::Storage { address, slot },
+                ContractRead::Storage {
+                    address: target,
+                    slot: read_slot,
+                },
+            ) => address == target && slot == read_slot,
+            _ => false,
+        }
+    }
 }
 
+const fn default_event_lookback_blocks() -> u64 {
+    10_000
+}
+
+/// Maximum number of blocks requested in a single `eth_getLogs` call, as most
+/// RPC providers reject wider ranges.
+const MAX_LOG_RANGE_BLOCKS: u64 = 2_000;
+
 /// Builder for creating EVM adapter instances
 pub struct EvmAdapterBuilder;
 
@@ -47,6 +119,9 @@ impl AdapterBuilder for EvmAdapterBuilder {
             rpcUrl = %config.url,
             routerAddress = %config.router_address,
             finalityOffset = ?config.finality_offset,
+            eventLookbackBlocks = config.event_lookback_blocks,
+            contractStateAllowlist = config.contract_state_allowlist.len(),
+            eventLogAllowlist = config.event_log_allowlist.len(),
             "initializing EVM adapter"
         );
 
@@ -74,6 +149,82 @@ pub struct EvmAdapter {
     router: routerInstance<RootProvider>,
 }
 
+impl EvmAdapter {
+    /// Decode a router log into a packet event, skipping unrelated logs.
+    fn decode_packet_log(&self, log: &Log) -> Result<Option<PacketEvent>, AttestationAdapterError> {
+        if log.address() != self.config.router_address {
+            return Ok(None);
+        }
+
+        let decoded = match log.topic0() {
+            Some(&SendPacket::SIGNATURE_HASH) => log
+                .log_decode::<SendPacket>()
+                .map(|event| (PacketEventKind::Send, event.inner.data.packet)),
+            Some(&WriteAcknowledgement::SIGNATURE_HASH) => log
+                .log_decode::<WriteAcknowledgement>()
+                .map(|event| (PacketEventKind::WriteAck, event.inner.data.packet)),
+            _ => return Ok(None),
+        };
+
+        let (kind, packet) = decoded.map_err(|err| {
+            error!(error = %err, "failed to decode router packet log");
+            AttestationAdapterError::MalformedEvent(format!("Malformed router log: {err}"))
+        })?;
+
+        Ok(Some(PacketEvent { kind, packet }))
+    }
+
+    /// Decode the packets carried by router packet logs.
+    fn decode_packet_logs(&self, logs: &[Log]) -> Result<Vec<Packet>, AttestationAdapterError> {
+        logs.iter()
+            .filter_map(|log| self.decode_packet_log(log).transpose())
+            .map(|event| event.map(|event| event.packet))
+            .collect()
+    }
+
+    /// Fetch router logs of the event with `signature` whose first indexed
+    /// topic is `client_id`, in chunks the RPC provider accepts.
+    async fn get_client_logs(
+        &self,
+        operation: &'static str,
+        signature: B256,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Log>, AttestationAdapterError> {
+        let mut logs = Vec::new();
+        let mut chunk_start = from_height;
+        while chunk_start <= to_height {
+            let chunk_end = chunk_start
+                .saturating_add(MAX_LOG_RANGE_BLOCKS - 1)
+                .min(to_height);
+
+            let filter = Filter::new()
+                .address(self.config.router_address)
+                .event_signature(signature)
+                .topic1(keccak256(client_id.as_bytes()))
+                .from_block(chunk_start)
+                .to_block(chunk_end);
+
+            let chunk = with_retry_backoff(operation, || async {
+                self.client.get_logs(&filter).await.map_err(|err| {
+                    // error log emitted by retry module
+                    debug!(error = %err, "failed to fetch logs from EVM chain");
+                    AttestationAdapterError::RetrievalError(err.to_string())
+                })
+            })
+            .await?;
+            logs.extend(chunk);
+
+            let Some(next_start) = chunk_end.checked_add(1) else {
+                break;
+            };
+            chunk_start = next_start;
+        }
+        Ok(logs)
+    }
+}
+
 #[async_trait::async_trait]
 impl AttestationAdapter for EvmAdapter {
     #[tracing::instrument(skip(self), fields(chain = "evm"))]
@@ -153,6 +304,109 @@ impl AttestationAdapter for EvmAdapter {
         Ok(timestamp)
     }
 
+    #[tracing::instrument(skip(self), fields(chain = "evm", height))]
+    async fn get_block_header(
+        &self,
+        height: u64,
+    ) -> Result<ConsensusHeader, AttestationAdapterError> {
+        debug!("fetching block header from EVM chain");
+
+        let block = with_retry_backoff("evm.get_block_header.get_block", || async {
+            self.client
+                .get_block(BlockId::number(height))
+                .await
+                .map_err(|err| {
+                    // error log emitted by retry module
+                    debug!(error = %err, "failed to fetch block from EVM chain");
+                    AttestationAdapterError::RetrievalError(err.to_string())
+                })
+        })
+        .await?;
+
+        let block = block.ok_or_else(|| {
+            error!("block not found at specified height");
+            AttestationAdapterError::BlockNotFinalized
+        })?;
+
+        let header = ConsensusHeader {
+            height,
+            timestamp: block.header.timestamp(),
+            block_hash: block.header.hash.0,
+            root: block.header.state_root().0,
+            validators_hash: None,
+        };
+        debug!(
+            blockHash = %hex::encode(header.block_hash),
+            stateRoot = %hex::encode(header.root),
+            "retrieved block header"
+        );
+        Ok(header)
+    }
+
+    #[tracing::instrument(skip(self, read), fields(chain = "evm", height, address = %read.address()))]
+    async fn get_contract_state(
+        &self,
+        height: u64,
+        read: &ContractRead,
+    ) -> Result<Vec<u8>, AttestationAdapterError> {
+        if !self
+            .config
+            .contract_state_allowlist
+            .iter()
+            .any(|allowed| allowed.permits(read))
+        {
+            error!("contract state read is not allowlisted");
+            return Err(AttestationAdapterError::NotAllowed(format!(
+                "contract state read of {}",
+                read.address()
+            )));
+        }
+
+        match read {
+            ContractRead::Call { address, calldata } => {
+                debug!("calling view function on EVM contract");
+
+                let tx = TransactionRequest::default()
+                    .to(*address)
+                    .input(TransactionInput::new(calldata.clone()));
+                let result = with_retry_backoff("evm.get_contract_state.call", || async {
+                    self.client
+                        .call(tx.clone())
+                        .block(BlockId::number(height))
+                        .await
+                        .map_err(|err| {
+                            // error log emitted by retry module
+                            debug!(error = %err, "failed to call view function on EVM contract");
+                            AttestationAdapterError::RetrievalError(err.to_string())
+                        })
+                })
+                .await?;
+
+                debug!(result = %hex::encode(&result), "view call returned");
+                Ok(result.to_vec())
+            }
+            ContractRead::Storage { address, slot } => {
+                debug!(slot = %slot, "reading storage slot of EVM contract");
+
+                let value = with_retry_backoff("evm.get_contract_state.get_storage_at", || async {
+                    self.client
+                        .get_storage_at(*address, U256::from_be_bytes(slot.0))
+                        .block_id(BlockId::number(height))
+                        .await
+                        .map_err(|err| {
+                            // error log emitted by retry module
+                            debug!(error = %err, "failed to read storage slot of EVM contract");
+                            AttestationAdapterError::RetrievalError(err.to_string())
+                        })
+                })
+                .await?;
+
+                debug!(value = %value, "storage slot read");
+                Ok(value.to_be_bytes::<32>().to_vec())
+            }
+        }
+    }
+
     #[tracing::instrument(
         skip(self, _client_id, commitment_path),
         fields(chain = "evm", clientId = %_client_id, height, sequence = _sequence, commitmentType = ?_commitment_type)
@@ -202,4 +456,370 @@ impl AttestationAdapter for EvmAdapter {
             Ok(Some(commitment.into()))
         }
     }
+
+    #[tracing::instrument(skip(self), fields(chain = "evm", clientId = %client_id, sequence, height))]
+    async fn find_send_packet(
+        &self,
+        client_id: &str,
+        sequence: u64,
+        height: u64,
+    ) -> Result<Option<Packet>, AttestationAdapterError> {
+        let from_block = height.saturating_sub(self.config.event_lookback_blocks);
+
+        debug!(
+            fromBlock = from_block,
+            "searching SendPacket logs on EVM router"
+        );
+
+        // Scan backwards in chunks the RPC provider accepts, newest first,
+        // and stop at the first match
+        let mut chunk_end = height;
+        let log = loop {
+            let chunk_start = chunk_end
+                .saturating_sub(MAX_LOG_RANGE_BLOCKS - 1)
+                .max(from_block);
+
+            let filter = Filter::new()
+                .address(self.config.router_address)
+                .event_signature(SendPacket::SIGNATURE_HASH)
+                .topic1(keccak256(client_id.as_bytes()))
+                .topic2(B256::from(U256::from(sequence)))
+                .from_block(chunk_start)
+                .to_block(chunk_end);
+
+            let mut logs = with_retry_backoff("evm.find_send_packet.get_logs", || async {
+                self.client.get_logs(&filter).await.map_err(|err| {
+                    // error log emitted by retry module
+                    debug!(error = %err, "failed to fetch logs from EVM chain");
+                    AttestationAdapterError::RetrievalError(err.to_string())
+                })
+            })
+            .await?;
+
+            // A client ID and sequence identify exactly one sent packet
+            if let Some(log) = logs.pop() {
+                break log;
+            }
+            if chunk_start <= from_block {
+                debug!("SendPacket log not found");
+                return Ok(None);
+            }
+            chunk_end = chunk_start - 1;
+        };
+
+        let event = log.log_decode::<SendPacket>().map_err(|err| {
+            error!(error = %err, "failed to decode SendPacket log");
+            AttestationAdapterError::MalformedEvent(format!("Malformed SendPacket log: {err}"))
+        })?;
+
+        debug!(
+            blockNumber = ?log.block_number,
+            "SendPacket log found"
+        );
+        Ok(Some(event.inner.data.packet))
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "evm", txHash = %tx_hash))]
+    async fn get_tx_packet_events(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Option<TxPacketEvents>, AttestationAdapterError> {
+        debug!("fetching transaction receipt from EVM chain");
+
+        let hash = B256::from_str(tx_hash).map_err(|err| {
+            error!(error = %err, "invalid EVM transaction hash");
+            AttestationAdapterError::InvalidTxHash(format!("{tx_hash}: {err}"))
+        })?;
+
+        let receipt = with_retry_backoff(
+            "evm.get_tx_packet_events.get_transaction_receipt",
+            || async {
+                self.client
+                    .get_transaction_receipt(hash)
+                    .await
+                    .map_err(|err| {
+                        // error log emitted by retry module
+                        debug!(error = %err, "failed to fetch transaction receipt from EVM chain");
+                        AttestationAdapterError::RetrievalError(err.to_string())
+                    })
+            },
+        )
+        .await?;
+
+        let Some(receipt) = receipt else {
+            debug!("transaction receipt not found");
+            return Ok(None);
+        };
+
+        let height = receipt.block_number.ok_or_else(|| {
+            error!("transaction receipt is missing block number");
+            AttestationAdapterError::RetrievalError(
+                "Transaction receipt is missing block number".to_string(),
+            )
+        })?;
+
+        let events = receipt
+            .inner
+            .logs()
+            .iter()
+            .filter_map(|log| self.decode_packet_log(log).transpose())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        debug!(
+            blockNumber = height,
+            numEvents = events.len(),
+            "decoded router packet events"
+        );
+        Ok(Some(TxPacketEvents { height, events }))
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "evm", txHash = %tx_hash, logIndex = log_index))]
+    async fn get_event_log(
+        &self,
+        tx_hash: &str,
+        log_index: u64,
+    ) -> Result<Option<EventLog>, AttestationAdapterError> {
+        debug!("fetching transaction receipt from EVM chain");
+
+        let hash = B256::from_str(tx_hash).map_err(|err| {
+            error!(error = %err, "invalid EVM transaction hash");
+            AttestationAdapterError::InvalidTxHash(format!("{tx_hash}: {err}"))
+        })?;
+
+        let receipt = with_retry_backoff("evm.get_event_log.get_transaction_receipt", || async {
+            self.client
+                .get_transaction_receipt(hash)
+                .await
+                .map_err(|err| {
+                    // error log emitted by retry module
+                    debug!(error = %err, "failed to fetch transaction receipt from EVM chain");
+                    AttestationAdapterError::RetrievalError(err.to_string())
+                })
+        })
+        .await?;
+
+        let Some(receipt) = receipt else {
+            debug!("transaction receipt not found");
+            return Ok(None);
+        };
+
+        let Some(log) = receipt
+            .inner
+            .logs()
+            .iter()
+            .find(|log| log.log_index == Some(log_index) && !log.removed)
+        else {
+            debug!("log not found in transaction receipt");
+            return Ok(None);
+        };
+
+        if !self.config.event_log_allowlist.contains(&log.address()) {
+            error!(address = %log.address(), "event log emitter is not allowlisted");
+            return Err(AttestationAdapterError::NotAllowed(format!(
+                "event log of {}",
+                log.address()
+            )));
+        }
+
+        let (Some(height), Some(block_hash), Some(tx_index)) = (
+            receipt.block_number,
+            receipt.block_hash,
+            receipt.transaction_index,
+        ) else {
+            error!("transaction receipt is missing its block position");
+            return Err(AttestationAdapterError::RetrievalError(
+                "Transaction receipt is missing its block position".to_string(),
+            ));
+        };
+
+        debug!(
+            blockNumber = height,
+            address = %log.address(),
+            numTopics = log.topics().len(),
+            "retrieved event log"
+        );
+        Ok(Some(EventLog {
+            height,
+            block_hash: block_hash.0,
+            tx_index,
+            log_index,
+            address: log.address(),
+            topics: log.topics().to_vec(),
+            data: log.data().data.clone(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "evm", clientId = %client_id, fromHeight = from_height, toHeight = to_height))]
+    async fn get_send_packets(
+        &self,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        debug!("scanning SendPacket logs on EVM router");
+
+        let logs = self
+            .get_client_logs(
+                "evm.get_send_packets.get_logs",
+                SendPacket::SIGNATURE_HASH,
+                client_id,
+                from_height,
+                to_height,
+            )
+            .await?;
+        let packets = self.decode_packet_logs(&logs)?;
+
+        debug!(numPackets = packets.len(), "scanned SendPacket logs");
+        Ok(packets)
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "evm", clientId = %client_id, fromHeight = from_height, toHeight = to_height))]
+    async fn get_write_ack_packets(
+        &self,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        debug!("scanning WriteAcknowledgement logs on EVM router");
+
+        let logs = self
+            .get_client_logs(
+                "evm.get_write_ack_packets.get_logs",
+                WriteAcknowledgement::SIGNATURE_HASH,
+                client_id,
+                from_height,
+                to_height,
+            )
+            .await?;
+        let packets = self.decode_packet_logs(&logs)?;
+
+        debug!(
+            numPackets = packets.len(),
+            "scanned WriteAcknowledgement logs"
+        );
+        Ok(packets)
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "evm"))]
+    async fn get_chain_info(&self) -> Result<ChainInfo, AttestationAdapterError> {
+        debug!("fetching chain ID from EVM chain");
+
+        let chain_id = with_retry_backoff("evm.get_chain_info.get_chain_id", || async {
+            self.client.get_chain_id().await.map_err(|err| {
+                // error log emitted by retry module
+                debug!(error = %err, "failed to fetch chain ID from EVM chain");
+                AttestationAdapterError::RetrievalError(err.to_string())
+            })
+        })
+        .await?;
+
+        let finality = self.config.finality_offset.map_or_else(
+            || "finalized block tag".to_string(),
+            |offset| format!("latest block minus {offset} blocks"),
+        );
+
+        debug!(chainId = chain_id, "retrieved chain ID");
+        Ok(ChainInfo {
+            chain_id: chain_id.to_string(),
+            router: self.config.router_address.to_string(),
+            finality,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, address, bytes};
+
+    const IFT: Address = address!("0x00000000000000000000000000000000000000aa");
+
+    fn allowlist() -> Vec<AllowedContractRead> {
+        vec![
+            AllowedContractRead::Call {
+                address: IFT,
+                selector: Selector::from([0x12, 0x34, 0x56, 0x78]),
+            },
+            AllowedContractRead::Storage {
+                address: IFT,
+                slot: B256::with_last_byte(3),
+            },
+        ]
+    }
+
+    fn permitted(read: &ContractRead) -> bool {
+        allowlist().iter().any(|allowed| allowed.permits(read))
+    }
+
+    #[test]
+    fn allowlist_permits_listed_selector_with_any_arguments() {
+        assert!(permitted(&ContractRead::Call {
+            address: IFT,
+            calldata: bytes!("0x12345678"),
+        }));
+        assert!(permitted(&ContractRead::Call {
+            address: IFT,
+            calldata: bytes!(
+                "0x123456780000000000000000000000000000000000000000000000000000000000000001"
+            ),
+        }));
+    }
+
+    #[test]
+    fn allowlist_rejects_other_calls() {
+        // Other selector
+        assert!(!permitted(&ContractRead::Call {
+            address: IFT,
+            calldata: bytes!("0x87654321"),
+        }));
+        // Listed selector on another contract
+        assert!(!permitted(&ContractRead::Call {
+            address: Address::ZERO,
+            calldata: bytes!("0x12345678"),
+        }));
+        // Calldata shorter than a selector
+        assert!(!permitted(&ContractRead::Call {
+            address: IFT,
+            calldata: Bytes::new(),
+        }));
+    }
+
+    #[test]
+    fn allowlist_permits_listed_storage_slot_only() {
+        assert!(permitted(&ContractRead::Storage {
+            address: IFT,
+            slot: B256::with_last_byte(3),
+        }));
+        assert!(!permitted(&ContractRead::Storage {
+            address: IFT,
+            slot: B256::with_last_byte(4),
+        }));
+        assert!(!permitted(&ContractRead::Storage {
+            address: Address::ZERO,
+            slot: B256::with_last_byte(3),
+        }));
+    }
+
+    #[test]
+    fn allowlist_is_parsed_from_config() {
+        let config: EvmAdapterConfig = toml::from_str(
+            r#"
+            url = "http://localhost:8545"
+            router_address = "0x00000000000000000000000000000000000000bb"
+
+            [[contract_state_allowlist]]
+            kind = "call"
+            address = "0x00000000000000000000000000000000000000aa"
+            selector = "0x12345678"
+
+            [[contract_state_allowlist]]
+            kind = "storage"
+            address = "0x00000000000000000000000000000000000000aa"
+            slot = "0x0000000000000000000000000000000000000000000000000000000000000003"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.contract_state_allowlist, allowlist());
+    }
 }
@@ -1,3 +1,5 @@
+use alloy_primitives::{Address, B256, Bytes};
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Packet;
 use thiserror::Error;
 
 use crate::metrics;
@@ -33,6 +35,121 @@ pub enum AttestationAdapterError {
     /// Malformed commitment
     #[error("Commitment error: {0}")]
     CommitmentError(String),
+    /// Chain returned an event that cannot be decoded
+    #[error("Malformed event: {0}")]
+    MalformedEvent(String),
+    /// Operation is not available for this chain type
+    #[error("Operation not supported: {0}")]
+    Unsupported(String),
+    /// Transaction hash cannot be parsed for this chain type
+    #[error("Invalid transaction hash: {0}")]
+    InvalidTxHash(String),
+    /// Requested read is not on the configured allowlist
+    #[error("Not allowed: {0}")]
+    NotAllowed(String),
+}
+
+/// Kind of IBC v2 packet event emitted by a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketEventKind {
+    /// `SendPacket` event emitted on the source chain
+    Send,
+    /// `WriteAcknowledgement` event emitted on the destination chain
+    WriteAck,
+}
+
+/// IBC v2 packet event decoded from a transaction
+#[derive(Clone, Debug)]
+pub struct PacketEvent {
+    /// Kind of the event
+    pub kind: PacketEventKind,
+    /// Packet carried by the event
+    pub packet: Packet,
+}
+
+/// IBC v2 packet events emitted by a single transaction
+#[derive(Clone, Debug)]
+pub struct TxPacketEvents {
+    /// Height of the block that included the transaction
+    pub height: u64,
+    /// Packet events in emission order
+    pub events: Vec<PacketEvent>,
+}
+
+/// Consensus-relevant fields of a block header
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusHeader {
+    /// Block height
+    pub height: u64,
+    /// Block UNIX timestamp in seconds
+    pub timestamp: u64,
+    /// Hash of the block header
+    pub block_hash: [u8; 32],
+    /// Root of the chain state committed to by the header: the state root on
+    /// EVM, the app hash on Cosmos
+    pub root: [u8; 32],
+    /// Hash of the validator set that signed the block, Cosmos only
+    pub validators_hash: Option<[u8; 32]>,
+}
+
+/// Read of contract state, checked against the adapter allowlist
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContractRead {
+    /// View call of `address` with `calldata`, starting with the selector
+    Call {
+        /// Called contract
+        address: Address,
+        /// ABI-encoded call
+        calldata: Bytes,
+    },
+    /// Read of a raw storage slot of `address`
+    Storage {
+        /// Contract owning the storage
+        address: Address,
+        /// Storage slot
+        slot: B256,
+    },
+}
+
+impl ContractRead {
+    /// Returns the contract being read.
+    #[must_use]
+    pub const fn address(&self) -> Address {
+        match self {
+            Self::Call { address, .. } | Self::Storage { address, .. } => *address,
+        }
+    }
+}
+
+/// Log emitted by a contract in a transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventLog {
+    /// Height of the block that included the transaction
+    pub height: u64,
+    /// Hash of the block that included the transaction
+    pub block_hash: [u8; 32],
+    /// Index of the transaction in the block
+    pub tx_index: u64,
+    /// Index of the log in the block
+    pub log_index: u64,
+    /// Contract that emitted the log
+    pub address: Address,
+    /// Indexed topics, starting with the event signature hash
+    pub topics: Vec<B256>,
+    /// ABI-encoded non-indexed event data
+    pub data: Bytes,
+}
+
+/// Identity and finality rule of the attested chain
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainInfo {
+    /// Chain identifier: EVM chain ID, Cosmos network or Solana genesis hash
+    pub chain_id: String,
+    /// IBC router contract address or program ID, empty if IBC is native to
+    /// the chain
+    pub router: String,
+    /// Human-readable description of when a height is considered finalized
+    pub finality: String,
 }
 
 /// Captures builder methods needed to create an [`AttestationAdapter`]
@@ -96,6 +213,42 @@ impl AttestationAdapter for AdapterEnum {
         }
     }
 
+    async fn get_block_header(
+        &self,
+        height: u64,
+    ) -> Result<ConsensusHeader, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_block_header(height).await,
+            Self::Solana(a) => a.get_block_header(height).await,
+            Self::Cosmos(a) => a.get_block_header(height).await,
+        }
+    }
+
+    async fn get_contract_state(
+        &self,
+        height: u64,
+        read: &ContractRead,
+    ) -> Result<Vec<u8>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_contract_state(height, read).await,
+            Self::Solana(a) => a.get_contract_state(height, read).await,
+            Self::Cosmos(a) => a.get_contract_state(height, read).await,
+        }
+    }
+
+    async fn get_store_value(
+        &self,
+        height: u64,
+        store: &str,
+        key: &[u8],
+    ) -> Result<Vec<u8>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_store_value(height, store, key).await,
+            Self::Solana(a) => a.get_store_value(height, store, key).await,
+            Self::Cosmos(a) => a.get_store_value(height, store, key).await,
+        }
+    }
+
     async fn get_commitment(
         &self,
         client_id: String,
@@ -137,6 +290,93 @@ impl AttestationAdapter for AdapterEnum {
             }
         }
     }
+
+    async fn find_send_packet(
+        &self,
+        client_id: &str,
+        sequence: u64,
+        height: u64,
+    ) -> Result<Option<Packet>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.find_send_packet(client_id, sequence, height).await,
+            Self::Solana(a) => a.find_send_packet(client_id, sequence, height).await,
+            Self::Cosmos(a) => a.find_send_packet(client_id, sequence, height).await,
+        }
+    }
+
+    async fn get_tx_packet_events(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Option<TxPacketEvents>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_tx_packet_events(tx_hash).await,
+            Self::Solana(a) => a.get_tx_packet_events(tx_hash).await,
+            Self::Cosmos(a) => a.get_tx_packet_events(tx_hash).await,
+        }
+    }
+
+    async fn get_event_log(
+        &self,
+        tx_hash: &str,
+        log_index: u64,
+    ) -> Result<Option<EventLog>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_event_log(tx_hash, log_index).await,
+            Self::Solana(a) => a.get_event_log(tx_hash, log_index).await,
+            Self::Cosmos(a) => a.get_event_log(tx_hash, log_index).await,
+        }
+    }
+
+    fn supports_historical_state(&self) -> bool {
+        match self {
+            Self::Evm(a) => a.supports_historical_state(),
+            Self::Solana(a) => a.supports_historical_state(),
+            Self::Cosmos(a) => a.supports_historical_state(),
+        }
+    }
+
+    async fn get_send_packets(
+        &self,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_send_packets(client_id, from_height, to_height).await,
+            Self::Solana(a) => a.get_send_packets(client_id, from_height, to_height).await,
+            Self::Cosmos(a) => a.get_send_packets(client_id, from_height, to_height).await,
+        }
+    }
+
+    async fn get_write_ack_packets(
+        &self,
+        client_id: &str,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => {
+                a.get_write_ack_packets(client_id, from_height, to_height)
+                    .await
+            }
+            Self::Solana(a) => {
+                a.get_write_ack_packets(client_id, from_height, to_height)
+                    .await
+            }
+            Self::Cosmos(a) => {
+                a.get_write_ack_packets(client_id, from_height, to_height)
+                    .await
+            }
+        }
+    }
+
+    async fn get_chain_info(&self) -> Result<ChainInfo, AttestationAdapterError> {
+        match self {
+            Self::Evm(a) => a.get_chain_info().await,
+            Self::Solana(a) => a.get_chain_info().await,
+            Self::Cosmos(a) => a.get_chain_info().await,
+        }
+    }
 }
 
 /// Attestation adapter methods needed to provide attestations for a given chain
@@ -149,6 +389,48 @@ pub trait AttestationAdapter: Sync + Send + 'static {
     /// Returns a UNIX timestamp in seconds for the provided block height.
     async fn get_block_timestamp(&self, height: u64) -> Result<u64, AttestationAdapterError>;
 
+    /// Returns the consensus-relevant header fields of the block at `height`.
+    async fn get_block_header(
+        &self,
+        _height: u64,
+    ) -> Result<ConsensusHeader, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "block header".to_string(),
+        ))
+    }
+
+    /// Returns the result of an allowlisted contract state read at `height`:
+    /// the return data of a view call, or the 32-byte value of a storage slot.
+    async fn get_contract_state(
+        &self,
+        _height: u64,
+        _read: &ContractRead,
+    ) -> Result<Vec<u8>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "contract state".to_string(),
+        ))
+    }
+
+    /// Returns the value stored under `key` in the module `store` at `height`,
+    /// empty if the key is not set. The key must fall under an allowlisted
+    /// prefix of the store.
+    async fn get_store_value(
+        &self,
+        _height: u64,
+        _store: &str,
+        _key: &[u8],
+    ) -> Result<Vec<u8>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "store query".to_string(),
+        ))
+    }
+
+    /// Whether [`Self::get_commitment`] reads state at the requested height
+    /// rather than at the latest finalized height.
+    fn supports_historical_state(&self) -> bool {
+        true
+    }
+
     /// Get commitment at some block height.
     ///
     /// Note: Returns Ok(None) if commitment was not found.
@@ -160,4 +442,81 @@ pub trait AttestationAdapter: Sync + Send + 'static {
         commitment_path: &[u8],
         commitment_type: CommitmentType,
     ) -> Result<Option<[u8; 32]>, AttestationAdapterError>;
+
+    /// Locate a packet sent through `client_id` with `sequence` from the
+    /// chain's `SendPacket` events at or below `height`.
+    ///
+    /// Note: Returns Ok(None) if no matching event was found.
+    async fn find_send_packet(
+        &self,
+        _client_id: &str,
+        _sequence: u64,
+        _height: u64,
+    ) -> Result<Option<Packet>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "send packet lookup".to_string(),
+        ))
+    }
+
+    /// Decode the IBC v2 `SendPacket` and `WriteAcknowledgement` events
+    /// emitted by the transaction with the given chain-native hash.
+    ///
+    /// Note: Returns Ok(None) if the transaction was not found.
+    async fn get_tx_packet_events(
+        &self,
+        _tx_hash: &str,
+    ) -> Result<Option<TxPacketEvents>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "transaction packet lookup".to_string(),
+        ))
+    }
+
+    /// Returns the log at `log_index` of the block, emitted by the
+    /// transaction with the given chain-native hash. The log must be emitted
+    /// by an allowlisted contract.
+    ///
+    /// Note: Returns Ok(None) if the transaction or log was not found.
+    async fn get_event_log(
+        &self,
+        _tx_hash: &str,
+        _log_index: u64,
+    ) -> Result<Option<EventLog>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "event log lookup".to_string(),
+        ))
+    }
+
+    /// List the packets sent through `client_id` from the chain's `SendPacket`
+    /// events emitted in blocks `from_height..=to_height`, in sequence order.
+    async fn get_send_packets(
+        &self,
+        _client_id: &str,
+        _from_height: u64,
+        _to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "send packet scan".to_string(),
+        ))
+    }
+
+    /// List the packets acknowledged through destination client `client_id`
+    /// from the chain's `WriteAcknowledgement` events emitted in blocks
+    /// `from_height..=to_height`, in sequence order.
+    async fn get_write_ack_packets(
+        &self,
+        _client_id: &str,
+        _from_height: u64,
+        _to_height: u64,
+    ) -> Result<Vec<Packet>, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "write acknowledgement scan".to_string(),
+        ))
+    }
+
+    /// Describe the attested chain and the configured finality rule.
+    async fn get_chain_info(&self) -> Result<ChainInfo, AttestationAdapterError> {
+        Err(AttestationAdapterError::Unsupported(
+            "chain info".to_string(),
+        ))
+    }
 }
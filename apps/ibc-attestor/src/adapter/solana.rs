@@ -1,13 +1,18 @@
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use borsh::BorshDeserialize;
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::{Packet, Payload};
 use serde::Deserialize;
-use solana_client::nonblocking::rpc_client::RpcClient;
+use sha2::{Digest, Sha256};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcTransactionConfig};
 use solana_commitment_config::CommitmentConfig;
 use solana_ibc_types::Commitment;
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::str::FromStr;
 use tracing::{debug, error, info};
 
 use crate::adapter::{
-    AdapterBuilder, AttestationAdapter, AttestationAdapterError, retry::with_retry_backoff,
+    AdapterBuilder, AttestationAdapter, AttestationAdapterError, ChainInfo, PacketEvent,
+    PacketEventKind, TxPacketEvents, retry::with_retry_backoff,
 };
 use crate::rpc::api::CommitmentType;
 
@@ -17,6 +22,18 @@ const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
 /// Commitment length
 const COMMITMENT_LEN: usize = 32;
 
+/// Anchor event emitted by the router when a packet is sent
+const SEND_PACKET_EVENT: &str = "SendPacketEvent";
+
+/// Anchor event emitted by the router when an acknowledgement is written
+const WRITE_ACK_EVENT: &str = "WriteAcknowledgementEvent";
+
+/// Prefix of the log line carrying the base64 data of an Anchor event
+const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+
+/// Log line the runtime appends once a transaction's logs exceed the limit
+const LOG_TRUNCATED: &str = "Log truncated";
+
 /// Configuration for the Solana blockchain client adapter
 #[derive(Clone, Debug, Deserialize)]
 pub struct SolanaAdapterConfig {
@@ -77,8 +94,164 @@ impl AdapterBuilder for SolanaAdapterBuilder {
     }
 }
 
+/// Payload as encoded in the router's Anchor events
+#[derive(BorshDeserialize)]
+#[cfg_attr(test, derive(borsh::BorshSerialize))]
+struct RouterPayload {
+    source_port: String,
+    dest_port: String,
+    version: String,
+    encoding: String,
+    value: Vec<u8>,
+}
+
+/// Packet as encoded in the router's Anchor events
+#[derive(BorshDeserialize)]
+#[cfg_attr(test, derive(borsh::BorshSerialize))]
+struct RouterPacket {
+    sequence: u64,
+    source_client: String,
+    dest_client: String,
+    timeout_timestamp: i64,
+    payloads: Vec<RouterPayload>,
+}
+
+/// Leading fields of the router's `SendPacketEvent` and
+/// `WriteAcknowledgementEvent`. Fields after the packet are not needed and
+/// left undecoded.
+#[derive(BorshDeserialize)]
+#[cfg_attr(test, derive(borsh::BorshSerialize))]
+struct RouterPacketEvent {
+    _client_id: String,
+    _sequence: u64,
+    packet: RouterPacket,
+}
+
+/// Anchor event discriminator: the first 8 bytes of `sha256("event:<name>")`.
+fn event_discriminator(name: &str) -> [u8; ANCHOR_DISCRIMINATOR_LEN] {
+    let hash = Sha256::digest(format!("event:{name}"));
+    let mut discriminator = [0; ANCHOR_DISCRIMINATOR_LEN];
+    discriminator.copy_from_slice(&hash[..ANCHOR_DISCRIMINATOR_LEN]);
+    discriminator
+}
+
+impl TryFrom<RouterPacket> for Packet {
+    type Error = AttestationAdapterError;
+
+    fn try_from(packet: RouterPacket) -> Result<Self, Self::Error> {
+        let timeout_timestamp = u64::try_from(packet.timeout_timestamp).map_err(|_| {
+            error!(
+                timeoutTimestamp = packet.timeout_timestamp,
+                "negative packet timeout timestamp"
+            );
+            AttestationAdapterError::MalformedEvent(format!(
+                "Negative packet timeout timestamp {}",
+                packet.timeout_timestamp
+            ))
+        })?;
+
+        Ok(Self {
+            sequence: packet.sequence,
+            sourceClient: packet.source_client,
+            destClient: packet.dest_client,
+            timeoutTimestamp: timeout_timestamp,
+            payloads: packet
+                .payloads
+                .into_iter()
+                .map(|payload| Payload {
+                    sourcePort: payload.source_port,
+                    destPort: payload.dest_port,
+                    version: payload.version,
+                    encoding: payload.encoding,
+                    value: payload.value.into(),
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Decode a router Anchor event, skipping other events.
+fn decode_router_event(data: &[u8]) -> Result<Option<PacketEvent>, AttestationAdapterError> {
+    let Some((discriminator, mut encoded)) = data.split_first_chunk::<ANCHOR_DISCRIMINATOR_LEN>()
+    else {
+        return Ok(None);
+    };
+
+    let kind = if *discriminator == event_discriminator(SEND_PACKET_EVENT) {
+        PacketEventKind::Send
+    } else if *discriminator == event_discriminator(WRITE_ACK_EVENT) {
+        PacketEventKind::WriteAck
+    } else {
+        return Ok(None);
+    };
+
+    let event = RouterPacketEvent::deserialize(&mut encoded).map_err(|err| {
+        error!(error = %err, "failed to decode router packet event");
+        AttestationAdapterError::MalformedEvent(format!("Malformed router event: {err}"))
+    })?;
+
+    Ok(Some(PacketEvent {
+        kind,
+        packet: event.packet.try_into()?,
+    }))
+}
+
+/// Decode the router packet events from the log messages of a transaction.
+/// Each `Program data:` line is attributed to the program executing when it
+/// was logged, so events forged by other programs are ignored.
+fn decode_tx_logs(
+    router_program_id: &Pubkey,
+    logs: &[String],
+) -> Result<Vec<PacketEvent>, AttestationAdapterError> {
+    let router_program_id = router_program_id.to_string();
+    let mut invoked = Vec::new();
+    let mut events = Vec::new();
+
+    for line in logs {
+        if line == LOG_TRUNCATED {
+            error!("transaction logs are truncated");
+            return Err(AttestationAdapterError::MalformedEvent(
+                "Transaction logs are truncated".to_string(),
+            ));
+        }
+
+        if let Some(fields) = line.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+            if invoked.last() != Some(&router_program_id.as_str()) {
+                continue;
+            }
+            let mut data = Vec::new();
+            for field in fields.split_whitespace() {
+                let decoded = BASE64.decode(field).map_err(|err| {
+                    error!(error = %err, "program data is not valid base64");
+                    AttestationAdapterError::MalformedEvent(format!(
+                        "Malformed program data: {err}"
+                    ))
+                })?;
+                data.extend(decoded);
+            }
+            events.extend(decode_router_event(&data)?);
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            let mut words = rest.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some(program_id), Some("invoke")) => invoked.push(program_id),
+                (Some(_), Some("success" | "failed:")) => {
+                    invoked.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(events)
+}
+
 #[async_trait::async_trait]
 impl AttestationAdapter for SolanaAdapter {
+    // Commitment accounts are only read at the finalized commitment level
+    fn supports_historical_state(&self) -> bool {
+        false
+    }
+
     #[tracing::instrument(skip(self), fields(chain = "solana"))]
     async fn get_last_height_at_configured_finality(&self) -> Result<u64, AttestationAdapterError> {
         debug!("fetching last finalized slot from Solana chain");
@@ -204,4 +377,201 @@ impl AttestationAdapter for SolanaAdapter {
         debug!("commitment retrieved successfully");
         Ok(Some(commitment))
     }
+
+    #[tracing::instrument(skip(self), fields(chain = "solana", txHash = %tx_hash))]
+    async fn get_tx_packet_events(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Option<TxPacketEvents>, AttestationAdapterError> {
+        debug!("fetching transaction from Solana chain");
+
+        let signature = Signature::from_str(tx_hash).map_err(|err| {
+            error!(error = %err, "invalid Solana transaction signature");
+            AttestationAdapterError::InvalidTxHash(format!("{tx_hash}: {err}"))
+        })?;
+
+        // The signature status tells a missing transaction apart from an RPC
+        // failure, which `getTransaction` reports the same way
+        let status = with_retry_backoff(
+            "solana.get_tx_packet_events.get_signature_statuses",
+            || async {
+                self.client
+                    .get_signature_statuses_with_history(&[signature])
+                    .await
+                    .map_err(|err| {
+                        // error log emitted by retry module
+                        debug!(error = %err, "failed to fetch signature status from Solana chain");
+                        AttestationAdapterError::RetrievalError(err.to_string())
+                    })
+            },
+        )
+        .await?
+        .value
+        .into_iter()
+        .next()
+        .flatten();
+
+        let Some(status) = status else {
+            debug!("transaction not found");
+            return Ok(None);
+        };
+
+        // A failed transaction leaves no packet behind
+        if status.err.is_some() {
+            debug!(txSlot = status.slot, "transaction failed");
+            return Ok(Some(TxPacketEvents {
+                height: status.slot,
+                events: Vec::new(),
+            }));
+        }
+
+        // Confirmed rather than finalized, so a transaction that is not
+        // finalized yet is reported as such by the caller
+        let tx = with_retry_backoff("solana.get_tx_packet_events.get_transaction", || async {
+            self.client
+                .get_transaction_with_config(
+                    &signature,
+                    RpcTransactionConfig {
+                        commitment: Some(CommitmentConfig::confirmed()),
+                        max_supported_transaction_version: Some(0),
+                        ..RpcTransactionConfig::default()
+                    },
+                )
+                .await
+                .map_err(|err| {
+                    // error log emitted by retry module
+                    debug!(error = %err, "failed to fetch transaction from Solana chain");
+                    AttestationAdapterError::RetrievalError(err.to_string())
+                })
+        })
+        .await?;
+
+        let logs: Option<Vec<String>> = tx
+            .transaction
+            .meta
+            .and_then(|meta| meta.log_messages.into());
+        let logs = logs.ok_or_else(|| {
+            error!("transaction is missing its log messages");
+            AttestationAdapterError::RetrievalError(
+                "Transaction is missing its log messages".to_string(),
+            )
+        })?;
+
+        let events = decode_tx_logs(&self.router_program_id, &logs)?;
+
+        debug!(
+            txSlot = tx.slot,
+            numEvents = events.len(),
+            "decoded packet events"
+        );
+        Ok(Some(TxPacketEvents {
+            height: tx.slot,
+            events,
+        }))
+    }
+
+    #[tracing::instrument(skip(self), fields(chain = "solana"))]
+    async fn get_chain_info(&self) -> Result<ChainInfo, AttestationAdapterError> {
+        debug!("fetching genesis hash from Solana chain");
+
+        let genesis_hash = with_retry_backoff("solana.get_chain_info.get_genesis_hash", || async {
+            self.client.get_genesis_hash().await.map_err(|err| {
+                // error log emitted by retry module
+                debug!(error = %err, "failed to fetch genesis hash from Solana chain");
+                AttestationAdapterError::RetrievalError(err.to_string())
+            })
+        })
+        .await?;
+
+        debug!(genesisHash = %genesis_hash, "retrieved genesis hash");
+        Ok(ChainInfo {
+            chain_id: genesis_hash.to_string(),
+            router: self.router_program_id.to_string(),
+            finality: "finalized commitment level".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use borsh::BorshSerialize;
+
+    fn router_event_log(name: &str, sequence: u64) -> String {
+        let event = RouterPacketEvent {
+            _client_id: "client-0".to_string(),
+            _sequence: sequence,
+            packet: RouterPacket {
+                sequence,
+                source_client: "client-0".to_string(),
+                dest_client: "client-1".to_string(),
+                timeout_timestamp: 1_700_000_000,
+                payloads: vec![RouterPayload {
+                    source_port: "transfer".to_string(),
+                    dest_port: "transfer".to_string(),
+                    version: "ics20-1".to_string(),
+                    encoding: "application/x-solidity-abi".to_string(),
+                    value: vec![1, 2, 3],
+                }],
+            },
+        };
+        let mut data = event_discriminator(name).to_vec();
+        event.serialize(&mut data).unwrap();
+        format!("{PROGRAM_DATA_LOG_PREFIX}{}", BASE64.encode(data))
+    }
+
+    fn program_logs(program_id: &Pubkey, inner: Vec<String>) -> Vec<String> {
+        let mut logs = vec![format!("Program {program_id} invoke [1]")];
+        logs.extend(inner);
+        logs.push(format!("Program {program_id} success"));
+        logs
+    }
+
+    #[test]
+    fn decode_tx_logs_collects_router_send_and_ack_events() {
+        let router = Pubkey::new_unique();
+        let logs = program_logs(
+            &router,
+            vec![
+                "Program log: Instruction: SendPacket".to_string(),
+                router_event_log(SEND_PACKET_EVENT, 5),
+                router_event_log(WRITE_ACK_EVENT, 6),
+            ],
+        );
+
+        let events = decode_tx_logs(&router, &logs).unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].kind, PacketEventKind::Send);
+        assert_eq!(events[0].packet.sequence, 5);
+        assert_eq!(events[0].packet.timeoutTimestamp, 1_700_000_000);
+        assert_eq!(events[0].packet.payloads[0].value.to_vec(), vec![1, 2, 3]);
+        assert_eq!(events[1].kind, PacketEventKind::WriteAck);
+        assert_eq!(events[1].packet.sequence, 6);
+    }
+
+    #[test]
+    fn decode_tx_logs_ignores_events_logged_by_other_programs() {
+        let router = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let mut inner = program_logs(&other, vec![router_event_log(SEND_PACKET_EVENT, 5)]);
+        inner.push(router_event_log(SEND_PACKET_EVENT, 7));
+        let logs = program_logs(&router, inner);
+
+        let events = decode_tx_logs(&router, &logs).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].packet.sequence, 7);
+    }
+
+    #[test]
+    fn decode_tx_logs_rejects_truncated_logs() {
+        let router = Pubkey::new_unique();
+        let logs = program_logs(&router, vec![LOG_TRUNCATED.to_string()]);
+
+        assert!(matches!(
+            decode_tx_logs(&router, &logs),
+            Err(AttestationAdapterError::MalformedEvent(_))
+        ));
+    }
 }
@@ -1,34 +1,55 @@
 use tracing::{debug, error};
 
-pub use crate::attestation_payload::{AttestationPayload, AttestationType};
-use crate::{AttestorError, signer::Signer};
+pub use crate::attestation_payload::{AttestationPayload, AttestationType, SigningMode};
+use crate::{
+    AttestorError,
+    signer::{AttestorSignature, SignatureScheme, Signer},
+};
 
-/// Create an ECDSA signature over the attestation payload for on-chain verification.
+/// Create a signature over the attestation payload for on-chain verification.
 ///
-/// Uses domain separation to prevent cross-protocol replay between state and
-/// packet attestations: `sha256(type_tag || sha256(attested_data))`.
+/// Uses domain separation to prevent cross-protocol replay between attestation
+/// types: `sha256(type_tag || sha256(attested_data))`. The V2 scheme also
+/// binds the signature to a chain and light client. In EIP-712 `mode` the
+/// typed data hash of the attestation is signed instead. The signature is
+/// ECDSA or Ed25519 depending on the signer's key.
 ///
 /// # Errors
-/// Returns [`AttestorError::SignerError`] if signing fails.
-#[tracing::instrument(skip(payload, signer), fields(height, attestation_type = ?payload.attestation_type(), data_len = payload.data().len()))]
+/// Returns [`AttestorError::SigningPaused`] while signing is paused,
+/// [`AttestorError::SignerError`] or [`AttestorError::SignerFault`] if signing fails, or
+/// [`AttestorError::AbiError`] if the payload can't be decoded into typed data.
+#[tracing::instrument(skip(payload, mode, signer), fields(height, attestation_type = ?payload.attestation_type(), data_len = payload.data().len()))]
 pub async fn sign_attestation(
     height: u64,
     timestamp: Option<u64>,
     payload: AttestationPayload,
+    mode: &SigningMode,
     signer: &impl Signer,
 ) -> Result<SignedAttestation, AttestorError> {
     debug!(height, timestamp, "signing attestation");
 
-    let tagged_signing_input = payload.tagged_signing_input();
-    let signature = signer.sign(&tagged_signing_input).await.map_err(|e| {
+    let attestation_type = payload.attestation_type();
+    let result = match mode {
+        SigningMode::Tagged(scheme) => signer.sign(&payload.signing_input(scheme)).await,
+        SigningMode::Eip712(domain) => {
+            let request = payload
+                .eip712_request(domain)
+                .map_err(AttestorError::AbiError)?;
+            signer
+                .sign_typed_data(&request.digest, &request.typed_data)
+                .await
+                .map(AttestorSignature::Secp256k1)
+        }
+    };
+    let signature = result.map_err(|e| {
         error!(
             height,
             error = %e,
             "failed to sign attestation"
         );
-        AttestorError::SignerError(e.to_string())
+        AttestorError::signing_failed(&e)
     })?;
-    let signature_bytes = signature.as_bytes().to_vec();
+    let signature_bytes = signature.to_bytes();
 
     debug!(
         height,
@@ -38,30 +59,153 @@ pub async fn sign_attestation(
     );
 
     Ok(SignedAttestation {
+        attestation_type,
         height,
         timestamp,
         attested_data: payload.into_data(),
         signature: signature_bytes,
+        signature_scheme: signature.scheme(),
     })
 }
 
+/// ABI types of attestations defined by the attestor
+#[allow(clippy::nursery, clippy::pedantic, missing_docs)]
+pub mod msgs {
+    alloy_sol_types::sol! {
+        /// Claim of the latest finalized height of the attested chain, signed
+        /// with the [`AttestationType::LatestHeight`](super::AttestationType)
+        /// domain tag
+        #[derive(Debug, PartialEq, Eq)]
+        struct LatestHeightAttestation {
+            /// Latest finalized height
+            uint64 height;
+            /// Chain identifier as reported by `GetAttestorInfo`
+            string chainId;
+            /// Attestor wall-clock UNIX timestamp in seconds
+            uint64 timestamp;
+        }
+
+        /// Claim that a batch of packet commitments exists at a height,
+        /// signed with the [`AttestationType::PacketBatch`](super::AttestationType)
+        /// domain tag. Each packet is proven against `root` with a
+        /// [`merkle`](crate::merkle) inclusion proof.
+        #[derive(Debug, PartialEq, Eq)]
+        struct PacketBatchAttestation {
+            /// Height the commitments were read at
+            uint64 height;
+            /// Merkle root over the `(path, commitment)` leaves
+            bytes32 root;
+            /// Number of packets in the batch
+            uint64 size;
+        }
+
+        /// Claim of the consensus-relevant header fields of a block, signed
+        /// with the [`AttestationType::ConsensusState`](super::AttestationType)
+        /// domain tag. Light clients can verify ordinary Merkle proofs
+        /// against `root`. On EVM `root` is the state after block `height`,
+        /// while on Cosmos it is the state after block `height - 1`, as in
+        /// the IBC Tendermint light client, so proofs must be queried at
+        /// `height - 1`.
+        #[derive(Debug, PartialEq, Eq)]
+        struct ConsensusStateAttestation {
+            /// Block height
+            uint64 height;
+            /// Block UNIX timestamp in seconds
+            uint64 timestamp;
+            /// Hash of the block header
+            bytes32 blockHash;
+            /// State root after `height` on EVM, app hash after `height - 1`
+            /// on Cosmos
+            bytes32 root;
+            /// Validator set hash on Cosmos, zero elsewhere
+            bytes32 validatorsHash;
+        }
+
+        /// Claim of the result of an allowlisted contract state read, signed
+        /// with the [`AttestationType::ContractState`](super::AttestationType)
+        /// domain tag
+        #[derive(Debug, PartialEq, Eq)]
+        struct ContractStateAttestation {
+            /// Height the state was read at
+            uint64 height;
+            /// Contract that was read
+            address target;
+            /// Whether `input` is a storage slot rather than view calldata
+            bool storage;
+            /// Calldata of the view call, or the 32-byte storage slot
+            bytes input;
+            /// ABI-encoded return data, or the 32-byte slot value
+            bytes result;
+        }
+
+        /// Claim of the value stored under a key of a Cosmos module store,
+        /// signed with the [`AttestationType::StoreKey`](super::AttestationType)
+        /// domain tag
+        #[derive(Debug, PartialEq, Eq)]
+        struct StoreKeyAttestation {
+            /// Height the store was queried at
+            uint64 height;
+            /// Module store name
+            string store;
+            /// Store key
+            bytes key;
+            /// Stored value, empty if the key is not set
+            bytes value;
+        }
+
+        /// Claim that a log was emitted in a finalized block, signed with
+        /// the [`AttestationType::EventLog`](super::AttestationType) domain
+        /// tag
+        #[derive(Debug, PartialEq, Eq)]
+        struct EventLogAttestation {
+            /// Height of the block that included the log
+            uint64 height;
+            /// Hash of the block that included the log
+            bytes32 blockHash;
+            /// Index of the transaction in the block
+            uint64 txIndex;
+            /// Index of the log in the block
+            uint64 logIndex;
+            /// Contract that emitted the log
+            address emitter;
+            /// Indexed topics, starting with the event signature hash
+            bytes32[] topics;
+            /// ABI-encoded non-indexed event data
+            bytes data;
+        }
+    }
+}
+
 /// Signed attestation containing blockchain state data and cryptographic signature
+#[derive(Clone, Debug)]
 pub struct SignedAttestation {
+    /// Domain the attestation was signed for
+    pub attestation_type: AttestationType,
     /// Block height being attested
     pub height: u64,
-    /// Optional block timestamp (for state attestations)
+    /// Block timestamp for state attestations, wall-clock timestamp for latest
+    /// height attestations
     pub timestamp: Option<u64>,
     /// ABI-encoded attestation data
     pub attested_data: Vec<u8>,
-    /// 65-byte ECDSA signature (r: 32, s: 32, v: 1)
+    /// 65-byte ECDSA signature (r: 32, s: 32, v: 1) or 64-byte Ed25519
+    /// signature
     pub signature: Vec<u8>,
+    /// Scheme of `signature`
+    pub signature_scheme: SignatureScheme,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::Address;
     use alloy_signer_local::PrivateKeySigner;
-    use ethereum_keys::recover::recover_address;
+    use alloy_sol_types::SolValue;
+    use ethereum_keys::{
+        attestation::{SigningScheme, recover_eip712_signer},
+        recover::recover_address,
+    };
+    use ibc_eureka_solidity_types::msgs::IAttestationMsgs;
 
     use crate::signer::local::LocalSigner;
 
@@ -73,9 +217,15 @@ mod tests {
     async fn sign_attestation_produces_65_byte_signature() {
         let signer = test_signer();
         let tagged = AttestationPayload::new(b"data".to_vec(), AttestationType::State);
-        let result = sign_attestation(100, Some(1_700_000_000), tagged, &signer)
-            .await
-            .unwrap();
+        let result = sign_attestation(
+            100,
+            Some(1_700_000_000),
+            tagged,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+        )
+        .await
+        .unwrap();
         assert_eq!(result.signature.len(), 65);
     }
 
@@ -84,8 +234,17 @@ mod tests {
         let signer = test_signer();
         let original_data = b"original abi-encoded data".to_vec();
         let tagged = AttestationPayload::new(original_data.clone(), AttestationType::Packet);
-        let result = sign_attestation(42, None, tagged, &signer).await.unwrap();
+        let result = sign_attestation(
+            42,
+            None,
+            tagged,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+        )
+        .await
+        .unwrap();
         assert_eq!(result.attested_data, original_data);
+        assert_eq!(result.attestation_type, AttestationType::Packet);
     }
 
     #[tokio::test]
@@ -96,9 +255,15 @@ mod tests {
 
         let data = b"test attestation".to_vec();
         let tagged = AttestationPayload::new(data.clone(), AttestationType::State);
-        let result = sign_attestation(100, Some(123), tagged, &signer)
-            .await
-            .unwrap();
+        let result = sign_attestation(
+            100,
+            Some(123),
+            tagged,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+        )
+        .await
+        .unwrap();
 
         let verify_msg =
             AttestationPayload::new(data, AttestationType::State).tagged_signing_input();
@@ -114,9 +279,15 @@ mod tests {
 
         let data = b"cross-domain replay test".to_vec();
         let tagged = AttestationPayload::new(data.clone(), AttestationType::State);
-        let result = sign_attestation(100, Some(123), tagged, &signer)
-            .await
-            .unwrap();
+        let result = sign_attestation(
+            100,
+            Some(123),
+            tagged,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+        )
+        .await
+        .unwrap();
 
         let wrong_msg =
             AttestationPayload::new(data, AttestationType::Packet).tagged_signing_input();
@@ -132,7 +303,15 @@ mod tests {
 
         let data = b"raw data replay test".to_vec();
         let tagged = AttestationPayload::new(data.clone(), AttestationType::Packet);
-        let result = sign_attestation(100, None, tagged, &signer).await.unwrap();
+        let result = sign_attestation(
+            100,
+            None,
+            tagged,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+        )
+        .await
+        .unwrap();
 
         let recovered = recover_address(&data, &result.signature).unwrap();
         assert_ne!(recovered, expected_address);
@@ -147,6 +326,7 @@ mod tests {
             100,
             Some(123),
             AttestationPayload::new(data.clone(), AttestationType::State),
+            &SigningMode::Tagged(SigningScheme::V1),
             &signer,
         )
         .await
@@ -155,6 +335,7 @@ mod tests {
             100,
             None,
             AttestationPayload::new(data, AttestationType::Packet),
+            &SigningMode::Tagged(SigningScheme::V1),
             &signer,
         )
         .await
@@ -171,6 +352,7 @@ mod tests {
             1,
             None,
             AttestationPayload::new(b"deterministic".to_vec(), AttestationType::State),
+            &SigningMode::Tagged(SigningScheme::V1),
             &signer,
         )
         .await
@@ -179,6 +361,7 @@ mod tests {
             1,
             None,
             AttestationPayload::new(b"deterministic".to_vec(), AttestationType::State),
+            &SigningMode::Tagged(SigningScheme::V1),
             &signer,
         )
         .await
@@ -197,6 +380,7 @@ mod tests {
             100,
             None,
             AttestationPayload::new(packet_data.clone(), AttestationType::Packet),
+            &SigningMode::Tagged(SigningScheme::V1),
             &signer,
         )
         .await
@@ -209,4 +393,96 @@ mod tests {
 
         assert_ne!(recovered, expected_address);
     }
+
+    #[tokio::test]
+    async fn v2_signature_recovers_only_in_its_domain() {
+        let pk_signer = PrivateKeySigner::random();
+        let expected_address = pk_signer.address();
+        let signer = LocalSigner::new(pk_signer);
+
+        let data = b"domain bound data".to_vec();
+        let scheme = SigningScheme::v2("11155111", "client-0");
+        let result = sign_attestation(
+            100,
+            Some(123),
+            AttestationPayload::new(data.clone(), AttestationType::State),
+            &SigningMode::Tagged(scheme),
+            &signer,
+        )
+        .await
+        .unwrap();
+
+        let type_tag = AttestationType::State.as_byte();
+        assert_eq!(
+            scheme
+                .recover_signer(type_tag, &data, &result.signature)
+                .unwrap(),
+            expected_address
+        );
+        assert_ne!(
+            SigningScheme::v2("11155111", "client-1")
+                .recover_signer(type_tag, &data, &result.signature)
+                .unwrap(),
+            expected_address
+        );
+    }
+
+    #[tokio::test]
+    async fn eip712_signature_recovers_from_typed_data() {
+        let pk_signer = PrivateKeySigner::random();
+        let expected_address = pk_signer.address();
+        let signer = LocalSigner::new(pk_signer);
+
+        let attestation = IAttestationMsgs::StateAttestation {
+            height: 100,
+            timestamp: 123,
+        };
+        let domain = alloy_sol_types::eip712_domain! {
+            name: "ibc-attestor",
+            version: "1",
+            chain_id: 1,
+            verifying_contract: Address::with_last_byte(1),
+        };
+        let result = sign_attestation(
+            100,
+            Some(123),
+            AttestationPayload::new(attestation.abi_encode(), AttestationType::State),
+            &SigningMode::Eip712(domain.clone()),
+            &signer,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            recover_eip712_signer(&attestation, &domain, &result.signature).unwrap(),
+            expected_address
+        );
+    }
+
+    #[tokio::test]
+    async fn ed25519_signature_verifies_over_signing_input() {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[3; 32]);
+        let signer = LocalSigner::new_ed25519(signing_key.clone());
+
+        let payload = AttestationPayload::new(b"solana data".to_vec(), AttestationType::State);
+        let signing_input = payload.tagged_signing_input();
+        let result = sign_attestation(
+            100,
+            Some(123),
+            payload,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.signature_scheme, SignatureScheme::Ed25519);
+        let signature = ed25519_dalek::Signature::from_slice(&result.signature).unwrap();
+        assert!(
+            signing_key
+                .verifying_key()
+                .verify_strict(&signing_input, &signature)
+                .is_ok()
+        );
+    }
 }
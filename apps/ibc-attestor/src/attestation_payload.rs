@@ -1,10 +1,23 @@
-use sha2::{Digest, Sha256};
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::{Eip712Domain, SolStruct, SolValue};
+use ethereum_keys::attestation::SigningScheme;
+use ibc_eureka_solidity_types::msgs::IAttestationMsgs;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::{
+    attestation::msgs::{
+        ConsensusStateAttestation, ContractStateAttestation, EventLogAttestation,
+        LatestHeightAttestation, PacketBatchAttestation, StoreKeyAttestation,
+    },
+    config::ConfigError,
+};
 
 /// Distinguishes attestation types in the signing scheme to prevent cross-protocol replay.
 ///
 /// The domain tag byte is prepended before the inner hash, producing a 33-byte message:
 /// `domain_tag || sha256(data)`. The signer hashes this again, so the final signature covers
-/// `sha256(domain_tag || sha256(data))`.
+/// `sha256(domain_tag || sha256(data))`. See [`SigningScheme`] for the domain-bound variant.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum AttestationType {
@@ -12,6 +25,19 @@ pub enum AttestationType {
     State = 0x01,
     /// Packet attestations (height + packets)
     Packet = 0x02,
+    /// Latest height claims (height + chain ID + wall-clock timestamp)
+    LatestHeight = 0x03,
+    /// Packet batch attestations (height + Merkle root of the packets)
+    PacketBatch = 0x04,
+    /// Consensus state attestations (height + timestamp + block hash + state
+    /// root)
+    ConsensusState = 0x05,
+    /// Contract state attestations (height + contract + read + result)
+    ContractState = 0x06,
+    /// Store key attestations (height + store + key + value)
+    StoreKey = 0x07,
+    /// Event log attestations (block position + emitter + topics + data)
+    EventLog = 0x08,
 }
 
 impl AttestationType {
@@ -44,11 +70,13 @@ impl AttestationPayload {
     /// Construct the 33-byte tagged message: `type_tag || sha256(data)`.
     #[must_use]
     pub fn tagged_signing_input(&self) -> Vec<u8> {
-        let inner_hash = Sha256::digest(&self.data);
-        let mut tagged = Vec::with_capacity(33);
-        tagged.push(self.attestation_type.as_byte());
-        tagged.extend_from_slice(&inner_hash);
-        tagged
+        self.signing_input(&SigningScheme::V1)
+    }
+
+    /// Construct the message signed under `scheme`.
+    #[must_use]
+    pub fn signing_input(&self, scheme: &SigningScheme) -> Vec<u8> {
+        scheme.signing_input(self.attestation_type.as_byte(), &self.data)
     }
 
     /// Returns a reference to the raw ABI-encoded data.
@@ -68,17 +96,326 @@ impl AttestationPayload {
     pub const fn attestation_type(&self) -> AttestationType {
         self.attestation_type
     }
+
+    /// Build the EIP-712 signing request of the attestation under `domain`.
+    ///
+    /// The ABI-encoded data is decoded back into the attestation struct of the
+    /// payload's type, whose struct hash and JSON typed data are derived from
+    /// the same values.
+    ///
+    /// # Errors
+    /// Returns an error if the data is not a valid encoding of the struct
+    /// matching the attestation type.
+    pub fn eip712_request(
+        &self,
+        domain: &Eip712Domain,
+    ) -> Result<Eip712Request, alloy_sol_types::Error> {
+        let (digest, primary_type, message) = match self.attestation_type {
+            AttestationType::State => {
+                let attestation = IAttestationMsgs::StateAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "timestamp": attestation.timestamp,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "StateAttestation",
+                    message,
+                )
+            }
+            AttestationType::Packet => {
+                let attestation = IAttestationMsgs::PacketAttestation::abi_decode(&self.data)?;
+                let packets: Vec<Value> = attestation
+                    .packets
+                    .iter()
+                    .map(|packet| json!({ "path": packet.path, "commitment": packet.commitment }))
+                    .collect();
+                let message = json!({ "height": attestation.height, "packets": packets });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "PacketAttestation",
+                    message,
+                )
+            }
+            AttestationType::LatestHeight => {
+                let attestation = LatestHeightAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "chainId": &attestation.chainId,
+                    "timestamp": attestation.timestamp,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "LatestHeightAttestation",
+                    message,
+                )
+            }
+            AttestationType::PacketBatch => {
+                let attestation = PacketBatchAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "root": attestation.root,
+                    "size": attestation.size,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "PacketBatchAttestation",
+                    message,
+                )
+            }
+            AttestationType::ConsensusState => {
+                let attestation = ConsensusStateAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "timestamp": attestation.timestamp,
+                    "blockHash": attestation.blockHash,
+                    "root": attestation.root,
+                    "validatorsHash": attestation.validatorsHash,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "ConsensusStateAttestation",
+                    message,
+                )
+            }
+            AttestationType::ContractState => {
+                let attestation = ContractStateAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "target": attestation.target,
+                    "storage": attestation.storage,
+                    "input": &attestation.input,
+                    "result": &attestation.result,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "ContractStateAttestation",
+                    message,
+                )
+            }
+            AttestationType::StoreKey => {
+                let attestation = StoreKeyAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "store": &attestation.store,
+                    "key": &attestation.key,
+                    "value": &attestation.value,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "StoreKeyAttestation",
+                    message,
+                )
+            }
+            AttestationType::EventLog => {
+                let attestation = EventLogAttestation::abi_decode(&self.data)?;
+                let message = json!({
+                    "height": attestation.height,
+                    "blockHash": attestation.blockHash,
+                    "txIndex": attestation.txIndex,
+                    "logIndex": attestation.logIndex,
+                    "emitter": attestation.emitter,
+                    "topics": &attestation.topics,
+                    "data": &attestation.data,
+                });
+                (
+                    attestation.eip712_signing_hash(domain),
+                    "EventLogAttestation",
+                    message,
+                )
+            }
+        };
+
+        Ok(Eip712Request {
+            digest,
+            typed_data: json!({
+                "types": eip712_types(),
+                "primaryType": primary_type,
+                "domain": {
+                    "name": domain.name,
+                    "version": domain.version,
+                    "chainId": domain.chain_id.map(|chain_id| chain_id.to_string()),
+                    "verifyingContract": domain.verifying_contract,
+                },
+                "message": message,
+            }),
+        })
+    }
+}
+
+/// EIP-712 digest of an attestation and the typed data it was computed from.
+#[derive(Clone, Debug)]
+pub struct Eip712Request {
+    /// `keccak256(0x1901 || domainSeparator || hashStruct(message))`
+    pub digest: B256,
+    /// Typed data in the `eth_signTypedData_v4` JSON format
+    pub typed_data: Value,
+}
+
+/// Type definitions of all attestation structs, in the `eth_signTypedData_v4`
+/// JSON format. Only the domain fields set by [`SigningConfig::Eip712`] are
+/// declared.
+fn eip712_types() -> Value {
+    json!({
+        "EIP712Domain": [
+            { "name": "name", "type": "string" },
+            { "name": "version", "type": "string" },
+            { "name": "chainId", "type": "uint256" },
+            { "name": "verifyingContract", "type": "address" },
+        ],
+        "StateAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "timestamp", "type": "uint64" },
+        ],
+        "PacketAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "packets", "type": "PacketCompact[]" },
+        ],
+        "PacketCompact": [
+            { "name": "path", "type": "bytes32" },
+            { "name": "commitment", "type": "bytes32" },
+        ],
+        "LatestHeightAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "chainId", "type": "string" },
+            { "name": "timestamp", "type": "uint64" },
+        ],
+        "PacketBatchAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "root", "type": "bytes32" },
+            { "name": "size", "type": "uint64" },
+        ],
+        "ConsensusStateAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "timestamp", "type": "uint64" },
+            { "name": "blockHash", "type": "bytes32" },
+            { "name": "root", "type": "bytes32" },
+            { "name": "validatorsHash", "type": "bytes32" },
+        ],
+        "ContractStateAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "target", "type": "address" },
+            { "name": "storage", "type": "bool" },
+            { "name": "input", "type": "bytes" },
+            { "name": "result", "type": "bytes" },
+        ],
+        "StoreKeyAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "store", "type": "string" },
+            { "name": "key", "type": "bytes" },
+            { "name": "value", "type": "bytes" },
+        ],
+        "EventLogAttestation": [
+            { "name": "height", "type": "uint64" },
+            { "name": "blockHash", "type": "bytes32" },
+            { "name": "txIndex", "type": "uint64" },
+            { "name": "logIndex", "type": "uint64" },
+            { "name": "emitter", "type": "address" },
+            { "name": "topics", "type": "bytes32[]" },
+            { "name": "data", "type": "bytes" },
+        ],
+    })
+}
+
+/// How attestations are turned into the message the signer signs.
+#[derive(Clone, Debug)]
+pub enum SigningMode {
+    /// Sign the tagged signing input of the [`SigningScheme`]
+    Tagged(SigningScheme),
+    /// Sign the EIP-712 typed data hash under the domain
+    Eip712(Eip712Domain),
+}
+
+/// Signing scheme configuration.
+///
+/// `v1` signatures only separate attestation types, so they replay between
+/// chains and light clients sharing an attestor key. `v2` additionally binds
+/// them to the attested chain and the light client verifying them. `eip712`
+/// signs typed data hashes, which wallets and signing policy engines can
+/// display and audit.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(tag = "scheme", rename_all = "snake_case")]
+pub enum SigningConfig {
+    /// `type_tag || sha256(data)`
+    #[default]
+    V1,
+    /// `type_tag || sha256(abi.encode(chain_id, client_id)) || sha256(data)`
+    V2 {
+        /// Identifier of the attested chain
+        chain_id: String,
+        /// Light client verifying the attestations on the counterparty
+        client_id: String,
+    },
+    /// EIP-712 typed data hash under the configured domain
+    Eip712 {
+        /// Domain name
+        name: String,
+        /// Domain version
+        version: String,
+        /// EIP-155 chain ID of the verifying contract
+        chain_id: u64,
+        /// Contract verifying the attestations
+        verifying_contract: Address,
+    },
+}
+
+impl SigningConfig {
+    pub(crate) fn validate(self) -> Result<Self, ConfigError> {
+        let error = match &self {
+            Self::V2 {
+                chain_id,
+                client_id,
+            } if chain_id.is_empty() || client_id.is_empty() => {
+                Some("`signing.chain_id` and `signing.client_id` must not be empty")
+            }
+            Self::Eip712 { name, version, .. } if name.is_empty() || version.is_empty() => {
+                Some("`signing.name` and `signing.version` must not be empty")
+            }
+            _ => None,
+        };
+
+        match error {
+            Some(error) => Err(ConfigError::InvalidSigningConfig(error.to_string())),
+            None => Ok(self),
+        }
+    }
+
+    /// Build the configured [`SigningMode`].
+    #[must_use]
+    pub fn mode(&self) -> SigningMode {
+        match self {
+            Self::V1 => SigningMode::Tagged(SigningScheme::V1),
+            Self::V2 {
+                chain_id,
+                client_id,
+            } => SigningMode::Tagged(SigningScheme::v2(chain_id, client_id)),
+            Self::Eip712 {
+                name,
+                version,
+                chain_id,
+                verifying_contract,
+            } => SigningMode::Eip712(Eip712Domain::new(
+                Some(name.clone().into()),
+                Some(version.clone().into()),
+                Some(U256::from(*chain_id)),
+                Some(*verifying_contract),
+                None,
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy_primitives::B256;
+    use sha2::{Digest, Sha256};
 
     #[test]
     fn as_byte_returns_expected_values() {
         assert_eq!(AttestationType::State.as_byte(), 0x01);
         assert_eq!(AttestationType::Packet.as_byte(), 0x02);
+        assert_eq!(AttestationType::LatestHeight.as_byte(), 0x03);
     }
 
     #[test]
@@ -177,4 +514,116 @@ mod tests {
         let actual_digest = B256::from_slice(&Sha256::digest(&msg));
         assert_eq!(actual_digest, expected_digest);
     }
+
+    #[test]
+    fn signing_config_defaults_to_v1() {
+        assert!(matches!(
+            SigningConfig::default().mode(),
+            SigningMode::Tagged(SigningScheme::V1)
+        ));
+    }
+
+    #[test]
+    fn signing_config_v2_binds_chain_and_client() {
+        let config: SigningConfig = toml::from_str(
+            r#"
+            scheme = "v2"
+            chain_id = "11155111"
+            client_id = "client-0"
+            "#,
+        )
+        .unwrap();
+        let SigningMode::Tagged(scheme) = config.validate().unwrap().mode() else {
+            panic!("v2 must use a tagged signing input");
+        };
+        assert_eq!(scheme, SigningScheme::v2("11155111", "client-0"));
+
+        let payload = AttestationPayload::new(b"data".to_vec(), AttestationType::State);
+        assert_ne!(
+            payload.signing_input(&scheme),
+            payload.tagged_signing_input()
+        );
+    }
+
+    #[test]
+    fn signing_config_v2_rejects_empty_client_id() {
+        let config = SigningConfig::V2 {
+            chain_id: "11155111".to_string(),
+            client_id: String::new(),
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidSigningConfig(_))
+        ));
+    }
+
+    fn test_domain() -> Eip712Domain {
+        let config: SigningConfig = toml::from_str(
+            r#"
+            scheme = "eip712"
+            name = "ibc-attestor"
+            version = "1"
+            chain_id = 11155111
+            verifying_contract = "0x0000000000000000000000000000000000000001"
+            "#,
+        )
+        .unwrap();
+        let SigningMode::Eip712(domain) = config.validate().unwrap().mode() else {
+            panic!("eip712 config must build an EIP-712 domain");
+        };
+        domain
+    }
+
+    #[test]
+    fn signing_config_eip712_builds_domain() {
+        let domain = test_domain();
+        assert_eq!(domain.name.as_deref(), Some("ibc-attestor"));
+        assert_eq!(domain.version.as_deref(), Some("1"));
+        assert_eq!(domain.chain_id, Some(U256::from(11_155_111)));
+        assert_eq!(domain.verifying_contract, Some(Address::with_last_byte(1)));
+    }
+
+    #[test]
+    fn eip712_request_hashes_state_attestation() {
+        let domain = test_domain();
+        let attestation = IAttestationMsgs::StateAttestation {
+            height: 100,
+            timestamp: 1_700_000_000,
+        };
+        let payload = AttestationPayload::new(attestation.abi_encode(), AttestationType::State);
+
+        let request = payload.eip712_request(&domain).unwrap();
+        assert_eq!(request.digest, attestation.eip712_signing_hash(&domain));
+        assert_eq!(request.typed_data["primaryType"], "StateAttestation");
+        assert_eq!(request.typed_data["domain"]["chainId"], "11155111");
+        assert_eq!(request.typed_data["message"]["height"], 100);
+        assert_eq!(request.typed_data["message"]["timestamp"], 1_700_000_000);
+    }
+
+    #[test]
+    fn eip712_request_lists_packets() {
+        let domain = test_domain();
+        let attestation = IAttestationMsgs::PacketAttestation {
+            height: 7,
+            packets: vec![IAttestationMsgs::PacketCompact {
+                path: B256::repeat_byte(0xaa),
+                commitment: B256::repeat_byte(0xbb),
+            }],
+        };
+        let payload = AttestationPayload::new(attestation.abi_encode(), AttestationType::Packet);
+
+        let request = payload.eip712_request(&domain).unwrap();
+        assert_eq!(request.digest, attestation.eip712_signing_hash(&domain));
+        assert_eq!(request.typed_data["primaryType"], "PacketAttestation");
+        assert_eq!(
+            request.typed_data["message"]["packets"][0]["path"],
+            format!("0x{}", "aa".repeat(32))
+        );
+    }
+
+    #[test]
+    fn eip712_request_rejects_mismatched_data() {
+        let payload = AttestationPayload::new(vec![1, 2, 3], AttestationType::Packet);
+        assert!(payload.eip712_request(&test_domain()).is_err());
+    }
 }
@@ -104,6 +104,10 @@ pub mod key {
         /// Custom keystore directory path. If not specified, uses ~/.ibc-attestor/
         #[clap(long)]
         pub keystore: Option<PathBuf>,
+        /// Generate a BLS12-381 key and print its proof of possession
+        #[cfg(feature = "bls")]
+        #[clap(long)]
+        pub bls: bool,
     }
 
     #[derive(Clone, Debug, Parser)]
@@ -115,5 +119,9 @@ pub mod key {
         /// Custom keystore directory path. If not specified, uses ~/.ibc-attestor/
         #[clap(long)]
         pub keystore: Option<PathBuf>,
+        /// Show the BLS12-381 key and its proof of possession
+        #[cfg(feature = "bls")]
+        #[clap(long)]
+        pub bls: bool,
     }
 }
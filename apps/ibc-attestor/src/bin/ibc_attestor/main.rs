@@ -1,13 +1,25 @@
-use std::{env, fs, path::PathBuf};
+#[cfg(feature = "bls")]
+use std::path::Path;
+use std::{env, fs, path::PathBuf, sync::Arc, time::Duration};
 
 use alloy_signer_local::PrivateKeySigner;
 use clap::Parser;
 use ethereum_keys::signer_local::{read_from_keystore, write_to_keystore};
+#[cfg(feature = "bls")]
+use ibc_attestor::signer::bls::{BlsKey, DEFAULT_BLS_KEY_NAME};
 use ibc_attestor::{
     config::RuntimeConfig,
+    head::{HeadLimits, HeadTracker},
     logging::init_logging,
-    rpc::{RpcError, health, server},
-    signer::local::DEFAULT_KEYSTORE_NAME,
+    pause::PauseSwitch,
+    rpc::{
+        ProvenIdentity, RpcError,
+        health::{self, ReadinessProbe},
+        server,
+    },
+    signer::{local::DEFAULT_KEYSTORE_NAME, pausable::PausableSigner},
+    sink::Publisher,
+    watcher::{self, AttestationStore},
 };
 
 use tokio::{
@@ -35,7 +47,7 @@ fn default_attestor_dir() -> Result<PathBuf, anyhow::Error> {
 
 type ServerHandles = (JoinHandle<Result<(), RpcError>>, JoinHandle<()>);
 
-fn run_servers(
+async fn run_servers(
     config: RuntimeConfig,
     shutdown_tx: &broadcast::Sender<()>,
 ) -> Result<ServerHandles, anyhow::Error> {
@@ -49,21 +61,84 @@ fn run_servers(
 
     let grpc_addr = server_config.listen_addr;
     let health_addr = server_config.health_addr;
+    let admin_addr = server_config.admin_addr;
 
+    let adapter = Arc::new(config.adapter);
+    let head = HeadTracker::spawn(
+        Arc::clone(&adapter),
+        Duration::from_millis(server_config.head_poll_interval_ms),
+        HeadLimits {
+            max_stall: Duration::from_secs(server_config.max_head_stall_secs),
+            max_clock_skew: Duration::from_secs(server_config.max_clock_skew_secs),
+            max_block_age: server_config.max_block_age_secs.map(Duration::from_secs),
+        },
+    );
+    let pause = PauseSwitch::default();
+    if let Some(pause_file) = config.pause_file {
+        pause.watch_file(pause_file);
+    }
+    // Proven on first use through the kill switch, so the attestor starts
+    // while the signer is unreachable or paused
+    let identity = Arc::new(ProvenIdentity::default());
+    let signer = Arc::new(PausableSigner::new(config.signer, pause.clone()));
+    let mode = config.signing.mode();
+    let publisher = Publisher::spawn(config.sinks);
+    // Shared so the watcher counts transfer windows with the RPC server
+    let policy = Arc::new(config.policy);
+
+    let store = config.watcher.map(|watcher_config| {
+        let store = Arc::new(AttestationStore::new(watcher_config.store_capacity));
+        // Detached, the watcher runs for the lifetime of the process
+        drop(watcher::spawn(
+            watcher_config,
+            Arc::clone(&adapter),
+            mode.clone(),
+            Arc::clone(&signer),
+            Arc::clone(&policy),
+            publisher.clone(),
+            &head,
+            Arc::clone(&store),
+        ));
+        store
+    });
+
+    let probe = Arc::new(ReadinessProbe::new(
+        grpc_addr,
+        Arc::clone(&adapter),
+        Arc::clone(&signer),
+        Arc::clone(&identity),
+        pause.clone(),
+        head.clone(),
+        Duration::from_millis(server_config.readiness_timeout_ms),
+    ));
+
+    let grpc_pause = pause.clone();
+    let grpc_probe = Arc::clone(&probe);
     let grpc_handle = tokio::spawn(async move {
         server::start(
             grpc_addr,
-            config.adapter,
+            adapter,
             adapter_name,
-            config.signer,
+            signer,
             signer_name,
+            identity,
+            mode,
+            policy,
+            head,
+            store,
+            publisher,
+            config.limits,
+            grpc_pause,
+            admin_addr,
+            config.admin_token,
+            grpc_probe,
             grpc_shutdown_rx,
         )
         .await
     });
 
     let health_handle = tokio::spawn(async move {
-        health::start(health_addr, grpc_addr, health_shutdown_rx).await;
+        health::start(health_addr, pause, probe, health_shutdown_rx).await;
     });
 
     Ok((grpc_handle, health_handle))
@@ -85,7 +160,7 @@ async fn main() -> Result<(), anyhow::Error> {
             // Create shutdown broadcast channel
             let (shutdown_tx, _shutdown_rx) = broadcast::channel(1);
 
-            let (grpc_handle, health_handle) = run_servers(config, &shutdown_tx)?;
+            let (grpc_handle, health_handle) = run_servers(config, &shutdown_tx).await?;
 
             _ = wait_for_shutdown_signal().await;
             info!("shutdown signal received, starting graceful shutdown");
@@ -100,6 +175,10 @@ async fn main() -> Result<(), anyhow::Error> {
                         Some(path) => path,
                         None => default_attestor_dir()?,
                     };
+                    #[cfg(feature = "bls")]
+                    if args.bls {
+                        return generate_bls_key(&attestor_dir);
+                    }
                     let keystore_path = attestor_dir.join(DEFAULT_KEYSTORE_NAME);
 
                     if !attestor_dir.exists() {
@@ -123,6 +202,10 @@ async fn main() -> Result<(), anyhow::Error> {
                         Some(path) => path,
                         None => default_attestor_dir()?,
                     };
+                    #[cfg(feature = "bls")]
+                    if args.bls {
+                        return show_bls_key(&attestor_dir, args.show_private, args.show_public);
+                    }
                     let keystore_path = attestor_dir.join(DEFAULT_KEYSTORE_NAME);
 
                     let mut printed_any = false;
@@ -152,6 +235,56 @@ async fn main() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+/// Generate a BLS12-381 key in `attestor_dir` and print its public key and
+/// proof of possession, both needed to register the key.
+#[cfg(feature = "bls")]
+fn generate_bls_key(attestor_dir: &Path) -> Result<(), anyhow::Error> {
+    let key_path = attestor_dir.join(DEFAULT_BLS_KEY_NAME);
+
+    if !attestor_dir.exists() {
+        fs::create_dir_all(attestor_dir)?;
+    }
+
+    if key_path.exists() {
+        return Err(anyhow::anyhow!(
+            "key pair already found at {key_path:?}; aborting"
+        ));
+    }
+
+    let key = BlsKey::random()?;
+    key.write_to_file(&key_path)?;
+    println!("key successfully saved to {key_path:?}");
+    print_bls_public_key(&key);
+    Ok(())
+}
+
+/// Print the BLS12-381 key stored in `attestor_dir`.
+#[cfg(feature = "bls")]
+fn show_bls_key(
+    attestor_dir: &Path,
+    show_private: bool,
+    show_public: bool,
+) -> Result<(), anyhow::Error> {
+    let key = BlsKey::read_from_file(&attestor_dir.join(DEFAULT_BLS_KEY_NAME))?;
+
+    if show_private {
+        println!("private key: {}", hex::encode(key.secret_key_bytes()));
+    }
+    if show_public {
+        print_bls_public_key(&key);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "bls")]
+fn print_bls_public_key(key: &BlsKey) {
+    println!("public key: {}", hex::encode(key.public_key_bytes()));
+    println!(
+        "proof of possession: {}",
+        hex::encode(key.proof_of_possession())
+    );
+}
+
 /// Wait for a shutdown signal (SIGTERM or SIGINT).
 ///
 /// # Panics
@@ -1,5 +1,9 @@
 //! Defines the top level configuration for the attestor.
-use std::{fs, net::SocketAddr, path::Path};
+use std::{
+    fs,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+};
 
 use serde::Deserialize;
 use thiserror::Error;
@@ -11,11 +15,15 @@ use crate::adapter::{
     evm::{EvmAdapterBuilder, EvmAdapterConfig},
     solana::{SolanaAdapterBuilder, SolanaAdapterConfig},
 };
+use crate::attestation_payload::SigningConfig;
+use crate::policy::{Policy, PolicyConfig};
 use crate::signer::{
-    SignerBuilder, SignerEnum, SignerError,
+    SignatureScheme, Signer, SignerBuilder, SignerEnum, SignerError,
     local::{LocalSigner, LocalSignerConfig},
     remote::{RemoteSigner, RemoteSignerConfig},
 };
+use crate::sink::{SinkConfig, SinkEnum, SinkError};
+use crate::watcher::WatcherConfig;
 
 /// The type of blockchain adapter to use.
 #[derive(Clone, Debug)]
@@ -50,6 +58,20 @@ pub struct RuntimeConfig {
     pub signer: SignerEnum,
     /// Optional tracing configuration for OpenTelemetry export.
     pub tracing: Option<TracingConfig>,
+    /// Optional watcher configuration for pre-computing packet attestations.
+    pub watcher: Option<WatcherConfig>,
+    /// The built sinks every signed attestation is published to.
+    pub sinks: Vec<SinkEnum>,
+    /// The scheme attestations are signed with.
+    pub signing: SigningConfig,
+    /// The clients and packets attestations may be signed for.
+    pub policy: Policy,
+    /// File pausing all signing while it exists.
+    pub pause_file: Option<PathBuf>,
+    /// Bearer token of the admin service, which is only served if set.
+    pub admin_token: Option<String>,
+    /// Bounds on the chain and signer work a single request may cause.
+    pub limits: RequestLimits,
 }
 
 /// Raw TOML structure used for partial deserialization. The `adapter` and
@@ -61,6 +83,17 @@ struct RawConfig {
     adapter: toml::Value,
     signer: toml::Value,
     tracing: Option<TracingConfig>,
+    watcher: Option<WatcherConfig>,
+    #[serde(default)]
+    sinks: Vec<SinkConfig>,
+    #[serde(default)]
+    signing: SigningConfig,
+    #[serde(default)]
+    policy: PolicyConfig,
+    #[serde(default)]
+    admin: AdminConfig,
+    #[serde(default)]
+    limits: RequestLimits,
 }
 
 impl RuntimeConfig {
@@ -109,12 +142,52 @@ impl RuntimeConfig {
         .map_err(ConfigError::Signer)?;
 
         let tracing = raw.tracing.map(TracingConfig::validate).transpose()?;
+        let watcher = raw.watcher.map(WatcherConfig::validate).transpose()?;
+        if watcher.is_some() && matches!(chain_type, ChainType::Solana) {
+            // The Solana adapter decodes router events of a given transaction
+            // only and can't scan a slot range for them
+            return Err(ConfigError::InvalidWatcherConfig(
+                "the watcher is not supported for Solana".to_string(),
+            ));
+        }
+        let sinks = raw
+            .sinks
+            .into_iter()
+            .map(SinkEnum::build)
+            .collect::<Result<_, _>>()
+            .map_err(ConfigError::Sink)?;
+        let signing = raw.signing.validate()?;
+        let policy = raw.policy.validate()?.into();
+        let admin_token = raw.admin.read_token()?;
+        let limits = raw.limits.validate()?;
+        if signer.signature_scheme() != SignatureScheme::Secp256k1
+            && matches!(signing, SigningConfig::Eip712 { .. })
+        {
+            return Err(ConfigError::InvalidSigningConfig(
+                "eip712 signing requires a secp256k1 signer".to_string(),
+            ));
+        }
+        if matches!(signer, SignerEnum::Remote(_))
+            && matches!(signing, SigningConfig::Eip712 { .. })
+        {
+            // The remote signer service has no typed data payload yet
+            return Err(ConfigError::Signer(SignerError::Unsupported(
+                "eip712 signing with a remote signer".to_string(),
+            )));
+        }
 
         Ok(Self {
             server: raw.server,
             adapter,
             signer,
             tracing,
+            watcher,
+            sinks,
+            signing,
+            policy,
+            pause_file: raw.admin.pause_file,
+            admin_token,
+            limits,
         })
     }
 }
@@ -126,6 +199,128 @@ pub struct ServerConfig {
     pub listen_addr: SocketAddr,
     /// The address that the HTTP health server should listen on.
     pub health_addr: SocketAddr,
+    /// The address that the admin gRPC service listens on, if enabled. Kept
+    /// off the public gRPC port, localhost by default.
+    #[serde(default = "default_admin_addr")]
+    pub admin_addr: SocketAddr,
+    /// How often the finalized head is polled for streaming subscribers, in
+    /// milliseconds.
+    #[serde(default = "default_head_poll_interval_ms")]
+    pub head_poll_interval_ms: u64,
+    /// Deadline of the chain RPC and signer readiness checks, in
+    /// milliseconds.
+    #[serde(default = "default_readiness_timeout_ms")]
+    pub readiness_timeout_ms: u64,
+    /// How long the finalized height may not advance before the attestor
+    /// reports not ready, in seconds.
+    #[serde(default = "default_max_head_stall_secs")]
+    pub max_head_stall_secs: u64,
+    /// How far the latest finalized block timestamp may be ahead of the local
+    /// clock before the attestor reports not ready, in seconds.
+    #[serde(default = "default_max_clock_skew_secs")]
+    pub max_clock_skew_secs: u64,
+    /// How far the latest finalized block timestamp may be behind the local
+    /// clock before the attestor reports not ready, in seconds. Unbounded if
+    /// unset, as the finality delay depends on the chain.
+    #[serde(default)]
+    pub max_block_age_secs: Option<u64>,
+}
+
+const fn default_admin_addr() -> SocketAddr {
+    SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8082)
+}
+
+const fn default_head_poll_interval_ms() -> u64 {
+    1_000
+}
+
+const fn default_readiness_timeout_ms() -> u64 {
+    2_000
+}
+
+const fn default_max_head_stall_secs() -> u64 {
+    // Ethereum finalizes once per epoch, allow for a few missed ones
+    1_800
+}
+
+const fn default_max_clock_skew_secs() -> u64 {
+    60
+}
+
+/// Bounds on the chain and signer work a single request may cause, as the
+/// attestation service is unauthenticated.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default)]
+pub struct RequestLimits {
+    /// Largest height range scanned by `UnrelayedPackets`, in blocks.
+    pub max_unrelayed_scan_blocks: u64,
+    /// Most packets returned by `UnrelayedPackets`.
+    pub max_unrelayed_packets: usize,
+    /// Commitment lookups `UnrelayedPackets` runs concurrently.
+    pub unrelayed_lookup_concurrency: usize,
+    /// Furthest a `SubscribeStateAttestations` start height may be below the
+    /// finalized head, in blocks.
+    pub max_subscription_backfill_blocks: u64,
+    /// Most `SubscribeStateAttestations` streams served at once.
+    pub max_subscriptions: usize,
+}
+
+impl Default for RequestLimits {
+    fn default() -> Self {
+        Self {
+            max_unrelayed_scan_blocks: 10_000,
+            max_unrelayed_packets: 1_000,
+            unrelayed_lookup_concurrency: 16,
+            max_subscription_backfill_blocks: 1_000,
+            max_subscriptions: 64,
+        }
+    }
+}
+
+impl RequestLimits {
+    fn validate(self) -> Result<Self, ConfigError> {
+        if self.max_unrelayed_scan_blocks == 0
+            || self.max_unrelayed_packets == 0
+            || self.unrelayed_lookup_concurrency == 0
+            || self.max_subscriptions == 0
+        {
+            return Err(ConfigError::InvalidLimitsConfig(
+                "`limits` values other than `max_subscription_backfill_blocks` must be greater than 0"
+                    .to_string(),
+            ));
+        }
+
+        Ok(self)
+    }
+}
+
+/// Operator controls to pause signing during an incident.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct AdminConfig {
+    /// Signing is paused while this file exists.
+    pub pause_file: Option<PathBuf>,
+    /// File holding the bearer token of the admin gRPC service. The service
+    /// is only served if set.
+    pub token_file: Option<PathBuf>,
+}
+
+impl AdminConfig {
+    fn read_token(&self) -> Result<Option<String>, ConfigError> {
+        let Some(path) = &self.token_file else {
+            return Ok(None);
+        };
+        let token =
+            fs::read_to_string(path).map_err(|e| ConfigError::Io(path.display().to_string(), e))?;
+        let token = token.trim();
+        if token.is_empty() {
+            return Err(ConfigError::InvalidAdminConfig(format!(
+                "admin token file `{}` is empty",
+                path.display()
+            )));
+        }
+
+        Ok(Some(token.to_string()))
+    }
 }
 
 /// Configuration for OpenTelemetry tracing export.
@@ -170,6 +365,26 @@ pub enum ConfigError {
     #[error("invalid tracing config: {0}")]
     InvalidTracingConfig(String),
 
+    /// Invalid watcher section values
+    #[error("invalid watcher config: {0}")]
+    InvalidWatcherConfig(String),
+
+    /// Invalid signing section values
+    #[error("invalid signing config: {0}")]
+    InvalidSigningConfig(String),
+
+    /// Invalid policy section values
+    #[error("invalid policy config: {0}")]
+    InvalidPolicyConfig(String),
+
+    /// Invalid limits section values
+    #[error("invalid limits config: {0}")]
+    InvalidLimitsConfig(String),
+
+    /// Invalid admin section values
+    #[error("invalid admin config: {0}")]
+    InvalidAdminConfig(String),
+
     /// Adapter build failure
     #[error(transparent)]
     Adapter(AttestationAdapterError),
@@ -177,4 +392,8 @@ pub enum ConfigError {
     /// Signer build failure
     #[error(transparent)]
     Signer(SignerError),
+
+    /// Sink build failure
+    #[error(transparent)]
+    Sink(SinkError),
 }
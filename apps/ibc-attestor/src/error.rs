@@ -1,23 +1,55 @@
 use alloy::sol_types::Error as AbiError;
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::time::Duration;
 use thiserror::Error;
 use tonic::{Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
 
 use crate::adapter::AttestationAdapterError;
+use crate::rpc::api::ErrorReason;
 use crate::signer::SignerError;
 
+/// Domain reported in the `google.rpc.ErrorInfo` status detail
+pub const ERROR_DOMAIN: &str = "ibc-attestor";
+
+/// Suggested back-off before retrying a request for a not yet finalized height
+const NOT_FINALIZED_RETRY_DELAY: Duration = Duration::from_secs(1);
+/// Suggested back-off before retrying after the chain RPC failed
+const RPC_UNAVAILABLE_RETRY_DELAY: Duration = Duration::from_secs(2);
+/// Suggested back-off before retrying after the signer failed
+const SIGNER_UNAVAILABLE_RETRY_DELAY: Duration = Duration::from_secs(5);
+/// Suggested back-off before subscribing again while all subscriptions are
+/// taken
+const RESOURCE_EXHAUSTED_RETRY_DELAY: Duration = Duration::from_secs(10);
+
 /// Errors that can occur while working with attestor
 #[derive(Debug, Error)]
 pub enum AttestorError {
     /// Requested block is not finalized
-    #[error("Block is not finalized")]
-    BlockNotFinalized,
-
-    /// Malformed commitment
-    #[error("Packet commitment found but invalid due to: {reason}")]
-    InvalidCommitment {
-        /// Why commitment is bad
-        reason: String,
+    #[error("Block at height={height} is not finalized, finalized height={finalized_height}")]
+    BlockNotFinalized {
+        /// Requested block height
+        height: u64,
+        /// Latest finalized block height
+        finalized_height: u64,
+    },
+
+    /// Commitment on chain differs from the expected value
+    #[error(
+        "Commitment mismatch client_id={client_id}, sequence={sequence} at height={height}: expected 0x{expected}, got 0x{actual}"
+    )]
+    CommitmentMismatch {
+        /// Client Id
+        client_id: String,
+        /// Sequence ID
+        sequence: u64,
+        /// Block height
+        height: u64,
+        /// Hex encoded expected commitment
+        expected: String,
+        /// Hex encoded on-chain commitment
+        actual: String,
     },
 
     /// Missing commitment
@@ -31,10 +63,106 @@ pub enum AttestorError {
         height: u64,
     },
 
-    /// Failed to sign data
+    /// Missing transaction
+    #[error("Transaction not found tx_hash={tx_hash}")]
+    TransactionNotFound {
+        /// Chain-native transaction hash
+        tx_hash: String,
+    },
+
+    /// Missing event log
+    #[error("Event log not found tx_hash={tx_hash}, log_index={log_index}")]
+    EventLogNotFound {
+        /// Chain-native transaction hash
+        tx_hash: String,
+        /// Index of the log in the block
+        log_index: u64,
+    },
+
+    /// Event log topics differ from the expected topics
+    #[error("Event log topics mismatch tx_hash={tx_hash}, log_index={log_index}")]
+    EventLogMismatch {
+        /// Chain-native transaction hash
+        tx_hash: String,
+        /// Index of the log in the block
+        log_index: u64,
+    },
+
+    /// No pre-computed attestation is stored for the packet
+    #[error("Attestation not found client_id={client_id}, sequence={sequence}")]
+    AttestationNotFound {
+        /// Client Id
+        client_id: String,
+        /// Sequence ID
+        sequence: u64,
+    },
+
+    /// The watcher is not configured
+    #[error("Watcher is not enabled")]
+    WatcherDisabled,
+
+    /// No finalized block has reached the requested timestamp yet
+    #[error("No finalized block has timestamp>={timestamp}, finalized height={finalized_height}")]
+    TimestampNotReached {
+        /// Requested UNIX timestamp in seconds
+        timestamp: u64,
+        /// Latest finalized block height
+        finalized_height: u64,
+    },
+
+    /// Height range is empty
+    #[error("Invalid height range from_height={from_height} to_height={to_height}")]
+    InvalidHeightRange {
+        /// First height of the range
+        from_height: u64,
+        /// Last height of the range
+        to_height: u64,
+    },
+
+    /// Height range spans more blocks than a request may scan
+    #[error(
+        "Height range from_height={from_height} to_height={to_height} exceeds the maximum of {max_blocks} blocks"
+    )]
+    HeightRangeTooLarge {
+        /// First height of the range
+        from_height: u64,
+        /// Last height of the range
+        to_height: u64,
+        /// Largest number of blocks a range may span
+        max_blocks: u64,
+    },
+
+    /// All streaming subscriptions are taken
+    #[error("Too many subscriptions, max_subscriptions={max_subscriptions}")]
+    TooManySubscriptions {
+        /// Largest number of concurrent subscriptions
+        max_subscriptions: usize,
+    },
+
+    /// Packet batch attestation requested for no packets
+    #[error("Packet batch is empty")]
+    EmptyPacketBatch,
+
+    /// Contract state read is missing or malformed
+    #[error("Invalid contract read: {0}")]
+    InvalidContractRead(String),
+
+    /// Request is outside the configured signing policy
+    #[error("Denied by policy: {0}")]
+    PolicyViolation(String),
+
+    /// Signing is paused by the operator
+    #[error("Signing is paused by the operator")]
+    SigningPaused,
+
+    /// Signer could not be reached or failed transiently
     #[error("Failed to sign attestation due to: {0}")]
     SignerError(String),
 
+    /// Signer rejected the request or returned an unusable signature
+    #[error("Signer fault: {0}")]
+    SignerFault(String),
+
     /// Failed to initialize signer
     #[error("Signer initialization failed: {0}")]
     SignerInitError(#[from] SignerError),
@@ -52,24 +180,203 @@ pub enum AttestorError {
     MalformedCommitmentError(#[from] prost::UnknownEnumValue),
 }
 
-impl From<AttestorError> for Status {
-    fn from(value: AttestorError) -> Self {
-        match value {
-            AttestorError::BlockNotFinalized => {
-                Self::new(Code::FailedPrecondition, value.to_string())
+impl AttestorError {
+    /// Error for a failed signing operation, keeping an operator pause and
+    /// transient signer failures distinguishable from signer faults.
+    pub(crate) fn signing_failed(error: &SignerError) -> Self {
+        match error {
+            SignerError::Paused => Self::SigningPaused,
+            e if e.is_transient() => Self::SignerError(e.to_string()),
+            e => Self::SignerFault(e.to_string()),
+        }
+    }
+
+    /// Machine-readable reason reported in the status details.
+    #[must_use]
+    pub const fn reason(&self) -> ErrorReason {
+        match self {
+            Self::BlockNotFinalized { .. }
+            | Self::TimestampNotReached { .. }
+            | Self::AdapterError(AttestationAdapterError::BlockNotFinalized) => {
+                ErrorReason::BlockNotFinalized
             }
-            AttestorError::CommitmentNotFound { .. } => {
-                Self::new(Code::NotFound, value.to_string())
+            Self::CommitmentMismatch { .. }
+            | Self::AdapterError(AttestationAdapterError::CommitmentError(_)) => {
+                ErrorReason::CommitmentMismatch
             }
-            AttestorError::InvalidCommitment { .. }
-            | AttestorError::MalformedCommitmentError(_) => {
-                Self::new(Code::InvalidArgument, value.to_string())
+            Self::CommitmentNotFound { .. } => ErrorReason::CommitmentNotFound,
+            Self::TransactionNotFound { .. } => ErrorReason::TransactionNotFound,
+            Self::EventLogNotFound { .. } => ErrorReason::EventLogNotFound,
+            Self::AttestationNotFound { .. } => ErrorReason::AttestationNotFound,
+            Self::AbiError(_)
+            | Self::MalformedCommitmentError(_)
+            | Self::InvalidHeightRange { .. }
+            | Self::HeightRangeTooLarge { .. }
+            | Self::EmptyPacketBatch
+            | Self::InvalidContractRead(_)
+            | Self::EventLogMismatch { .. }
+            | Self::AdapterError(
+                AttestationAdapterError::InvalidHeight | AttestationAdapterError::InvalidTxHash(_),
+            ) => ErrorReason::InvalidRequest,
+            Self::AdapterError(AttestationAdapterError::RetrievalError(_)) => {
+                ErrorReason::RpcUnavailable
             }
-            AttestorError::SignerError(_)
-            | AttestorError::SignerInitError(_)
-            | AttestorError::AbiError(_)
-            | AttestorError::AdapterError(_) => Self::new(Code::Internal, value.to_string()),
+            Self::SignerError(_) => ErrorReason::SignerUnavailable,
+            Self::SignerInitError(e) if e.is_transient() => ErrorReason::SignerUnavailable,
+            Self::SigningPaused => ErrorReason::SigningPaused,
+            Self::TooManySubscriptions { .. } => ErrorReason::ResourceExhausted,
+            Self::WatcherDisabled | Self::AdapterError(AttestationAdapterError::Unsupported(_)) => {
+                ErrorReason::Unsupported
+            }
+            Self::SignerFault(_)
+            | Self::SignerInitError(_)
+            | Self::AdapterError(
+                AttestationAdapterError::ConfigError(_)
+                | AttestationAdapterError::MalformedEvent(_),
+            ) => ErrorReason::Internal,
+            Self::PolicyViolation(_)
+            | Self::AdapterError(AttestationAdapterError::NotAllowed(_)) => {
+                ErrorReason::PermissionDenied
+            }
+        }
+    }
+
+    /// Structured context reported alongside the reason.
+    fn metadata(&self) -> HashMap<String, String> {
+        let mut metadata = HashMap::new();
+        match self {
+            Self::BlockNotFinalized {
+                height,
+                finalized_height,
+            } => {
+                metadata.insert("height".to_string(), height.to_string());
+                metadata.insert("finalized_height".to_string(), finalized_height.to_string());
+            }
+            Self::CommitmentMismatch {
+                client_id,
+                sequence,
+                height,
+                expected,
+                actual,
+            } => {
+                metadata.insert("client_id".to_string(), client_id.clone());
+                metadata.insert("sequence".to_string(), sequence.to_string());
+                metadata.insert("height".to_string(), height.to_string());
+                metadata.insert("expected".to_string(), format!("0x{expected}"));
+                metadata.insert("actual".to_string(), format!("0x{actual}"));
+            }
+            Self::CommitmentNotFound {
+                client_id,
+                sequence,
+                height,
+            } => {
+                metadata.insert("client_id".to_string(), client_id.clone());
+                metadata.insert("sequence".to_string(), sequence.to_string());
+                metadata.insert("height".to_string(), height.to_string());
+            }
+            Self::TimestampNotReached {
+                timestamp,
+                finalized_height,
+            } => {
+                metadata.insert("timestamp".to_string(), timestamp.to_string());
+                metadata.insert("finalized_height".to_string(), finalized_height.to_string());
+            }
+            Self::AttestationNotFound {
+                client_id,
+                sequence,
+            } => {
+                metadata.insert("client_id".to_string(), client_id.clone());
+                metadata.insert("sequence".to_string(), sequence.to_string());
+            }
+            Self::TransactionNotFound { tx_hash } => {
+                metadata.insert("tx_hash".to_string(), tx_hash.clone());
+            }
+            Self::EventLogNotFound { tx_hash, log_index }
+            | Self::EventLogMismatch { tx_hash, log_index } => {
+                metadata.insert("tx_hash".to_string(), tx_hash.clone());
+                metadata.insert("log_index".to_string(), log_index.to_string());
+            }
+            Self::InvalidHeightRange {
+                from_height,
+                to_height,
+            } => {
+                metadata.insert("from_height".to_string(), from_height.to_string());
+                metadata.insert("to_height".to_string(), to_height.to_string());
+            }
+            Self::HeightRangeTooLarge {
+                from_height,
+                to_height,
+                max_blocks,
+            } => {
+                metadata.insert("from_height".to_string(), from_height.to_string());
+                metadata.insert("to_height".to_string(), to_height.to_string());
+                metadata.insert("max_blocks".to_string(), max_blocks.to_string());
+            }
+            Self::TooManySubscriptions { max_subscriptions } => {
+                metadata.insert(
+                    "max_subscriptions".to_string(),
+                    max_subscriptions.to_string(),
+                );
+            }
+            _ => {}
+        }
+        metadata
+    }
+
+    /// Suggested delay before the same request may succeed, if retrying makes sense.
+    const fn retry_delay(&self) -> Option<Duration> {
+        match self.reason() {
+            ErrorReason::BlockNotFinalized => Some(NOT_FINALIZED_RETRY_DELAY),
+            ErrorReason::RpcUnavailable => Some(RPC_UNAVAILABLE_RETRY_DELAY),
+            ErrorReason::SignerUnavailable => Some(SIGNER_UNAVAILABLE_RETRY_DELAY),
+            ErrorReason::ResourceExhausted => Some(RESOURCE_EXHAUSTED_RETRY_DELAY),
+            ErrorReason::Unspecified
+            | ErrorReason::CommitmentNotFound
+            | ErrorReason::CommitmentMismatch
+            | ErrorReason::InvalidRequest
+            | ErrorReason::Internal
+            | ErrorReason::Unsupported
+            | ErrorReason::TransactionNotFound
+            | ErrorReason::AttestationNotFound
+            | ErrorReason::PermissionDenied
+            | ErrorReason::EventLogNotFound
+            | ErrorReason::SigningPaused => None,
+        }
+    }
+}
+
+/// Status code of a failure with `reason`, so that the code and the reason
+/// never disagree.
+const fn status_code(reason: ErrorReason) -> Code {
+    match reason {
+        ErrorReason::BlockNotFinalized => Code::FailedPrecondition,
+        ErrorReason::CommitmentNotFound
+        | ErrorReason::TransactionNotFound
+        | ErrorReason::AttestationNotFound
+        | ErrorReason::EventLogNotFound => Code::NotFound,
+        ErrorReason::CommitmentMismatch | ErrorReason::InvalidRequest => Code::InvalidArgument,
+        ErrorReason::Unsupported => Code::Unimplemented,
+        ErrorReason::PermissionDenied => Code::PermissionDenied,
+        ErrorReason::ResourceExhausted => Code::ResourceExhausted,
+        // Transient, so generic gRPC retry policies retry them
+        ErrorReason::RpcUnavailable
+        | ErrorReason::SignerUnavailable
+        | ErrorReason::SigningPaused => Code::Unavailable,
+        ErrorReason::Internal => Code::Internal,
+        ErrorReason::Unspecified => Code::Unknown,
+    }
+}
+
+impl From<AttestorError> for Status {
+    fn from(value: AttestorError) -> Self {
+        let reason = value.reason();
+        let mut details =
+            ErrorDetails::with_error_info(reason.as_str_name(), ERROR_DOMAIN, value.metadata());
+        if let Some(delay) = value.retry_delay() {
+            details.set_retry_info(Some(delay));
         }
+
+        Self::with_error_details(status_code(reason), value.to_string(), details)
     }
 }
 
@@ -80,7 +387,10 @@ mod tests {
 
     #[test]
     fn block_not_finalized_maps_to_failed_precondition() {
-        let status = Status::from(AttestorError::BlockNotFinalized);
+        let status = Status::from(AttestorError::BlockNotFinalized {
+            height: 11,
+            finalized_height: 10,
+        });
         assert_eq!(status.code(), Code::FailedPrecondition);
     }
 
@@ -95,21 +405,494 @@ mod tests {
     }
 
     #[test]
-    fn invalid_commitment_maps_to_invalid_argument() {
-        let status = Status::from(AttestorError::InvalidCommitment {
-            reason: "mismatch".to_string(),
+    fn commitment_mismatch_maps_to_invalid_argument() {
+        let status = Status::from(AttestorError::CommitmentMismatch {
+            client_id: "client-a".to_string(),
+            sequence: 1,
+            height: 10,
+            expected: "aa".to_string(),
+            actual: "bb".to_string(),
         });
         assert_eq!(status.code(), Code::InvalidArgument);
     }
 
     #[test]
-    fn signer_errors_map_to_internal() {
-        let status = Status::from(AttestorError::SignerError("boom".to_string()));
+    fn transient_signer_errors_map_to_unavailable() {
+        let status = Status::from(AttestorError::signing_failed(
+            &SignerError::ConnectionError("refused".to_string()),
+        ));
+        assert_eq!(status.code(), Code::Unavailable);
+
+        let init_status = Status::from(AttestorError::SignerInitError(
+            SignerError::ConnectionError("refused".to_string()),
+        ));
+        assert_eq!(init_status.code(), Code::Unavailable);
+    }
+
+    #[test]
+    fn signer_faults_map_to_internal_without_retry_hint() {
+        let status = Status::from(AttestorError::signing_failed(
+            &SignerError::InvalidSignature("short".to_string()),
+        ));
         assert_eq!(status.code(), Code::Internal);
 
         let init_status = Status::from(AttestorError::SignerInitError(SignerError::ConfigError(
             "bad cfg".to_string(),
         )));
         assert_eq!(init_status.code(), Code::Internal);
+
+        let details = init_status.get_error_details();
+        assert_eq!(
+            details.error_info().unwrap().reason,
+            ErrorReason::Internal.as_str_name()
+        );
+        assert!(details.retry_info().is_none());
+    }
+
+    #[test]
+    fn malformed_events_are_not_retried() {
+        let status = Status::from(AttestorError::AdapterError(
+            AttestationAdapterError::MalformedEvent("bad router log".to_string()),
+        ));
+        assert_eq!(status.code(), Code::Internal);
+
+        let details = status.get_error_details();
+        assert_eq!(
+            details.error_info().unwrap().reason,
+            ErrorReason::Internal.as_str_name()
+        );
+        assert!(details.retry_info().is_none());
+    }
+
+    #[test]
+    fn unsupported_adapter_operations_map_to_unimplemented() {
+        let status = Status::from(AttestorError::AdapterError(
+            AttestationAdapterError::Unsupported("send packet lookup".to_string()),
+        ));
+        assert_eq!(status.code(), Code::Unimplemented);
+    }
+
+    #[test]
+    fn not_allowed_reads_map_to_permission_denied() {
+        let status = Status::from(AttestorError::AdapterError(
+            AttestationAdapterError::NotAllowed("contract state read".to_string()),
+        ));
+        assert_eq!(status.code(), Code::PermissionDenied);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_PERMISSION_DENIED");
+        assert!(details.retry_info().is_none());
+    }
+
+    #[test]
+    fn policy_violations_map_to_permission_denied() {
+        let status = Status::from(AttestorError::PolicyViolation(
+            "client client-9".to_string(),
+        ));
+        assert_eq!(status.code(), Code::PermissionDenied);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_PERMISSION_DENIED");
+    }
+
+    #[test]
+    fn paused_signing_maps_to_unavailable() {
+        let error = AttestorError::signing_failed(&SignerError::Paused);
+        let status = Status::from(error);
+        assert_eq!(status.code(), Code::Unavailable);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_SIGNING_PAUSED");
+        assert!(details.retry_info().is_none());
+
+        assert!(matches!(
+            AttestorError::signing_failed(&SignerError::RemoteError("boom".to_string())),
+            AttestorError::SignerError(_)
+        ));
+    }
+
+    #[test]
+    fn transaction_not_found_reports_tx_hash() {
+        let status = Status::from(AttestorError::TransactionNotFound {
+            tx_hash: "0xabc".to_string(),
+        });
+        assert_eq!(status.code(), Code::NotFound);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_TRANSACTION_NOT_FOUND");
+        assert_eq!(info.metadata.get("tx_hash").unwrap(), "0xabc");
+        assert!(details.retry_info().is_none());
+    }
+
+    #[test]
+    fn event_log_not_found_reports_log_index() {
+        let status = Status::from(AttestorError::EventLogNotFound {
+            tx_hash: "0xabc".to_string(),
+            log_index: 3,
+        });
+        assert_eq!(status.code(), Code::NotFound);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_EVENT_LOG_NOT_FOUND");
+        assert_eq!(info.metadata.get("tx_hash").unwrap(), "0xabc");
+        assert_eq!(info.metadata.get("log_index").unwrap(), "3");
+    }
+
+    #[test]
+    fn attestation_not_found_reports_packet() {
+        let status = Status::from(AttestorError::AttestationNotFound {
+            client_id: "client-a".to_string(),
+            sequence: 4,
+        });
+        assert_eq!(status.code(), Code::NotFound);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_ATTESTATION_NOT_FOUND");
+        assert_eq!(info.metadata.get("client_id").unwrap(), "client-a");
+        assert_eq!(info.metadata.get("sequence").unwrap(), "4");
+    }
+
+    #[test]
+    fn invalid_tx_hash_maps_to_invalid_argument() {
+        let status = Status::from(AttestorError::AdapterError(
+            AttestationAdapterError::InvalidTxHash("zz".to_string()),
+        ));
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn block_not_finalized_details_carry_finalized_height_and_retry_hint() {
+        let status = Status::from(AttestorError::BlockNotFinalized {
+            height: 11,
+            finalized_height: 10,
+        });
+        let details = status.get_error_details();
+
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_BLOCK_NOT_FINALIZED");
+        assert_eq!(info.domain, ERROR_DOMAIN);
+        assert_eq!(info.metadata.get("finalized_height").unwrap(), "10");
+        assert_eq!(info.metadata.get("height").unwrap(), "11");
+
+        let retry = details.retry_info().expect("retry info must be attached");
+        assert_eq!(retry.retry_delay, Some(NOT_FINALIZED_RETRY_DELAY));
+    }
+
+    #[test]
+    fn commitment_mismatch_details_carry_expected_and_actual() {
+        let status = Status::from(AttestorError::CommitmentMismatch {
+            client_id: "client-a".to_string(),
+            sequence: 3,
+            height: 10,
+            expected: "aa".to_string(),
+            actual: "bb".to_string(),
+        });
+        let details = status.get_error_details();
+
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_COMMITMENT_MISMATCH");
+        assert_eq!(info.metadata.get("client_id").unwrap(), "client-a");
+        assert_eq!(info.metadata.get("sequence").unwrap(), "3");
+        assert_eq!(info.metadata.get("expected").unwrap(), "0xaa");
+        assert_eq!(info.metadata.get("actual").unwrap(), "0xbb");
+        assert!(details.retry_info().is_none());
+    }
+
+    #[test]
+    fn retrieval_errors_report_rpc_unavailable_with_retry_hint() {
+        let status = Status::from(AttestorError::AdapterError(
+            AttestationAdapterError::RetrievalError("connection refused".to_string()),
+        ));
+        assert_eq!(status.code(), Code::Unavailable);
+        let details = status.get_error_details();
+
+        assert_eq!(
+            details.error_info().unwrap().reason,
+            ErrorReason::RpcUnavailable.as_str_name()
+        );
+        assert_eq!(
+            details.retry_info().unwrap().retry_delay,
+            Some(RPC_UNAVAILABLE_RETRY_DELAY)
+        );
+    }
+
+    #[test]
+    fn oversized_height_range_reports_the_maximum() {
+        let status = Status::from(AttestorError::HeightRangeTooLarge {
+            from_height: 0,
+            to_height: 20_000,
+            max_blocks: 10_000,
+        });
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_INVALID_REQUEST");
+        assert_eq!(
+            info.metadata.get("max_blocks").map(String::as_str),
+            Some("10000")
+        );
+    }
+
+    #[test]
+    fn too_many_subscriptions_maps_to_resource_exhausted() {
+        let status = Status::from(AttestorError::TooManySubscriptions {
+            max_subscriptions: 64,
+        });
+        assert_eq!(status.code(), Code::ResourceExhausted);
+
+        let details = status.get_error_details();
+        let info = details.error_info().expect("error info must be attached");
+        assert_eq!(info.reason, "ERROR_REASON_RESOURCE_EXHAUSTED");
+        assert_eq!(
+            info.metadata.get("max_subscriptions").map(String::as_str),
+            Some("64")
+        );
+        let retry = details.retry_info().expect("retry info must be attached");
+        assert_eq!(retry.retry_delay, Some(RESOURCE_EXHAUSTED_RETRY_DELAY));
+    }
+
+    #[test]
+    fn adapter_not_finalized_maps_to_failed_precondition() {
+        let status = Status::from(AttestorError::AdapterError(
+            AttestationAdapterError::BlockNotFinalized,
+        ));
+        assert_eq!(status.code(), Code::FailedPrecondition);
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn status_codes_agree_with_reasons() {
+        let adapter_error = AttestorError::AdapterError;
+        let cases = [
+            (
+                AttestorError::BlockNotFinalized {
+                    height: 11,
+                    finalized_height: 10,
+                },
+                Code::FailedPrecondition,
+                ErrorReason::BlockNotFinalized,
+            ),
+            (
+                AttestorError::TimestampNotReached {
+                    timestamp: 1_700_000_000,
+                    finalized_height: 10,
+                },
+                Code::FailedPrecondition,
+                ErrorReason::BlockNotFinalized,
+            ),
+            (
+                adapter_error(AttestationAdapterError::BlockNotFinalized),
+                Code::FailedPrecondition,
+                ErrorReason::BlockNotFinalized,
+            ),
+            (
+                AttestorError::CommitmentMismatch {
+                    client_id: "client-a".to_string(),
+                    sequence: 1,
+                    height: 10,
+                    expected: "aa".to_string(),
+                    actual: "bb".to_string(),
+                },
+                Code::InvalidArgument,
+                ErrorReason::CommitmentMismatch,
+            ),
+            (
+                adapter_error(AttestationAdapterError::CommitmentError(
+                    "length".to_string(),
+                )),
+                Code::InvalidArgument,
+                ErrorReason::CommitmentMismatch,
+            ),
+            (
+                AttestorError::CommitmentNotFound {
+                    client_id: "client-a".to_string(),
+                    sequence: 1,
+                    height: 10,
+                },
+                Code::NotFound,
+                ErrorReason::CommitmentNotFound,
+            ),
+            (
+                AttestorError::TransactionNotFound {
+                    tx_hash: "0xabc".to_string(),
+                },
+                Code::NotFound,
+                ErrorReason::TransactionNotFound,
+            ),
+            (
+                AttestorError::EventLogNotFound {
+                    tx_hash: "0xabc".to_string(),
+                    log_index: 3,
+                },
+                Code::NotFound,
+                ErrorReason::EventLogNotFound,
+            ),
+            (
+                AttestorError::AttestationNotFound {
+                    client_id: "client-a".to_string(),
+                    sequence: 4,
+                },
+                Code::NotFound,
+                ErrorReason::AttestationNotFound,
+            ),
+            (
+                AttestorError::EventLogMismatch {
+                    tx_hash: "0xabc".to_string(),
+                    log_index: 3,
+                },
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::InvalidHeightRange {
+                    from_height: 2,
+                    to_height: 1,
+                },
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::HeightRangeTooLarge {
+                    from_height: 0,
+                    to_height: 20_000,
+                    max_blocks: 10_000,
+                },
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::EmptyPacketBatch,
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::InvalidContractRead("no read".to_string()),
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::AbiError(AbiError::Overrun),
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::MalformedCommitmentError(prost::UnknownEnumValue(9)),
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                adapter_error(AttestationAdapterError::InvalidHeight),
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                adapter_error(AttestationAdapterError::InvalidTxHash("zz".to_string())),
+                Code::InvalidArgument,
+                ErrorReason::InvalidRequest,
+            ),
+            (
+                AttestorError::WatcherDisabled,
+                Code::Unimplemented,
+                ErrorReason::Unsupported,
+            ),
+            (
+                adapter_error(AttestationAdapterError::Unsupported("scan".to_string())),
+                Code::Unimplemented,
+                ErrorReason::Unsupported,
+            ),
+            (
+                AttestorError::PolicyViolation("client client-9".to_string()),
+                Code::PermissionDenied,
+                ErrorReason::PermissionDenied,
+            ),
+            (
+                adapter_error(AttestationAdapterError::NotAllowed("read".to_string())),
+                Code::PermissionDenied,
+                ErrorReason::PermissionDenied,
+            ),
+            (
+                AttestorError::TooManySubscriptions {
+                    max_subscriptions: 64,
+                },
+                Code::ResourceExhausted,
+                ErrorReason::ResourceExhausted,
+            ),
+            (
+                AttestorError::SigningPaused,
+                Code::Unavailable,
+                ErrorReason::SigningPaused,
+            ),
+            (
+                AttestorError::SignerError("refused".to_string()),
+                Code::Unavailable,
+                ErrorReason::SignerUnavailable,
+            ),
+            (
+                AttestorError::SignerInitError(SignerError::ConnectionError("refused".to_string())),
+                Code::Unavailable,
+                ErrorReason::SignerUnavailable,
+            ),
+            (
+                adapter_error(AttestationAdapterError::RetrievalError(
+                    "refused".to_string(),
+                )),
+                Code::Unavailable,
+                ErrorReason::RpcUnavailable,
+            ),
+            (
+                AttestorError::SignerFault("short".to_string()),
+                Code::Internal,
+                ErrorReason::Internal,
+            ),
+            (
+                AttestorError::SignerInitError(SignerError::ConfigError("bad cfg".to_string())),
+                Code::Internal,
+                ErrorReason::Internal,
+            ),
+            (
+                adapter_error(AttestationAdapterError::ConfigError("bad cfg".to_string())),
+                Code::Internal,
+                ErrorReason::Internal,
+            ),
+            (
+                adapter_error(AttestationAdapterError::MalformedEvent(
+                    "bad log".to_string(),
+                )),
+                Code::Internal,
+                ErrorReason::Internal,
+            ),
+        ];
+
+        for (error, code, reason) in cases {
+            let name = format!("{error:?}");
+            let status = Status::from(error);
+            assert_eq!(status.code(), code, "{name}");
+            assert_eq!(
+                status.get_error_details().error_info().unwrap().reason,
+                reason.as_str_name(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn signer_errors_report_signer_unavailable() {
+        let status = Status::from(AttestorError::SignerError("boom".to_string()));
+        let details = status.get_error_details();
+        assert_eq!(
+            details.error_info().unwrap().reason,
+            ErrorReason::SignerUnavailable.as_str_name()
+        );
+        assert_eq!(
+            details.retry_info().unwrap().retry_delay,
+            Some(SIGNER_UNAVAILABLE_RETRY_DELAY)
+        );
     }
 }
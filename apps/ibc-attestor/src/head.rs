@@ -0,0 +1,337 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+use crate::{adapter::AttestationAdapter, metrics};
+
+/// Bounds within which the finalized head is considered healthy
+#[derive(Clone, Copy, Debug)]
+pub struct HeadLimits {
+    /// How long the finalized height may not advance
+    pub max_stall: Duration,
+    /// How far the latest finalized block timestamp may be ahead of the
+    /// local clock
+    pub max_clock_skew: Duration,
+    /// How far the latest finalized block timestamp may be behind the local
+    /// clock, unbounded if `None`
+    pub max_block_age: Option<Duration>,
+}
+
+/// Snapshot of the finalized head watchdog
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeadStatus {
+    /// The latest finalized height observed, if any
+    pub height: Option<u64>,
+    /// How long the finalized height has not advanced, or how long the
+    /// tracker has been waiting for a first height
+    pub stalled_for: Duration,
+    /// Whether `stalled_for` exceeds [`HeadLimits::max_stall`]
+    pub stalled: bool,
+    /// Local wall-clock time minus the latest finalized block timestamp, in
+    /// seconds. Negative when the block is ahead of the local clock.
+    pub block_age_secs: Option<i64>,
+    /// Whether `block_age_secs` is within [`HeadLimits::max_clock_skew`] and
+    /// [`HeadLimits::max_block_age`]
+    pub block_time_ok: bool,
+}
+
+/// Last finalized height seen and when it advanced
+#[derive(Debug)]
+struct HeadWatch {
+    height: Option<u64>,
+    advanced_at: Instant,
+    block_timestamp: Option<u64>,
+}
+
+impl HeadWatch {
+    const fn new(now: Instant) -> Self {
+        Self {
+            height: None,
+            advanced_at: now,
+            block_timestamp: None,
+        }
+    }
+
+    /// Record `height` and return `true` if it advanced the head.
+    fn advance(&mut self, height: u64, now: Instant) -> bool {
+        if self.height.is_some_and(|current| current >= height) {
+            return false;
+        }
+        self.height = Some(height);
+        self.advanced_at = now;
+        self.block_timestamp = None;
+        true
+    }
+
+    fn status(&self, limits: &HeadLimits, now: Instant, unix_now: u64) -> HeadStatus {
+        let stalled_for = now.duration_since(self.advanced_at);
+        let block_age_secs = self.block_timestamp.map(|timestamp| {
+            i64::try_from(i128::from(unix_now) - i128::from(timestamp)).unwrap_or(i64::MIN)
+        });
+        let block_time_ok = block_age_secs.is_none_or(|age| {
+            let (ahead, behind) = if age < 0 {
+                (age.unsigned_abs(), 0)
+            } else {
+                (0, age.unsigned_abs())
+            };
+            ahead <= limits.max_clock_skew.as_secs()
+                && limits
+                    .max_block_age
+                    .is_none_or(|max| behind <= max.as_secs())
+        });
+
+        HeadStatus {
+            height: self.height,
+            stalled_for,
+            stalled: stalled_for > limits.max_stall,
+            block_age_secs,
+            block_time_ok,
+        }
+    }
+}
+
+/// Tracks the latest finalized height of the attested chain.
+///
+/// A single background task polls the adapter and publishes each new finalized
+/// height to every subscriber, so subscribers don't query the chain themselves.
+/// It also acts as a watchdog, flagging a head that stops advancing or whose
+/// block timestamp drifts from the local clock. The task stops once the
+/// tracker and all its subscriptions are dropped.
+#[derive(Clone, Debug)]
+pub struct HeadTracker {
+    receiver: watch::Receiver<Option<u64>>,
+    watchdog: HeadWatchdog,
+}
+
+impl HeadTracker {
+    /// Spawn the polling task on the current tokio runtime.
+    #[must_use]
+    pub fn spawn<A: AttestationAdapter>(
+        adapter: Arc<A>,
+        poll_interval: Duration,
+        limits: HeadLimits,
+    ) -> Self {
+        info!(
+            pollIntervalMs = poll_interval.as_millis(),
+            maxStallSecs = limits.max_stall.as_secs(),
+            "starting finalized head tracker"
+        );
+
+        let (sender, receiver) = watch::channel(None);
+        let watchdog = HeadWatchdog {
+            watch: Arc::new(Mutex::new(HeadWatch::new(Instant::now()))),
+            limits,
+        };
+        tokio::spawn(poll_finalized_head(
+            adapter,
+            poll_interval,
+            sender,
+            watchdog.clone(),
+        ));
+
+        Self { receiver, watchdog }
+    }
+
+    /// Subscribe to finalized height updates.
+    ///
+    /// The value is `None` until the first successful poll.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<Option<u64>> {
+        self.receiver.clone()
+    }
+
+    /// The latest finalized height observed, if any.
+    #[must_use]
+    pub fn latest(&self) -> Option<u64> {
+        *self.receiver.borrow()
+    }
+
+    /// The current watchdog status of the finalized head.
+    ///
+    /// # Panics
+    /// Panics if the head lock was poisoned by a panicking writer.
+    #[must_use]
+    pub fn status(&self) -> HeadStatus {
+        self.watchdog.status()
+    }
+}
+
+/// Watchdog state shared by the tracker and its polling task
+#[derive(Clone, Debug)]
+struct HeadWatchdog {
+    watch: Arc<Mutex<HeadWatch>>,
+    limits: HeadLimits,
+}
+
+impl HeadWatchdog {
+    fn advance(&self, height: u64) -> bool {
+        self.watch
+            .lock()
+            .expect("head watch lock poisoned")
+            .advance(height, Instant::now())
+    }
+
+    fn set_block_timestamp(&self, height: u64, timestamp: u64) {
+        let mut watch = self.watch.lock().expect("head watch lock poisoned");
+        // Skip if the head advanced again while the timestamp was fetched
+        if watch.height == Some(height) {
+            watch.block_timestamp = Some(timestamp);
+        }
+    }
+
+    fn status(&self) -> HeadStatus {
+        self.watch.lock().expect("head watch lock poisoned").status(
+            &self.limits,
+            Instant::now(),
+            unix_now(),
+        )
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+async fn poll_finalized_head<A: AttestationAdapter>(
+    adapter: Arc<A>,
+    poll_interval: Duration,
+    sender: watch::Sender<Option<u64>>,
+    watchdog: HeadWatchdog,
+) {
+    let mut ticker = tokio::time::interval(poll_interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut previous = watchdog.status();
+
+    loop {
+        tokio::select! {
+            () = sender.closed() => {
+                debug!("all head subscribers dropped, stopping head tracker");
+                return;
+            }
+            _ = ticker.tick() => {}
+        }
+
+        // error log emitted by retry module
+        if let Ok(height) = adapter.get_last_height_at_configured_finality().await {
+            // Finalized heights never move backwards, ignore lagging RPC nodes
+            if watchdog.advance(height) {
+                debug!(finalizedHeight = height, "finalized head advanced");
+                sender.send_replace(Some(height));
+                if let Ok(timestamp) = adapter.get_block_timestamp(height).await {
+                    watchdog.set_block_timestamp(height, timestamp);
+                }
+            }
+        }
+
+        let status = watchdog.status();
+        report(&previous, &status);
+        previous = status;
+    }
+}
+
+/// Publish the watchdog gauges and log status transitions.
+fn report(previous: &HeadStatus, status: &HeadStatus) {
+    metrics::set_finalized_head_stall(status.stalled_for, status.stalled);
+    if let Some(age) = status.block_age_secs {
+        metrics::set_finalized_block_age(age);
+    }
+
+    match (previous.stalled, status.stalled) {
+        (false, true) => error!(
+            finalizedHeight = status.height,
+            stalledSecs = status.stalled_for.as_secs(),
+            "finalized height is not advancing"
+        ),
+        (true, false) => info!(
+            finalizedHeight = status.height,
+            "finalized height is advancing again"
+        ),
+        _ => {}
+    }
+    match (previous.block_time_ok, status.block_time_ok) {
+        (true, false) => warn!(
+            finalizedHeight = status.height,
+            blockAgeSecs = status.block_age_secs,
+            "finalized block timestamp is out of bounds of the local clock"
+        ),
+        (false, true) => info!(
+            finalizedHeight = status.height,
+            blockAgeSecs = status.block_age_secs,
+            "finalized block timestamp is back within bounds"
+        ),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMITS: HeadLimits = HeadLimits {
+        max_stall: Duration::from_secs(6),
+        max_clock_skew: Duration::from_secs(30),
+        max_block_age: Some(Duration::from_secs(600)),
+    };
+
+    #[test]
+    fn stall_is_measured_from_the_last_advance() {
+        let start = Instant::now();
+        let mut watch = HeadWatch::new(start);
+        let status =
+            |watch: &HeadWatch, secs| watch.status(&LIMITS, start + Duration::from_secs(secs), 0);
+
+        // Waiting for the first height counts as a stall
+        assert_eq!(status(&watch, 7).stalled_for, Duration::from_secs(7));
+        assert!(status(&watch, 7).stalled);
+
+        assert!(watch.advance(10, start + Duration::from_secs(8)));
+        assert_eq!(status(&watch, 8).stalled_for, Duration::ZERO);
+        assert!(!status(&watch, 13).stalled);
+        // A lagging node reporting an older height is not an advance
+        assert!(!watch.advance(9, start + Duration::from_secs(14)));
+        assert!(!watch.advance(10, start + Duration::from_secs(14)));
+        assert_eq!(status(&watch, 15).stalled_for, Duration::from_secs(7));
+        assert!(status(&watch, 15).stalled);
+
+        assert!(watch.advance(11, start + Duration::from_secs(16)));
+        assert_eq!(status(&watch, 18).height, Some(11));
+        assert!(!status(&watch, 18).stalled);
+    }
+
+    #[test]
+    fn block_age_is_bounded_both_ways() {
+        let now = Instant::now();
+        let mut watch = HeadWatch::new(now);
+        assert!(watch.advance(10, now));
+        let status = |watch: &HeadWatch, unix_now| watch.status(&LIMITS, now, unix_now);
+
+        // Unknown until the block timestamp is fetched
+        assert_eq!(status(&watch, 1_000).block_age_secs, None);
+        assert!(status(&watch, 1_000).block_time_ok);
+
+        watch.block_timestamp = Some(1_000);
+        assert_eq!(status(&watch, 1_600).block_age_secs, Some(600));
+        assert!(status(&watch, 1_600).block_time_ok);
+        assert!(!status(&watch, 1_601).block_time_ok);
+
+        assert_eq!(status(&watch, 970).block_age_secs, Some(-30));
+        assert!(status(&watch, 970).block_time_ok);
+        assert!(!status(&watch, 969).block_time_ok);
+
+        let unbounded = HeadLimits {
+            max_block_age: None,
+            ..LIMITS
+        };
+        assert!(watch.status(&unbounded, now, 100_000).block_time_ok);
+
+        // The timestamp belongs to the previous head once it advances
+        assert!(watch.advance(11, now));
+        assert_eq!(status(&watch, 1_000).block_age_secs, None);
+    }
+}
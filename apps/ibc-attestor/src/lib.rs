@@ -13,16 +13,31 @@ pub mod attestation;
 pub mod attestation_payload;
 /// Configuration structures and loading
 pub mod config;
+/// Finalized head tracking shared by streaming subscribers
+pub mod head;
 /// Logging and observability setup
 pub mod logging;
+/// Merkle trees over packet commitments for batched attestations
+pub mod merkle;
 /// Prometheus metrics registry and recording helpers
 pub mod metrics;
+/// Operator kill switch pausing all signing
+pub mod pause;
+/// Decoding of application packet payloads inspected by the policy
+pub mod payload;
+/// Signing policy restricting clients, ports, batch sizes and transfers
+pub mod policy;
 /// gRPC server and service implementations
 pub mod rpc;
 /// Signer implementations for local and remote signing
 pub mod signer;
+/// Push delivery of signed attestations to external sinks
+pub mod sink;
+/// Background watcher that pre-computes packet attestations
+pub mod watcher;
 
 mod error;
+mod search;
 
 /// Attestor and IBC proto definitions
 pub mod proto {
@@ -40,7 +55,7 @@ pub mod proto {
 }
 
 use alloy_sol_types::SolType;
-pub use error::AttestorError;
+pub use error::{AttestorError, ERROR_DOMAIN};
 use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Packet;
 
 /// A collection of IBC packets for batch attestation
@@ -0,0 +1,164 @@
+//! Merkle trees over packet commitments, signed by batch attestations.
+//!
+//! The tree follows the `OpenZeppelin` `MerkleProof` conventions so proofs
+//! verify on-chain without the leaf index:
+//! - leaf: `keccak256(keccak256(abi.encode(path, commitment)))`
+//! - node: `keccak256(min(a, b) || max(a, b))`
+//!
+//! A node without a sibling is promoted to the next level unchanged.
+use alloy_primitives::{B256, keccak256};
+use alloy_sol_types::SolValue;
+use ibc_eureka_solidity_types::msgs::IAttestationMsgs::PacketCompact;
+
+/// Hash of a `(path, commitment)` leaf. Hashing twice keeps leaves from
+/// being mistaken for inner nodes.
+#[must_use]
+pub fn leaf_hash(packet: &PacketCompact) -> B256 {
+    keccak256(keccak256((packet.path, packet.commitment).abi_encode()))
+}
+
+fn node_hash(a: B256, b: B256) -> B256 {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    keccak256([left.as_slice(), right.as_slice()].concat())
+}
+
+/// Merkle tree over a batch of packet commitments
+#[derive(Clone, Debug)]
+pub struct PacketMerkleTree {
+    /// Levels from the leaves up to the root
+    levels: Vec<Vec<B256>>,
+}
+
+impl PacketMerkleTree {
+    /// Build the tree over `packets`, in order. Returns `None` for an empty
+    /// batch.
+    #[must_use]
+    pub fn new(packets: &[PacketCompact]) -> Option<Self> {
+        if packets.is_empty() {
+            return None;
+        }
+
+        let mut levels = vec![packets.iter().map(leaf_hash).collect::<Vec<_>>()];
+        while let Some(level) = levels.last()
+            && level.len() > 1
+        {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => node_hash(*a, *b),
+                    [a] => *a,
+                    _ => unreachable!("chunks of two"),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Some(Self { levels })
+    }
+
+    /// Returns the root of the tree.
+    #[must_use]
+    pub fn root(&self) -> B256 {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the number of leaves.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns `true` if the tree has no leaves, which [`Self::new`] never
+    /// builds.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Returns the sibling hashes from the leaf at `index` up to the root, or
+    /// `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn proof(&self, mut index: usize) -> Option<Vec<B256>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut proof = Vec::with_capacity(self.levels.len() - 1);
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+/// Check that `packet` is included in the tree with the given `root`.
+#[must_use]
+pub fn verify_proof(root: B256, packet: &PacketCompact, proof: &[B256]) -> bool {
+    proof
+        .iter()
+        .fold(leaf_hash(packet), |hash, sibling| node_hash(hash, *sibling))
+        == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(n: u8) -> PacketCompact {
+        PacketCompact {
+            path: B256::repeat_byte(n),
+            commitment: B256::repeat_byte(n.wrapping_add(100)),
+        }
+    }
+
+    #[test]
+    fn empty_batch_has_no_tree() {
+        assert!(PacketMerkleTree::new(&[]).is_none());
+    }
+
+    #[test]
+    fn single_leaf_root_is_leaf_hash() {
+        let tree = PacketMerkleTree::new(&[packet(1)]).unwrap();
+        assert_eq!(tree.root(), leaf_hash(&packet(1)));
+        assert_eq!(tree.proof(0).unwrap(), Vec::<B256>::new());
+    }
+
+    #[test]
+    fn every_leaf_proves_inclusion() {
+        for size in 1..=9u8 {
+            let packets = (0..size).map(packet).collect::<Vec<_>>();
+            let tree = PacketMerkleTree::new(&packets).unwrap();
+
+            for (index, packet) in packets.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert!(
+                    verify_proof(tree.root(), packet, &proof),
+                    "leaf {index} of {size}"
+                );
+            }
+            assert!(tree.proof(packets.len()).is_none());
+        }
+    }
+
+    #[test]
+    fn proof_does_not_verify_other_packet() {
+        let packets = (0..4).map(packet).collect::<Vec<_>>();
+        let tree = PacketMerkleTree::new(&packets).unwrap();
+
+        let proof = tree.proof(0).unwrap();
+        assert!(!verify_proof(tree.root(), &packets[1], &proof));
+        assert!(!verify_proof(tree.root(), &packet(9), &proof));
+    }
+
+    #[test]
+    fn root_depends_on_commitment() {
+        let mut packets = (0..3).map(packet).collect::<Vec<_>>();
+        let root = PacketMerkleTree::new(&packets).unwrap().root();
+
+        packets[2].commitment = B256::ZERO;
+        assert_ne!(PacketMerkleTree::new(&packets).unwrap().root(), root);
+    }
+}
@@ -28,7 +28,15 @@ struct Metrics {
     commitment_validation_failures_total: IntCounterVec,
     adapter_retry_failures_total: IntCounterVec,
     adapter_finalized_height: IntGauge,
+    finalized_head_stall_seconds: IntGauge,
+    finalized_head_stalled: IntGauge,
+    finalized_block_age_seconds: IntGauge,
     signer_signs_total: IntCounterVec,
+    watcher_attestations_total: IntCounterVec,
+    watcher_scanned_height: IntGauge,
+    sink_deliveries_total: IntCounterVec,
+    policy_rejections_total: IntCounterVec,
+    signing_paused: IntGauge,
 }
 
 impl Metrics {
@@ -121,6 +129,33 @@ impl Metrics {
             .register(Box::new(adapter_finalized_height.clone()))
             .expect("metric registration is unique at startup");
 
+        let finalized_head_stall_seconds = IntGauge::new(
+            "attestor_finalized_head_stall_seconds",
+            "Seconds since the finalized height last advanced",
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(finalized_head_stall_seconds.clone()))
+            .expect("metric registration is unique at startup");
+
+        let finalized_head_stalled = IntGauge::new(
+            "attestor_finalized_head_stalled",
+            "1 while the finalized height has not advanced for longer than the configured maximum, 0 otherwise",
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(finalized_head_stalled.clone()))
+            .expect("metric registration is unique at startup");
+
+        let finalized_block_age_seconds = IntGauge::new(
+            "attestor_finalized_block_age_seconds",
+            "Local wall-clock time minus the latest finalized block timestamp; negative = block ahead of the local clock",
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(finalized_block_age_seconds.clone()))
+            .expect("metric registration is unique at startup");
+
         let signer_signs_total = IntCounterVec::new(
             Opts::new(
                 "attestor_signer_signs_total",
@@ -133,6 +168,60 @@ impl Metrics {
             .register(Box::new(signer_signs_total.clone()))
             .expect("metric registration is unique at startup");
 
+        let watcher_attestations_total = IntCounterVec::new(
+            Opts::new(
+                "attestor_watcher_attestations_total",
+                "Total packet attestations pre-computed by the watcher, by kind and outcome",
+            ),
+            &["kind", "result"],
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(watcher_attestations_total.clone()))
+            .expect("metric registration is unique at startup");
+
+        let watcher_scanned_height = IntGauge::new(
+            "attestor_watcher_scanned_height",
+            "Last finalized height scanned by the watcher",
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(watcher_scanned_height.clone()))
+            .expect("metric registration is unique at startup");
+
+        let sink_deliveries_total = IntCounterVec::new(
+            Opts::new(
+                "attestor_sink_deliveries_total",
+                "Total attestations published to sinks, by sink and outcome",
+            ),
+            &["sink", "result"],
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(sink_deliveries_total.clone()))
+            .expect("metric registration is unique at startup");
+
+        let policy_rejections_total = IntCounterVec::new(
+            Opts::new(
+                "attestor_policy_rejections_total",
+                "Number of requests rejected by the signing policy, by rule",
+            ),
+            &["rule"],
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(policy_rejections_total.clone()))
+            .expect("metric registration is unique at startup");
+
+        let signing_paused = IntGauge::new(
+            "attestor_signing_paused",
+            "1 while signing is paused by the operator, 0 otherwise",
+        )
+        .expect("static metric definition is valid");
+        registry
+            .register(Box::new(signing_paused.clone()))
+            .expect("metric registration is unique at startup");
+
         Self {
             registry,
             rpc_requests_total,
@@ -142,7 +231,15 @@ impl Metrics {
             commitment_validation_failures_total,
             adapter_retry_failures_total,
             adapter_finalized_height,
+            finalized_head_stall_seconds,
+            finalized_head_stalled,
+            finalized_block_age_seconds,
             signer_signs_total,
+            watcher_attestations_total,
+            watcher_scanned_height,
+            sink_deliveries_total,
+            policy_rejections_total,
+            signing_paused,
         }
     }
 }
@@ -216,6 +313,24 @@ pub fn set_adapter_finalized_height(height: u64) {
     }
 }
 
+/// Set the gauges that track how long the finalized height has not advanced
+/// and whether that exceeds the configured maximum.
+pub fn set_finalized_head_stall(stalled_for: Duration, stalled: bool) {
+    if let Some(m) = metrics() {
+        let value = i64::try_from(stalled_for.as_secs()).unwrap_or(i64::MAX);
+        m.finalized_head_stall_seconds.set(value);
+        m.finalized_head_stalled.set(i64::from(stalled));
+    }
+}
+
+/// Set the gauge that tracks the local wall-clock time minus the latest
+/// finalized block timestamp, in seconds.
+pub fn set_finalized_block_age(age_secs: i64) {
+    if let Some(m) = metrics() {
+        m.finalized_block_age_seconds.set(age_secs);
+    }
+}
+
 /// Increment for each signer call. `result` ∈ {`ok`, `err`}.
 pub fn inc_signer_sign(result: &str) {
     if let Some(m) = metrics() {
@@ -223,6 +338,51 @@ pub fn inc_signer_sign(result: &str) {
     }
 }
 
+/// Increment for each packet event handled by the watcher. `kind` ∈ {`packet`,
+/// `ack`}, `result` ∈ {`ok`, `skipped`}.
+pub fn inc_watcher_attestation(kind: &str, result: &str) {
+    if let Some(m) = metrics() {
+        m.watcher_attestations_total
+            .with_label_values(&[kind, result])
+            .inc();
+    }
+}
+
+/// Set the gauge that tracks the last finalized height scanned by the
+/// watcher.
+pub fn set_watcher_scanned_height(height: u64) {
+    if let Some(m) = metrics() {
+        let value = i64::try_from(height).unwrap_or(i64::MAX);
+        m.watcher_scanned_height.set(value);
+    }
+}
+
+/// Increment for each attestation handled by a sink. `result` ∈ {`ok`,
+/// `failed`, `dropped`}.
+pub fn inc_sink_delivery(sink: &str, result: &str) {
+    if let Some(m) = metrics() {
+        m.sink_deliveries_total
+            .with_label_values(&[sink, result])
+            .inc();
+    }
+}
+
+/// Increment when the signing policy rejects a request. `rule` ∈ {`client`,
+/// `port_pair`, `batch_size`, `payload`, `denied_receiver`, `transfer_cap`,
+/// `rate_limit`}.
+pub fn inc_policy_rejection(rule: &str) {
+    if let Some(m) = metrics() {
+        m.policy_rejections_total.with_label_values(&[rule]).inc();
+    }
+}
+
+/// Set the gauge that tracks whether signing is paused.
+pub fn set_signing_paused(paused: bool) {
+    if let Some(m) = metrics() {
+        m.signing_paused.set(i64::from(paused));
+    }
+}
+
 /// Encode the gathered metrics in Prometheus text format. Returns an empty
 /// buffer if the registry has not been initialized or if encoding fails.
 #[must_use]
@@ -295,7 +455,14 @@ mod tests {
         inc_commitment_failure("mismatch");
         inc_retry_failure("evm.latest_height");
         set_adapter_finalized_height(123);
+        set_finalized_head_stall(Duration::from_secs(12), false);
+        set_finalized_block_age(-3);
         inc_signer_sign("ok");
+        inc_watcher_attestation("packet", "ok");
+        set_watcher_scanned_height(123);
+        inc_sink_delivery("webhook", "ok");
+        inc_policy_rejection("client");
+        set_signing_paused(false);
 
         let body = encode_text();
         let exposed = String::from_utf8(body).expect("text format is utf-8");
@@ -308,7 +475,15 @@ mod tests {
             "attestor_commitment_validation_failures_total",
             "attestor_adapter_retry_failures_total",
             "attestor_adapter_finalized_height",
+            "attestor_finalized_head_stall_seconds",
+            "attestor_finalized_head_stalled",
+            "attestor_finalized_block_age_seconds",
             "attestor_signer_signs_total",
+            "attestor_watcher_attestations_total",
+            "attestor_watcher_scanned_height",
+            "attestor_sink_deliveries_total",
+            "attestor_policy_rejections_total",
+            "attestor_signing_paused",
         ] {
             assert!(
                 exposed.contains(name),
@@ -0,0 +1,188 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use tracing::{error, info, warn};
+
+use crate::metrics;
+
+/// How often the pause file is checked
+const PAUSE_FILE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Operator kill switch pausing all signing.
+///
+/// Signing is paused while the configured pause file exists or an operator
+/// paused it through the admin service. Both must be cleared to resume.
+/// An operator pause is only held in memory, so a restart clears it while the
+/// pause file keeps signing paused. Clones share the same state.
+#[derive(Clone, Debug, Default)]
+pub struct PauseSwitch {
+    inner: Arc<PauseInner>,
+}
+
+#[derive(Debug, Default)]
+struct PauseInner {
+    /// Reason given by the operator, set while paused through the admin service
+    admin_reason: Mutex<Option<String>>,
+    /// Whether the pause file exists
+    file_present: AtomicBool,
+}
+
+/// Snapshot of the [`PauseSwitch`] state
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PauseState {
+    /// Reason given when paused through the admin service
+    pub admin_reason: Option<String>,
+    /// Whether the pause file exists
+    pub file_present: bool,
+}
+
+impl PauseState {
+    /// Returns `true` if signing is paused.
+    #[must_use]
+    pub const fn is_paused(&self) -> bool {
+        self.admin_reason.is_some() || self.file_present
+    }
+}
+
+impl PauseSwitch {
+    /// Returns `true` if signing is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.state().is_paused()
+    }
+
+    /// Returns the current state.
+    ///
+    /// # Panics
+    /// Panics if the state lock was poisoned by a panicking writer.
+    #[must_use]
+    pub fn state(&self) -> PauseState {
+        PauseState {
+            admin_reason: self
+                .inner
+                .admin_reason
+                .lock()
+                .expect("pause state lock poisoned")
+                .clone(),
+            file_present: self.inner.file_present.load(Ordering::Acquire),
+        }
+    }
+
+    /// Pause signing on behalf of the operator.
+    ///
+    /// # Panics
+    /// Panics if the state lock was poisoned by a panicking writer.
+    #[must_use]
+    pub fn pause(&self, reason: String) -> PauseState {
+        warn!(reason = %reason, "signing paused by operator");
+        *self
+            .inner
+            .admin_reason
+            .lock()
+            .expect("pause state lock poisoned") = Some(reason);
+        self.publish()
+    }
+
+    /// Clear an operator pause. Signing stays paused while the pause file
+    /// exists.
+    ///
+    /// # Panics
+    /// Panics if the state lock was poisoned by a panicking writer.
+    #[must_use]
+    pub fn resume(&self) -> PauseState {
+        let previous = self
+            .inner
+            .admin_reason
+            .lock()
+            .expect("pause state lock poisoned")
+            .take();
+        if previous.is_some() {
+            info!("operator pause cleared");
+        }
+        self.publish()
+    }
+
+    /// Check the pause file now, then every second on the current tokio
+    /// runtime for the lifetime of the process.
+    pub fn watch_file(&self, path: PathBuf) {
+        info!(path = %path.display(), "watching pause file");
+        self.refresh_file(&path);
+
+        let switch = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(PAUSE_FILE_POLL_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                switch.refresh_file(&path);
+            }
+        });
+    }
+
+    fn refresh_file(&self, path: &Path) {
+        // Fail closed if the file can't be checked
+        let present = path.try_exists().unwrap_or_else(|e| {
+            error!(path = %path.display(), error = %e, "failed to check pause file");
+            true
+        });
+
+        let previous = self.inner.file_present.swap(present, Ordering::AcqRel);
+        if present != previous {
+            if present {
+                warn!(path = %path.display(), "signing paused by pause file");
+            } else {
+                info!(path = %path.display(), "pause file removed");
+            }
+            self.publish();
+        }
+    }
+
+    fn publish(&self) -> PauseState {
+        let state = self.state();
+        metrics::set_signing_paused(state.is_paused());
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operator_pause_is_resumed() {
+        let switch = PauseSwitch::default();
+        assert!(!switch.is_paused());
+
+        let state = switch.pause("incident".to_string());
+        assert_eq!(state.admin_reason.as_deref(), Some("incident"));
+        assert!(switch.clone().is_paused());
+
+        assert!(!switch.resume().is_paused());
+    }
+
+    #[test]
+    fn pause_file_keeps_signing_paused() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("paused");
+        let switch = PauseSwitch::default();
+
+        switch.refresh_file(&path);
+        assert!(!switch.is_paused());
+
+        std::fs::write(&path, b"").unwrap();
+        switch.refresh_file(&path);
+        assert!(switch.is_paused());
+        // Resuming only clears an operator pause
+        assert!(switch.resume().is_paused());
+
+        std::fs::remove_file(&path).unwrap();
+        switch.refresh_file(&path);
+        assert!(!switch.is_paused());
+    }
+}
@@ -0,0 +1,451 @@
+//! Decoding of packet payloads of known applications.
+//!
+//! The attestor signs commitments and never needs to understand payloads,
+//! but value limits of the [`policy`](crate::policy) do. Payloads are
+//! recognised by their destination port:
+//! - ICS20 transfers, ABI (`application/x-solidity-abi`) or JSON encoded
+//! - ICS27-GMP calls, ABI encoded. Calls to `iftMint` of an IFT contract are
+//!   decoded as transfers of the token the contract mints, see `docs/ift.md`.
+//!
+//! GMP calls other than IFT mints, and payloads of other ports, carry no
+//! transfer.
+use std::fmt::Display;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, SolValue};
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Payload;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Default port of the ICS20 transfer application
+pub const DEFAULT_TRANSFER_PORT: &str = "transfer";
+
+/// Default port of the ICS27-GMP application
+pub const DEFAULT_GMP_PORT: &str = "gmpport";
+
+const ABI_ENCODING: &str = "application/x-solidity-abi";
+const JSON_ENCODING: &str = "application/json";
+
+/// Type URL suffix of the Cosmos SDK IFT mint message
+const COSMOS_IFT_MINT_TYPE: &str = ".MsgIFTMint";
+
+/// ABI types of application packet data
+#[allow(clippy::nursery, clippy::pedantic, missing_docs)]
+pub mod msgs {
+    alloy_sol_types::sol! {
+        /// ICS20 packet data as sent by the Solidity transfer application
+        #[derive(Debug, PartialEq, Eq)]
+        struct FungibleTokenPacketData {
+            string denom;
+            string sender;
+            string receiver;
+            uint256 amount;
+            string memo;
+        }
+
+        /// ICS27-GMP packet data as sent by the Solidity GMP application
+        #[derive(Debug, PartialEq, Eq)]
+        struct GMPPacketData {
+            string sender;
+            string receiver;
+            bytes salt;
+            bytes payload;
+            string memo;
+        }
+
+        /// Mint entrypoint of an EVM IFT contract
+        function iftMint(address receiver, uint256 amount);
+    }
+}
+
+/// Application a transfer was decoded from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum App {
+    /// ICS20 fungible token transfer
+    Ics20,
+    /// IFT mint carried by an ICS27-GMP call
+    Ift,
+}
+
+/// Value moved by a packet payload
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer {
+    /// Application the payload belongs to
+    pub app: App,
+    /// ICS20 denom, the checksummed IFT contract address on EVM or the
+    /// minted denom on Cosmos
+    pub denom: String,
+    /// Receiver on the destination chain
+    pub receiver: String,
+    /// Amount in the smallest unit of `denom`
+    pub amount: U256,
+}
+
+/// Errors decoding the payload of a known application
+#[derive(Debug, Error)]
+pub enum PayloadError {
+    /// The payload encoding is not supported for the application
+    #[error("unsupported {port} payload encoding `{encoding}`")]
+    UnsupportedEncoding {
+        /// Destination port of the payload
+        port: String,
+        /// Encoding of the payload
+        encoding: String,
+    },
+
+    /// The payload does not decode as the application's packet data
+    #[error("malformed {port} payload: {reason}")]
+    Malformed {
+        /// Destination port of the payload
+        port: String,
+        /// Decoding failure
+        reason: String,
+    },
+}
+
+/// Ports identifying the applications whose payloads are decoded
+#[derive(Clone, Debug)]
+pub struct AppPorts {
+    /// Port of the ICS20 transfer application
+    pub transfer: String,
+    /// Port of the ICS27-GMP application
+    pub gmp: String,
+}
+
+impl Default for AppPorts {
+    fn default() -> Self {
+        Self {
+            transfer: DEFAULT_TRANSFER_PORT.to_string(),
+            gmp: DEFAULT_GMP_PORT.to_string(),
+        }
+    }
+}
+
+impl AppPorts {
+    /// Decode the value moved by `payload`, if any.
+    ///
+    /// # Errors
+    /// Returns [`PayloadError`] if the payload is addressed to a known
+    /// application but can't be decoded.
+    pub fn decode_transfer(&self, payload: &Payload) -> Result<Option<Transfer>, PayloadError> {
+        if payload.destPort == self.transfer {
+            decode_ics20(payload).map(Some)
+        } else if payload.destPort == self.gmp {
+            decode_gmp(payload)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// ICS20 packet data as sent by `ibc-go`, with the amount as a decimal
+/// string
+#[derive(Deserialize)]
+struct JsonFungibleTokenPacketData {
+    denom: String,
+    amount: String,
+    receiver: String,
+}
+
+fn decode_ics20(payload: &Payload) -> Result<Transfer, PayloadError> {
+    let (denom, receiver, amount) = match payload.encoding.as_str() {
+        ABI_ENCODING => {
+            let data = msgs::FungibleTokenPacketData::abi_decode(&payload.value)
+                .map_err(|e| malformed(payload, &e))?;
+            (data.denom, data.receiver, data.amount)
+        }
+        JSON_ENCODING => {
+            let data: JsonFungibleTokenPacketData =
+                serde_json::from_slice(&payload.value).map_err(|e| malformed(payload, &e))?;
+            let amount = parse_amount(&data.amount)
+                .ok_or_else(|| malformed(payload, &format!("invalid amount `{}`", data.amount)))?;
+            (data.denom, data.receiver, amount)
+        }
+        _ => return Err(unsupported_encoding(payload)),
+    };
+
+    Ok(Transfer {
+        app: App::Ics20,
+        denom,
+        receiver,
+        amount,
+    })
+}
+
+fn decode_gmp(payload: &Payload) -> Result<Option<Transfer>, PayloadError> {
+    if payload.encoding != ABI_ENCODING {
+        return Err(unsupported_encoding(payload));
+    }
+
+    let data =
+        msgs::GMPPacketData::abi_decode(&payload.value).map_err(|e| malformed(payload, &e))?;
+
+    if data.payload.starts_with(&msgs::iftMintCall::SELECTOR) {
+        let call =
+            msgs::iftMintCall::abi_decode(&data.payload).map_err(|e| malformed(payload, &e))?;
+        // Limits are keyed by the checksummed contract address, however the
+        // sender spelled it
+        let contract: Address = data.receiver.parse().map_err(|e| {
+            malformed(
+                payload,
+                &format!("invalid IFT contract `{}`: {e}", data.receiver),
+            )
+        })?;
+        return Ok(Some(Transfer {
+            app: App::Ift,
+            denom: contract.to_string(),
+            receiver: call.receiver.to_string(),
+            amount: call.amount,
+        }));
+    }
+
+    decode_cosmos_ift_mint(payload, &data.payload)
+}
+
+/// Type URL of a protojson encoded Cosmos SDK message
+#[derive(Deserialize)]
+struct CosmosMsg {
+    #[serde(rename = "@type")]
+    type_url: String,
+}
+
+/// `MsgIFTMint` as executed by the interchain account on Cosmos chains
+#[derive(Deserialize)]
+struct CosmosIftMint {
+    receiver: String,
+    amount: CosmosCoin,
+}
+
+#[derive(Deserialize)]
+struct CosmosCoin {
+    denom: String,
+    amount: String,
+}
+
+fn decode_cosmos_ift_mint(
+    payload: &Payload,
+    call: &[u8],
+) -> Result<Option<Transfer>, PayloadError> {
+    // Other GMP calls are free-form and may not be JSON at all
+    let Ok(msg) = serde_json::from_slice::<CosmosMsg>(call) else {
+        return Ok(None);
+    };
+    if !msg.type_url.ends_with(COSMOS_IFT_MINT_TYPE) {
+        return Ok(None);
+    }
+
+    let mint: CosmosIftMint = serde_json::from_slice(call).map_err(|e| malformed(payload, &e))?;
+
+    let amount = parse_amount(&mint.amount.amount)
+        .ok_or_else(|| malformed(payload, &format!("invalid amount `{}`", mint.amount.amount)))?;
+    Ok(Some(Transfer {
+        app: App::Ift,
+        denom: mint.amount.denom,
+        receiver: mint.receiver,
+        amount,
+    }))
+}
+
+fn parse_amount(amount: &str) -> Option<U256> {
+    U256::from_str_radix(amount, 10).ok()
+}
+
+fn unsupported_encoding(payload: &Payload) -> PayloadError {
+    PayloadError::UnsupportedEncoding {
+        port: payload.destPort.clone(),
+        encoding: payload.encoding.clone(),
+    }
+}
+
+fn malformed(payload: &Payload, reason: &impl Display) -> PayloadError {
+    PayloadError::Malformed {
+        port: payload.destPort.clone(),
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, address};
+
+    const IFT: Address = address!("0x1111111111111111111111111111111111111111");
+    const RECEIVER: Address = address!("0x2222222222222222222222222222222222222222");
+
+    fn payload(port: &str, encoding: &str, value: Vec<u8>) -> Payload {
+        Payload {
+            sourcePort: port.to_string(),
+            destPort: port.to_string(),
+            version: String::new(),
+            encoding: encoding.to_string(),
+            value: value.into(),
+        }
+    }
+
+    fn gmp(call: Vec<u8>) -> Payload {
+        let data = msgs::GMPPacketData {
+            sender: "0xsender".to_string(),
+            receiver: IFT.to_string(),
+            salt: Vec::new().into(),
+            payload: call.into(),
+            memo: String::new(),
+        };
+        payload(DEFAULT_GMP_PORT, ABI_ENCODING, data.abi_encode())
+    }
+
+    #[test]
+    fn abi_ics20_payload_is_decoded() {
+        let data = msgs::FungibleTokenPacketData {
+            denom: "uatom".to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: "0xreceiver".to_string(),
+            amount: U256::from(42),
+            memo: String::new(),
+        };
+        let transfer = AppPorts::default()
+            .decode_transfer(&payload(
+                DEFAULT_TRANSFER_PORT,
+                ABI_ENCODING,
+                data.abi_encode(),
+            ))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(transfer.app, App::Ics20);
+        assert_eq!(transfer.denom, "uatom");
+        assert_eq!(transfer.receiver, "0xreceiver");
+        assert_eq!(transfer.amount, U256::from(42));
+    }
+
+    #[test]
+    fn json_ics20_payload_is_decoded() {
+        let value = br#"{"denom":"uatom","amount":"1000","sender":"a","receiver":"b","memo":""}"#;
+        let transfer = AppPorts::default()
+            .decode_transfer(&payload(
+                DEFAULT_TRANSFER_PORT,
+                JSON_ENCODING,
+                value.to_vec(),
+            ))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(transfer.receiver, "b");
+        assert_eq!(transfer.amount, U256::from(1000));
+    }
+
+    #[test]
+    fn evm_ift_mint_is_decoded_as_transfer_of_the_contract() {
+        let call = msgs::iftMintCall {
+            receiver: RECEIVER,
+            amount: U256::from(7),
+        };
+        let transfer = AppPorts::default()
+            .decode_transfer(&gmp(call.abi_encode()))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(transfer.app, App::Ift);
+        assert_eq!(transfer.denom, IFT.to_string());
+        assert_eq!(transfer.receiver, RECEIVER.to_string());
+        assert_eq!(transfer.amount, U256::from(7));
+    }
+
+    #[test]
+    fn evm_ift_contract_is_normalized() {
+        let contract = address!("0xabcdef0000000000000000000000000000000001");
+        let call = msgs::iftMintCall {
+            receiver: RECEIVER,
+            amount: U256::from(7),
+        }
+        .abi_encode();
+        let with_receiver = |receiver: String| {
+            let data = msgs::GMPPacketData {
+                sender: "0xsender".to_string(),
+                receiver,
+                salt: Vec::new().into(),
+                payload: call.clone().into(),
+                memo: String::new(),
+            };
+            AppPorts::default().decode_transfer(&payload(
+                DEFAULT_GMP_PORT,
+                ABI_ENCODING,
+                data.abi_encode(),
+            ))
+        };
+
+        let transfer = with_receiver(contract.to_string().to_lowercase())
+            .unwrap()
+            .unwrap();
+        assert_eq!(transfer.denom, contract.to_checksum(None));
+        assert!(matches!(
+            with_receiver("not-a-contract".to_string()),
+            Err(PayloadError::Malformed { .. })
+        ));
+    }
+
+    #[test]
+    fn cosmos_ift_mint_is_decoded() {
+        let call = br#"{"@type":"/examplechain.tokenfactory.MsgIFTMint","ica_address":"cosmos1ica","receiver":"cosmos1receiver","amount":{"denom":"testift","amount":"5"}}"#;
+        let transfer = AppPorts::default()
+            .decode_transfer(&gmp(call.to_vec()))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(transfer.denom, "testift");
+        assert_eq!(transfer.receiver, "cosmos1receiver");
+        assert_eq!(transfer.amount, U256::from(5));
+    }
+
+    #[test]
+    fn other_gmp_calls_carry_no_transfer() {
+        let ports = AppPorts::default();
+        assert!(
+            ports
+                .decode_transfer(&gmp(vec![0xde, 0xad, 0xbe, 0xef]))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            ports
+                .decode_transfer(&gmp(br#"{"@type":"/cosmos.bank.v1beta1.MsgSend"}"#.to_vec()))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn unknown_ports_are_not_decoded() {
+        assert!(
+            AppPorts::default()
+                .decode_transfer(&payload("oracle", "proto3", vec![1, 2, 3]))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn malformed_payloads_of_known_apps_are_rejected() {
+        let ports = AppPorts::default();
+        assert!(matches!(
+            ports.decode_transfer(&payload(DEFAULT_TRANSFER_PORT, ABI_ENCODING, vec![1])),
+            Err(PayloadError::Malformed { .. })
+        ));
+        assert!(matches!(
+            ports.decode_transfer(&payload(DEFAULT_TRANSFER_PORT, "proto3", vec![1])),
+            Err(PayloadError::UnsupportedEncoding { .. })
+        ));
+        assert!(matches!(
+            ports.decode_transfer(&gmp(
+                [msgs::iftMintCall::SELECTOR.as_slice(), &[0; 4]].concat()
+            )),
+            Err(PayloadError::Malformed { .. })
+        ));
+        assert!(matches!(
+            ports.decode_transfer(&gmp(
+                br#"{"@type":"/examplechain.tokenfactory.MsgIFTMint","receiver":"cosmos1"}"#
+                    .to_vec()
+            )),
+            Err(PayloadError::Malformed { .. })
+        ));
+    }
+}
@@ -0,0 +1,879 @@
+//! Restricts which clients and packets the attestor signs for.
+//!
+//! Without a policy the attestor signs for any client and payload as long as
+//! the commitment exists. Operators serving a single bridge can scope their
+//! key with the optional `[policy]` section. Requests are checked before any
+//! adapter call where the packets are known up front.
+//!
+//! Transfer limits and the receiver deny-list inspect the
+//! [payloads](crate::payload) of sent packets, the attestations a
+//! destination chain mints or unlocks on. Rolling windows are counted in
+//! memory once the commitments are validated and kept once the attestation
+//! is signed, so they restart with the attestor and are kept per instance.
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::{Address, U256};
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Packet;
+use serde::{Deserialize, Deserializer, de};
+use tracing::error;
+
+use crate::{
+    AttestorError,
+    config::ConfigError,
+    metrics,
+    payload::{AppPorts, Transfer},
+    rpc::api::CommitmentType,
+};
+
+/// Configuration of the signing [`Policy`]. Empty lists allow anything.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct PolicyConfig {
+    /// Client IDs on the attested chain that may be signed for: the source
+    /// client for packet commitments, the destination client for ack and
+    /// receipt commitments.
+    #[serde(default)]
+    pub allowed_clients: Vec<String>,
+
+    /// Port pairs every payload of a signed packet must use.
+    #[serde(default)]
+    pub allowed_port_pairs: Vec<PortPair>,
+
+    /// Maximum number of packets signed by a single request.
+    pub max_batch_size: Option<usize>,
+
+    /// Port of the ICS20 transfer application, `transfer` by default.
+    pub transfer_port: Option<String>,
+
+    /// Port of the ICS27-GMP application, `gmpport` by default.
+    pub gmp_port: Option<String>,
+
+    /// Per-denom limits on the value of sent packets.
+    #[serde(default)]
+    pub transfer_limits: Vec<TransferLimit>,
+
+    /// Receivers sent packets may not transfer to, compared
+    /// case-insensitively.
+    #[serde(default)]
+    pub denied_receivers: Vec<String>,
+}
+
+/// Limits on the value transferred in a single denom. IFT mints on EVM are
+/// limited by the address of the IFT contract, in any letter case.
+#[derive(Clone, Debug, Deserialize)]
+pub struct TransferLimit {
+    /// Denom the limits apply to
+    pub denom: String,
+    /// Largest amount of a single transfer
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub max_amount: Option<U256>,
+    /// Largest total amount signed for within `window_secs`
+    #[serde(default, deserialize_with = "deserialize_amount")]
+    pub window_amount: Option<U256>,
+    /// Length of the rolling window in seconds
+    pub window_secs: Option<u64>,
+}
+
+/// Source and destination port of a packet payload
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Hash)]
+pub struct PortPair {
+    /// Port on the source chain
+    pub source_port: String,
+    /// Port on the destination chain
+    pub dest_port: String,
+}
+
+/// Amounts exceed TOML integers, so they are given as decimal or
+/// `0x`-prefixed hex strings.
+fn deserialize_amount<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<U256>, D::Error> {
+    Option::<String>::deserialize(deserializer)?
+        .map(|amount| amount.parse().map_err(de::Error::custom))
+        .transpose()
+}
+
+impl PolicyConfig {
+    pub(crate) fn validate(self) -> Result<Self, ConfigError> {
+        if self.max_batch_size == Some(0) {
+            return Err(ConfigError::InvalidPolicyConfig(
+                "`policy.max_batch_size` must be positive".to_string(),
+            ));
+        }
+
+        let mut denoms = HashSet::new();
+        for limit in &self.transfer_limits {
+            if !denoms.insert(normalize_denom(&limit.denom)) {
+                return Err(ConfigError::InvalidPolicyConfig(format!(
+                    "duplicate transfer limit for `{}`",
+                    limit.denom
+                )));
+            }
+            match (limit.window_amount, limit.window_secs) {
+                (Some(_), Some(0)) => {
+                    return Err(ConfigError::InvalidPolicyConfig(format!(
+                        "`window_secs` of `{}` must be positive",
+                        limit.denom
+                    )));
+                }
+                (Some(_), None) | (None, Some(_)) => {
+                    return Err(ConfigError::InvalidPolicyConfig(format!(
+                        "`window_amount` and `window_secs` of `{}` must be set together",
+                        limit.denom
+                    )));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+/// Rules checked against attestation requests before signing
+#[derive(Debug, Default)]
+pub struct Policy {
+    allowed_clients: HashSet<String>,
+    allowed_port_pairs: HashSet<PortPair>,
+    max_batch_size: Option<usize>,
+    app_ports: AppPorts,
+    transfer_limits: HashMap<String, TransferLimit>,
+    denied_receivers: HashSet<String>,
+    windows: Mutex<HashMap<String, VecDeque<CountedTransfer>>>,
+}
+
+impl From<PolicyConfig> for Policy {
+    fn from(config: PolicyConfig) -> Self {
+        let defaults = AppPorts::default();
+        Self {
+            allowed_clients: config.allowed_clients.into_iter().collect(),
+            allowed_port_pairs: config.allowed_port_pairs.into_iter().collect(),
+            max_batch_size: config.max_batch_size,
+            app_ports: AppPorts {
+                transfer: config.transfer_port.unwrap_or(defaults.transfer),
+                gmp: config.gmp_port.unwrap_or(defaults.gmp),
+            },
+            transfer_limits: config
+                .transfer_limits
+                .into_iter()
+                .map(|limit| (normalize_denom(&limit.denom), limit))
+                .collect(),
+            denied_receivers: config
+                .denied_receivers
+                .iter()
+                .map(|receiver| receiver.to_lowercase())
+                .collect(),
+            windows: Mutex::default(),
+        }
+    }
+}
+
+/// Identifies a payload across requests, so a packet attested several times
+/// is counted once
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PayloadId {
+    source_client: String,
+    sequence: u64,
+    index: usize,
+}
+
+#[derive(Debug)]
+struct CountedTransfer {
+    at: Instant,
+    payload: PayloadId,
+    amount: U256,
+}
+
+/// Transfers of packets that passed [`Policy::check_packets`], to be counted
+/// against the rolling windows with [`Policy::admit`] once their commitments
+/// are validated
+#[derive(Debug, Default)]
+#[must_use]
+pub struct CheckedTransfers(Vec<(PayloadId, Transfer)>);
+
+impl CheckedTransfers {
+    /// Move the transfers of `other` into these, to admit them together.
+    pub fn append(&mut self, mut other: Self) {
+        self.0.append(&mut other.0);
+    }
+}
+
+/// Transfers counted by [`Policy::admit`]. Dropping the admission before
+/// [`Admission::commit`] uncounts them, so requests that fail to sign don't
+/// use up the windows.
+#[derive(Debug)]
+#[must_use]
+pub struct Admission<'a> {
+    policy: &'a Policy,
+    counted: Vec<(String, PayloadId)>,
+}
+
+impl Admission<'_> {
+    /// Keep the transfers counted once their attestation is signed.
+    pub fn commit(mut self) {
+        self.counted.clear();
+    }
+}
+
+impl Drop for Admission<'_> {
+    fn drop(&mut self) {
+        if self.counted.is_empty() {
+            return;
+        }
+        let Ok(mut windows) = self.policy.windows.lock() else {
+            return;
+        };
+        for (denom, payload) in self.counted.drain(..) {
+            if let Some(counted) = windows.get_mut(&denom) {
+                counted.retain(|entry| entry.payload != payload);
+            }
+        }
+    }
+}
+
+impl Policy {
+    /// Check that `client_id` may be signed for.
+    ///
+    /// # Errors
+    /// Returns [`AttestorError::PolicyViolation`] if the client is not allowed.
+    pub fn check_client(&self, client_id: &str) -> Result<(), AttestorError> {
+        if self.allowed_clients.is_empty() || self.allowed_clients.contains(client_id) {
+            return Ok(());
+        }
+
+        error!(clientId = client_id, "client is not allowed by policy");
+        Err(violation("client", format!("client {client_id}")))
+    }
+
+    /// Check that a single request may sign `size` packets.
+    ///
+    /// # Errors
+    /// Returns [`AttestorError::PolicyViolation`] if the batch is too large.
+    pub fn check_batch_size(&self, size: usize) -> Result<(), AttestorError> {
+        match self.max_batch_size {
+            Some(max) if size > max => {
+                error!(size, max, "batch size exceeds policy limit");
+                Err(violation(
+                    "batch_size",
+                    format!("batch of {size} packets, at most {max} allowed"),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the client owning the commitments of `commitment_type` and the
+    /// ports of every payload of `packets`. Payloads of sent packets are
+    /// checked against the receiver deny-list and per-transfer limits.
+    ///
+    /// # Errors
+    /// Returns [`AttestorError::PolicyViolation`] on the first rule a packet
+    /// breaks.
+    pub fn check_packets<'a>(
+        &self,
+        packets: impl IntoIterator<Item = &'a Packet>,
+        commitment_type: CommitmentType,
+    ) -> Result<CheckedTransfers, AttestorError> {
+        let mut transfers = Vec::new();
+        for packet in packets {
+            let client_id = match commitment_type {
+                CommitmentType::Packet => &packet.sourceClient,
+                CommitmentType::Ack | CommitmentType::Receipt => &packet.destClient,
+            };
+            self.check_client(client_id)?;
+            self.check_ports(packet)?;
+            if self.inspects_payloads(commitment_type) {
+                transfers.extend(self.check_transfers(packet)?);
+            }
+        }
+        Ok(CheckedTransfers(transfers))
+    }
+
+    /// Check that packets of `commitment_type` may be signed without knowing
+    /// their ports and payloads, as for ack and receipt sequences and sent
+    /// packets the attestor could not locate on chain.
+    ///
+    /// # Errors
+    /// Returns [`AttestorError::PolicyViolation`] if port pairs are
+    /// restricted, or payloads are inspected for `commitment_type`.
+    pub fn check_unknown_payloads(
+        &self,
+        commitment_type: CommitmentType,
+    ) -> Result<(), AttestorError> {
+        if !self.allowed_port_pairs.is_empty() {
+            error!("packet ports are unknown but restricted by policy");
+            return Err(violation(
+                "port_pair",
+                "packet ports could not be checked".to_string(),
+            ));
+        }
+        if !self.inspects_payloads(commitment_type) {
+            return Ok(());
+        }
+
+        error!("packet payloads are unknown but inspected by policy");
+        Err(violation(
+            "payload",
+            "packet payloads could not be inspected".to_string(),
+        ))
+    }
+
+    /// Count `transfers` against the rolling windows of their denoms.
+    /// Payloads counted before within the window are not counted again.
+    /// The transfers stay counted once the returned [`Admission`] is
+    /// committed, after signing.
+    ///
+    /// # Errors
+    /// Returns [`AttestorError::PolicyViolation`] if a window would be
+    /// exceeded, in which case nothing is counted.
+    ///
+    /// # Panics
+    /// Panics if the window lock was poisoned by a panicking writer.
+    pub fn admit(&self, transfers: &CheckedTransfers) -> Result<Admission<'_>, AttestorError> {
+        self.admit_at(transfers, Instant::now())
+    }
+
+    fn admit_at(
+        &self,
+        transfers: &CheckedTransfers,
+        now: Instant,
+    ) -> Result<Admission<'_>, AttestorError> {
+        let mut windows = self.windows.lock().expect("policy window lock poisoned");
+
+        let mut admitted = Vec::new();
+        let mut totals = HashMap::<&str, U256>::new();
+        for (payload, transfer) in &transfers.0 {
+            let Some((max, window)) = self.window(&transfer.denom) else {
+                continue;
+            };
+            let counted = windows.entry(transfer.denom.clone()).or_default();
+            while counted
+                .front()
+                .is_some_and(|entry| now.duration_since(entry.at) >= window)
+            {
+                counted.pop_front();
+            }
+            if counted.iter().any(|entry| entry.payload == *payload)
+                || admitted.iter().any(|(id, _)| *id == payload)
+            {
+                continue;
+            }
+
+            let total = totals.entry(&transfer.denom).or_insert_with(|| {
+                counted
+                    .iter()
+                    .fold(U256::ZERO, |sum, entry| sum.saturating_add(entry.amount))
+            });
+            *total = total.saturating_add(transfer.amount);
+            if *total > max {
+                error!(
+                    denom = %transfer.denom,
+                    %max,
+                    windowSecs = window.as_secs(),
+                    "transfer exceeds policy rate limit"
+                );
+                return Err(violation(
+                    "rate_limit",
+                    format!(
+                        "more than {max} {} within {}s",
+                        transfer.denom,
+                        window.as_secs()
+                    ),
+                ));
+            }
+            admitted.push((payload, transfer));
+        }
+
+        let mut counted = Vec::with_capacity(admitted.len());
+        for (payload, transfer) in admitted {
+            windows
+                .entry(transfer.denom.clone())
+                .or_default()
+                .push_back(CountedTransfer {
+                    at: now,
+                    payload: payload.clone(),
+                    amount: transfer.amount,
+                });
+            counted.push((transfer.denom.clone(), payload.clone()));
+        }
+        Ok(Admission {
+            policy: self,
+            counted,
+        })
+    }
+
+    fn window(&self, denom: &str) -> Option<(U256, Duration)> {
+        let limit = self.transfer_limits.get(denom)?;
+        Some((
+            limit.window_amount?,
+            Duration::from_secs(limit.window_secs?),
+        ))
+    }
+
+    fn inspects_payloads(&self, commitment_type: CommitmentType) -> bool {
+        commitment_type == CommitmentType::Packet
+            && !(self.transfer_limits.is_empty() && self.denied_receivers.is_empty())
+    }
+
+    fn check_transfers(
+        &self,
+        packet: &Packet,
+    ) -> Result<Vec<(PayloadId, Transfer)>, AttestorError> {
+        let mut transfers = Vec::new();
+        for (index, payload) in packet.payloads.iter().enumerate() {
+            let transfer = self.app_ports.decode_transfer(payload).map_err(|e| {
+                error!(error = %e, sequence = packet.sequence, "payload can't be inspected");
+                violation("payload", e.to_string())
+            })?;
+            let Some(mut transfer) = transfer else {
+                continue;
+            };
+            // ICS20 denoms of ERC20 tokens are addresses too
+            transfer.denom = normalize_denom(&transfer.denom);
+
+            if self
+                .denied_receivers
+                .contains(&transfer.receiver.to_lowercase())
+            {
+                error!(receiver = %transfer.receiver, "receiver is denied by policy");
+                return Err(violation(
+                    "denied_receiver",
+                    format!("receiver {}", transfer.receiver),
+                ));
+            }
+            if let Some(max) = self
+                .transfer_limits
+                .get(&transfer.denom)
+                .and_then(|limit| limit.max_amount)
+                && transfer.amount > max
+            {
+                error!(
+                    denom = %transfer.denom,
+                    amount = %transfer.amount,
+                    %max,
+                    "transfer exceeds policy cap"
+                );
+                return Err(violation(
+                    "transfer_cap",
+                    format!(
+                        "transfer of {} {}, at most {max} allowed",
+                        transfer.amount, transfer.denom
+                    ),
+                ));
+            }
+
+            transfers.push((
+                PayloadId {
+                    source_client: packet.sourceClient.clone(),
+                    sequence: packet.sequence,
+                    index,
+                },
+                transfer,
+            ));
+        }
+        Ok(transfers)
+    }
+
+    fn check_ports(&self, packet: &Packet) -> Result<(), AttestorError> {
+        if self.allowed_port_pairs.is_empty() {
+            return Ok(());
+        }
+
+        for payload in &packet.payloads {
+            let pair = PortPair {
+                source_port: payload.sourcePort.clone(),
+                dest_port: payload.destPort.clone(),
+            };
+            if !self.allowed_port_pairs.contains(&pair) {
+                error!(
+                    sourcePort = %pair.source_port,
+                    destPort = %pair.dest_port,
+                    "port pair is not allowed by policy"
+                );
+                return Err(violation(
+                    "port_pair",
+                    format!("ports {} -> {}", pair.source_port, pair.dest_port),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checksum `0x`-prefixed EVM addresses, as IFT contracts are
+/// [decoded](crate::payload), and leave other denoms as they are
+fn normalize_denom(denom: &str) -> String {
+    if denom.starts_with("0x")
+        && let Ok(address) = denom.parse::<Address>()
+    {
+        return address.to_string();
+    }
+    denom.to_string()
+}
+
+fn violation(rule: &'static str, message: String) -> AttestorError {
+    metrics::inc_policy_rejection(rule);
+    AttestorError::PolicyViolation(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payload::msgs::FungibleTokenPacketData;
+    use alloy_sol_types::SolValue;
+    use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Payload;
+
+    fn packet(source_client: &str, dest_client: &str, ports: &[(&str, &str)]) -> Packet {
+        Packet {
+            sequence: 1,
+            sourceClient: source_client.to_string(),
+            destClient: dest_client.to_string(),
+            timeoutTimestamp: 123_456_789,
+            payloads: ports
+                .iter()
+                .map(|(source_port, dest_port)| Payload {
+                    sourcePort: (*source_port).to_string(),
+                    destPort: (*dest_port).to_string(),
+                    version: "ics20-1".to_string(),
+                    encoding: "proto3".to_string(),
+                    value: vec![1, 2, 3].into(),
+                })
+                .collect(),
+        }
+    }
+
+    fn policy() -> Policy {
+        Policy::from(PolicyConfig {
+            allowed_clients: vec!["client-0".to_string()],
+            allowed_port_pairs: vec![PortPair {
+                source_port: "ift".to_string(),
+                dest_port: "ift".to_string(),
+            }],
+            max_batch_size: Some(2),
+            ..PolicyConfig::default()
+        })
+    }
+
+    fn transfer(sequence: u64, denom: &str, receiver: &str, amount: u64) -> Packet {
+        let data = FungibleTokenPacketData {
+            denom: denom.to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: receiver.to_string(),
+            amount: U256::from(amount),
+            memo: String::new(),
+        };
+        Packet {
+            sequence,
+            sourceClient: "client-0".to_string(),
+            destClient: "client-9".to_string(),
+            timeoutTimestamp: 123_456_789,
+            payloads: vec![Payload {
+                sourcePort: "transfer".to_string(),
+                destPort: "transfer".to_string(),
+                version: "ics20-1".to_string(),
+                encoding: "application/x-solidity-abi".to_string(),
+                value: data.abi_encode().into(),
+            }],
+        }
+    }
+
+    fn value_policy() -> Policy {
+        Policy::from(PolicyConfig {
+            transfer_limits: vec![TransferLimit {
+                denom: "uatom".to_string(),
+                max_amount: Some(U256::from(100)),
+                window_amount: Some(U256::from(150)),
+                window_secs: Some(60),
+            }],
+            denied_receivers: vec!["0xDEAD".to_string()],
+            ..PolicyConfig::default()
+        })
+    }
+
+    #[test]
+    fn default_policy_allows_anything() {
+        let packets = vec![packet("a", "b", &[("transfer", "transfer")]); 100];
+        assert!(Policy::default().check_batch_size(packets.len()).is_ok());
+        assert!(
+            Policy::default()
+                .check_packets(&packets, CommitmentType::Packet)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn client_is_taken_from_the_commitment_side() {
+        let sent = [packet("client-0", "client-9", &[("ift", "ift")])];
+        assert!(
+            policy()
+                .check_packets(&sent, CommitmentType::Packet)
+                .is_ok()
+        );
+        assert!(matches!(
+            policy().check_packets(&sent, CommitmentType::Ack),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+
+        let received = [packet("client-9", "client-0", &[("ift", "ift")])];
+        assert!(
+            policy()
+                .check_packets(&received, CommitmentType::Receipt)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn every_payload_must_use_an_allowed_port_pair() {
+        let packets = [packet(
+            "client-0",
+            "client-9",
+            &[("ift", "ift"), ("transfer", "transfer")],
+        )];
+        assert!(matches!(
+            policy().check_packets(&packets, CommitmentType::Packet),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+    }
+
+    #[test]
+    fn batch_size_is_limited() {
+        assert!(policy().check_batch_size(2).is_ok());
+        assert!(matches!(
+            policy().check_batch_size(3),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+    }
+
+    #[test]
+    fn zero_batch_size_is_rejected() {
+        let config = PolicyConfig {
+            max_batch_size: Some(0),
+            ..PolicyConfig::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidPolicyConfig(_))
+        ));
+    }
+
+    #[test]
+    fn transfers_above_the_cap_are_rejected() {
+        let policy = value_policy();
+        assert!(
+            policy
+                .check_packets(
+                    &[transfer(1, "uatom", "0xbeef", 100)],
+                    CommitmentType::Packet
+                )
+                .is_ok()
+        );
+        assert!(matches!(
+            policy.check_packets(
+                &[transfer(1, "uatom", "0xbeef", 101)],
+                CommitmentType::Packet
+            ),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+        // Other denoms are not limited
+        assert!(
+            policy
+                .check_packets(
+                    &[transfer(1, "uosmo", "0xbeef", 1000)],
+                    CommitmentType::Packet
+                )
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn denied_receivers_are_matched_case_insensitively() {
+        assert!(matches!(
+            value_policy()
+                .check_packets(&[transfer(1, "uosmo", "0xdead", 1)], CommitmentType::Packet),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+    }
+
+    #[test]
+    fn payloads_are_only_inspected_for_sent_packets() {
+        let packets = [transfer(1, "uatom", "0xdead", 1000)];
+        assert!(
+            value_policy()
+                .check_packets(&packets, CommitmentType::Ack)
+                .is_ok()
+        );
+        assert!(
+            value_policy()
+                .check_unknown_payloads(CommitmentType::Receipt)
+                .is_ok()
+        );
+        assert!(
+            value_policy()
+                .check_unknown_payloads(CommitmentType::Packet)
+                .is_err()
+        );
+        assert!(
+            Policy::default()
+                .check_unknown_payloads(CommitmentType::Packet)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn unknown_packets_are_rejected_when_ports_are_restricted() {
+        let policy = Policy::from(PolicyConfig {
+            allowed_port_pairs: vec![PortPair {
+                source_port: "transfer".to_string(),
+                dest_port: "transfer".to_string(),
+            }],
+            ..PolicyConfig::default()
+        });
+
+        for commitment_type in [
+            CommitmentType::Packet,
+            CommitmentType::Ack,
+            CommitmentType::Receipt,
+        ] {
+            assert!(matches!(
+                policy.check_unknown_payloads(commitment_type),
+                Err(AttestorError::PolicyViolation(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn address_denoms_match_in_any_case() {
+        let denom = "0xabcdef0000000000000000000000000000000001";
+        let policy = Policy::from(PolicyConfig {
+            transfer_limits: vec![TransferLimit {
+                denom: denom.to_uppercase().replacen("0X", "0x", 1),
+                max_amount: Some(U256::from(100)),
+                window_amount: None,
+                window_secs: None,
+            }],
+            ..PolicyConfig::default()
+        });
+
+        assert!(matches!(
+            policy.check_packets(&[transfer(1, denom, "0xbeef", 101)], CommitmentType::Packet),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+    }
+
+    #[test]
+    fn rolling_window_limits_the_total_amount() {
+        let policy = value_policy();
+        let start = Instant::now();
+        let checked = |sequence, amount| {
+            policy
+                .check_packets(
+                    &[transfer(sequence, "uatom", "0xbeef", amount)],
+                    CommitmentType::Packet,
+                )
+                .unwrap()
+        };
+
+        policy.admit_at(&checked(1, 100), start).unwrap().commit();
+        // Attesting the same packet again is not counted twice
+        policy.admit_at(&checked(1, 100), start).unwrap().commit();
+        assert!(matches!(
+            policy.admit_at(&checked(2, 51), start),
+            Err(AttestorError::PolicyViolation(_))
+        ));
+        policy.admit_at(&checked(3, 50), start).unwrap().commit();
+        policy
+            .admit_at(&checked(4, 100), start + Duration::from_secs(60))
+            .unwrap()
+            .commit();
+    }
+
+    #[test]
+    fn uncommitted_admissions_are_uncounted() {
+        let policy = value_policy();
+        let now = Instant::now();
+        let checked = |sequence, amount| {
+            policy
+                .check_packets(
+                    &[transfer(sequence, "uatom", "0xbeef", amount)],
+                    CommitmentType::Packet,
+                )
+                .unwrap()
+        };
+
+        // Signing failed, so the window is left as it was
+        drop(policy.admit_at(&checked(1, 100), now).unwrap());
+        policy.admit_at(&checked(2, 100), now).unwrap().commit();
+        assert!(policy.admit_at(&checked(3, 51), now).is_err());
+    }
+
+    #[test]
+    fn rejected_batches_are_not_counted() {
+        let policy = value_policy();
+        let now = Instant::now();
+        let batch = policy
+            .check_packets(
+                &[
+                    transfer(1, "uatom", "0xbeef", 100),
+                    transfer(2, "uatom", "0xbeef", 100),
+                ],
+                CommitmentType::Packet,
+            )
+            .unwrap();
+
+        assert!(policy.admit_at(&batch, now).is_err());
+        policy
+            .admit_at(
+                &policy
+                    .check_packets(
+                        &[transfer(3, "uatom", "0xbeef", 100)],
+                        CommitmentType::Packet,
+                    )
+                    .unwrap(),
+                now,
+            )
+            .unwrap()
+            .commit();
+    }
+
+    #[test]
+    fn window_limits_need_amount_and_length() {
+        let config = |window_amount: Option<u64>, window_secs| PolicyConfig {
+            transfer_limits: vec![TransferLimit {
+                denom: "uatom".to_string(),
+                max_amount: None,
+                window_amount: window_amount.map(U256::from),
+                window_secs,
+            }],
+            ..PolicyConfig::default()
+        };
+
+        assert!(config(Some(1), Some(60)).validate().is_ok());
+        assert!(config(Some(1), None).validate().is_err());
+        assert!(config(None, Some(60)).validate().is_err());
+        assert!(config(Some(1), Some(0)).validate().is_err());
+    }
+
+    #[test]
+    fn transfer_limits_parse_from_toml() {
+        let config: PolicyConfig = toml::from_str(
+            r#"
+            denied_receivers = ["0xdead"]
+
+            [[transfer_limits]]
+            denom = "uatom"
+            max_amount = "1000000"
+            window_amount = "0x10000000"
+            window_secs = 3600
+            "#,
+        )
+        .unwrap();
+
+        let limit = &config.transfer_limits[0];
+        assert_eq!(limit.max_amount, Some(U256::from(1_000_000)));
+        assert_eq!(limit.window_amount, Some(U256::from(0x1000_0000)));
+        assert!(config.validate().is_ok());
+    }
+}
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status, service::Interceptor};
+use tracing::warn;
+
+use super::api::{
+    GetPauseStateRequest, PauseSigningRequest, PauseStateResponse, ResumeSigningRequest,
+    admin_service_server::AdminService,
+};
+use crate::pause::{PauseState, PauseSwitch};
+
+/// Operator service pausing and resuming signing
+pub struct AttestorAdmin {
+    switch: PauseSwitch,
+}
+
+impl AttestorAdmin {
+    pub const fn new(switch: PauseSwitch) -> Self {
+        Self { switch }
+    }
+}
+
+impl From<PauseState> for PauseStateResponse {
+    fn from(state: PauseState) -> Self {
+        Self {
+            paused: state.is_paused(),
+            admin_paused: state.admin_reason.is_some(),
+            admin_reason: state.admin_reason.unwrap_or_default(),
+            pause_file_present: state.file_present,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl AdminService for AttestorAdmin {
+    async fn pause_signing(
+        &self,
+        request: Request<PauseSigningRequest>,
+    ) -> Result<Response<PauseStateResponse>, Status> {
+        let reason = request.into_inner().reason;
+        Ok(Response::new(self.switch.pause(reason).into()))
+    }
+
+    async fn resume_signing(
+        &self,
+        _request: Request<ResumeSigningRequest>,
+    ) -> Result<Response<PauseStateResponse>, Status> {
+        Ok(Response::new(self.switch.resume().into()))
+    }
+
+    async fn get_pause_state(
+        &self,
+        _request: Request<GetPauseStateRequest>,
+    ) -> Result<Response<PauseStateResponse>, Status> {
+        Ok(Response::new(self.switch.state().into()))
+    }
+}
+
+/// Interceptor admitting admin requests carrying the configured bearer token
+#[derive(Clone)]
+pub struct AdminAuth {
+    expected: Arc<[u8]>,
+}
+
+impl AdminAuth {
+    pub fn new(token: &str) -> Self {
+        Self {
+            expected: format!("Bearer {token}").into_bytes().into(),
+        }
+    }
+}
+
+impl Interceptor for AdminAuth {
+    #[allow(clippy::result_large_err)] // Signature fixed by tonic
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let authorized = request
+            .metadata()
+            .get("authorization")
+            .is_some_and(|value| constant_time_eq(value.as_bytes(), &self.expected));
+        if !authorized {
+            warn!("rejected admin request without a valid token");
+            return Err(Status::unauthenticated("invalid admin token"));
+        }
+        Ok(request)
+    }
+}
+
+/// Compare without short-circuiting on the first differing byte, so the
+/// token can't be guessed from response times
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(authorization: Option<&str>) -> Request<()> {
+        let mut request = Request::new(());
+        if let Some(value) = authorization {
+            request
+                .metadata_mut()
+                .insert("authorization", value.parse().unwrap());
+        }
+        request
+    }
+
+    #[test]
+    fn requests_need_the_admin_token() {
+        let mut auth = AdminAuth::new("secret");
+
+        assert!(auth.call(request(Some("Bearer secret"))).is_ok());
+        for authorization in [
+            None,
+            Some("Bearer other"),
+            Some("secret"),
+            Some("Bearer secret2"),
+        ] {
+            assert_eq!(
+                auth.call(request(authorization)).unwrap_err().code(),
+                tonic::Code::Unauthenticated
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn signing_is_paused_and_resumed() {
+        let switch = PauseSwitch::default();
+        let service = AttestorAdmin::new(switch.clone());
+
+        let paused = service
+            .pause_signing(Request::new(PauseSigningRequest {
+                reason: "incident".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(paused.paused && paused.admin_paused);
+        assert_eq!(paused.admin_reason, "incident");
+        assert!(switch.is_paused());
+
+        let resumed = service
+            .resume_signing(Request::new(ResumeSigningRequest {}))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(!resumed.paused);
+        assert!(!switch.is_paused());
+    }
+}
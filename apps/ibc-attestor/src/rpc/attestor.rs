@@ -1,24 +1,65 @@
-use alloy_primitives::keccak256;
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::{Address, B256, keccak256};
 use alloy_sol_types::SolValue;
-use futures::{StreamExt, stream::FuturesOrdered};
+use ethereum_keys::recover::recover_address;
+use futures::{
+    StreamExt,
+    stream::{self, FuturesOrdered},
+};
 use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Packet;
 use ibc_eureka_solidity_types::msgs::IAttestationMsgs;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::{OnceCell, Semaphore, mpsc, watch};
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
-use tracing::{debug, error};
+use tracing::{debug, error, info};
 
 use super::api::attestation_service_server::AttestationService;
+#[cfg(feature = "bls")]
+use crate::signer::bls;
 use crate::{
     AttestorError, Packets,
-    adapter::AttestationAdapter,
-    attestation::{SignedAttestation, sign_attestation},
-    attestation_payload::{AttestationPayload, AttestationType},
+    adapter::{
+        AttestationAdapter, AttestationAdapterError, ChainInfo, ConsensusHeader, ContractRead,
+        EventLog, PacketEventKind, TxPacketEvents,
+    },
+    attestation::{
+        SignedAttestation,
+        msgs::{
+            ConsensusStateAttestation, ContractStateAttestation, EventLogAttestation,
+            LatestHeightAttestation, PacketBatchAttestation, StoreKeyAttestation,
+        },
+        sign_attestation,
+    },
+    attestation_payload::{AttestationPayload, AttestationType, SigningMode},
+    config::RequestLimits,
+    head::HeadTracker,
+    merkle::PacketMerkleTree,
     metrics,
+    policy::Policy,
     rpc::api::{
-        Attestation, CommitmentType, LatestHeightRequest, LatestHeightResponse,
-        PacketAttestationRequest, PacketAttestationResponse, StateAttestationRequest,
-        StateAttestationResponse,
+        self, Attestation, CommitmentType, ConsensusStateAttestationRequest,
+        ConsensusStateAttestationResponse, ContractStateAttestationRequest,
+        ContractStateAttestationResponse, EventLogAttestationRequest, EventLogAttestationResponse,
+        FirstCommitmentHeightRequest, FirstHeightAtTimestampRequest, GetAttestorInfoRequest,
+        GetAttestorInfoResponse, HeightSearchResponse, LatestHeightRequest, LatestHeightResponse,
+        PacketAttestationBySequenceRequest, PacketAttestationBySequenceResponse,
+        PacketAttestationRequest, PacketAttestationResponse, PacketBatchAttestationRequest,
+        PacketBatchAttestationResponse, PacketInclusionProof, StateAttestationRequest,
+        StateAttestationResponse, StoreKeyAttestationRequest, StoreKeyAttestationResponse,
+        SubscribeStateAttestationsRequest, TransactionPacketAttestationRequest,
+        TransactionPacketAttestationResponse, UnrelayedPacketsRequest, UnrelayedPacketsResponse,
+        WatchedPacketAttestationRequest, WatchedPacketAttestationResponse,
+        contract_state_attestation_request,
     },
-    signer::Signer,
+    search::find_first_height,
+    signer::{AttestorSignature, SignatureScheme, Signer},
+    sink::Publisher,
+    watcher::{AttestationStore, StoreKey, StoredAttestation},
 };
 
 /// gRPC service implementation for attestation requests
@@ -27,25 +68,58 @@ use crate::{
 /// - Getting the latest finalized height
 /// - Generating state attestations
 /// - Generating packet attestations
+/// - Generating packet attestations by client ID and sequence
+/// - Generating packet attestations from a transaction hash
+/// - Listing unrelayed packets
+/// - Searching the earliest height matching a commitment or timestamp
+/// - Streaming state attestations for new finalized heights
+/// - Looking up packet attestations pre-computed by the watcher
+/// - Describing the attestor identity and configuration
 pub struct AttestorService<A, S> {
-    adapter: A,
+    adapter: Arc<A>,
     adapter_name: &'static str,
-    signer: S,
+    signer: Arc<S>,
     signer_name: &'static str,
+    mode: SigningMode,
+    policy: Arc<Policy>,
+    head: HeadTracker,
+    store: Option<Arc<AttestationStore>>,
+    publisher: Publisher,
+    limits: RequestLimits,
+    subscriptions: Arc<Semaphore>,
+    chain_info: OnceCell<ChainInfo>,
+    identity: Arc<ProvenIdentity>,
 }
 
 impl<A, S> AttestorService<A, S> {
-    pub const fn new(
-        adapter: A,
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        adapter: Arc<A>,
         adapter_name: &'static str,
-        signer: S,
+        signer: Arc<S>,
         signer_name: &'static str,
+        identity: Arc<ProvenIdentity>,
+        mode: SigningMode,
+        policy: Arc<Policy>,
+        head: HeadTracker,
+        store: Option<Arc<AttestationStore>>,
+        publisher: Publisher,
+        limits: RequestLimits,
     ) -> Self {
         Self {
             adapter,
             adapter_name,
             signer,
             signer_name,
+            mode,
+            policy,
+            head,
+            store,
+            publisher,
+            limits,
+            subscriptions: Arc::new(Semaphore::new(limits.max_subscriptions)),
+            chain_info: OnceCell::new(),
+            identity,
         }
     }
 
@@ -58,15 +132,34 @@ impl<A, S> AttestorService<A, S> {
     }
 }
 
+impl<A: AttestationAdapter, S> AttestorService<A, S> {
+    /// Chain identity, fetched once as it doesn't change while running
+    async fn chain_info(&self) -> Result<&ChainInfo, AttestorError> {
+        self.chain_info
+            .get_or_try_init(|| self.adapter.get_chain_info())
+            .await
+            .map_err(AttestorError::from)
+    }
+}
+
+/// Number of attestations buffered per subscription before the stream stops
+/// producing and waits for the client to catch up.
+const SUBSCRIPTION_BUFFER: usize = 16;
+
+/// Stream of state attestations pushed to a subscriber
+pub type StateAttestationStream = ReceiverStream<Result<StateAttestationResponse, Status>>;
+
 #[tonic::async_trait]
 impl<A, S> AttestationService for AttestorService<A, S>
 where
     A: AttestationAdapter,
     S: Signer,
 {
+    type SubscribeStateAttestationsStream = StateAttestationStream;
+
     async fn latest_height(
         &self,
-        _request: Request<LatestHeightRequest>,
+        request: Request<LatestHeightRequest>,
     ) -> Result<Response<LatestHeightResponse>, Status> {
         let height = self
             .adapter
@@ -74,7 +167,26 @@ where
             .await
             .map_err(AttestorError::from)?;
 
-        Ok(Response::new(LatestHeightResponse { height }))
+        let attestation = if request.get_ref().sign {
+            let chain_info = self.chain_info().await?;
+            let attestation = sign_latest_height_attestation(
+                height,
+                &chain_info.chain_id,
+                unix_now(),
+                &self.mode,
+                self.signer.as_ref(),
+                &self.publisher,
+            )
+            .await?;
+            Some(attestation.into())
+        } else {
+            None
+        };
+
+        Ok(Response::new(LatestHeightResponse {
+            height,
+            attestation,
+        }))
     }
 
     async fn state_attestation(
@@ -83,29 +195,162 @@ where
     ) -> Result<Response<StateAttestationResponse>, Status> {
         let height = request.get_ref().height;
 
-        validate_height(&self.adapter, height).await?;
+        validate_height(self.adapter.as_ref(), height).await?;
 
-        // Create unsigned attestation
         let timestamp = self
             .adapter
             .get_block_timestamp(height)
             .await
             .map_err(AttestorError::from)?;
-        let unsigned_attestation = IAttestationMsgs::StateAttestation { height, timestamp };
-        let attested_data = unsigned_attestation.abi_encode();
-
-        // Signed attestation
-        let attestation = sign_attestation(
+        let attestation = sign_state_attestation(
             height,
-            Some(timestamp),
-            AttestationPayload::new(attested_data, AttestationType::State),
-            &self.signer,
+            timestamp,
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
         )
         .await?;
 
         Ok(Response::from(attestation))
     }
 
+    async fn consensus_state_attestation(
+        &self,
+        request: Request<ConsensusStateAttestationRequest>,
+    ) -> Result<Response<ConsensusStateAttestationResponse>, Status> {
+        let height = request.get_ref().height;
+
+        validate_height(self.adapter.as_ref(), height).await?;
+
+        let header = self
+            .adapter
+            .get_block_header(height)
+            .await
+            .map_err(AttestorError::from)?;
+        let attestation = sign_consensus_state_attestation(
+            &header,
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
+        )
+        .await?;
+
+        Ok(Response::new(ConsensusStateAttestationResponse {
+            attestation: Some(attestation.into()),
+        }))
+    }
+
+    async fn contract_state_attestation(
+        &self,
+        request: Request<ContractStateAttestationRequest>,
+    ) -> Result<Response<ContractStateAttestationResponse>, Status> {
+        let request_inner = request.into_inner();
+        let height = request_inner.height;
+        let read = contract_read(request_inner)?;
+
+        validate_height(self.adapter.as_ref(), height).await?;
+
+        let result = self
+            .adapter
+            .get_contract_state(height, &read)
+            .await
+            .map_err(AttestorError::from)?;
+        let attestation = sign_contract_state_attestation(
+            height,
+            &read,
+            result.clone(),
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
+        )
+        .await?;
+
+        Ok(Response::new(ContractStateAttestationResponse {
+            attestation: Some(attestation.into()),
+            result,
+        }))
+    }
+
+    async fn store_key_attestation(
+        &self,
+        request: Request<StoreKeyAttestationRequest>,
+    ) -> Result<Response<StoreKeyAttestationResponse>, Status> {
+        let request_inner = request.into_inner();
+        let height = request_inner.height;
+
+        validate_height(self.adapter.as_ref(), height).await?;
+
+        let value = self
+            .adapter
+            .get_store_value(height, &request_inner.store, &request_inner.key)
+            .await
+            .map_err(AttestorError::from)?;
+        let attestation = sign_store_key_attestation(
+            height,
+            request_inner.store,
+            request_inner.key,
+            value.clone(),
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
+        )
+        .await?;
+
+        Ok(Response::new(StoreKeyAttestationResponse {
+            attestation: Some(attestation.into()),
+            value,
+        }))
+    }
+
+    async fn event_log_attestation(
+        &self,
+        request: Request<EventLogAttestationRequest>,
+    ) -> Result<Response<EventLogAttestationResponse>, Status> {
+        let request_inner = request.into_inner();
+        let tx_hash = request_inner.tx_hash;
+        let log_index = request_inner.log_index;
+
+        let Some(log) = self
+            .adapter
+            .get_event_log(&tx_hash, log_index)
+            .await
+            .map_err(AttestorError::from)?
+        else {
+            error!("event log not found on chain");
+            return Err(AttestorError::EventLogNotFound { tx_hash, log_index }.into());
+        };
+        if !has_leading_topics(&log, &request_inner.topics) {
+            error!("event log topics differ from the expected topics");
+            return Err(AttestorError::EventLogMismatch { tx_hash, log_index }.into());
+        }
+
+        validate_height(self.adapter.as_ref(), log.height).await?;
+
+        // The receipt may still point at a block that was reorged out before
+        // the log's height finalized
+        let header = self
+            .adapter
+            .get_block_header(log.height)
+            .await
+            .map_err(AttestorError::from)?;
+        if header.block_hash != log.block_hash {
+            error!(
+                blockHash = %hex::encode(log.block_hash),
+                canonicalBlockHash = %hex::encode(header.block_hash),
+                "event log block is not canonical"
+            );
+            return Err(AttestorError::EventLogNotFound { tx_hash, log_index }.into());
+        }
+
+        let attestation =
+            sign_event_log_attestation(&log, &self.mode, self.signer.as_ref(), &self.publisher)
+                .await?;
+
+        Ok(Response::new(EventLogAttestationResponse {
+            attestation: Some(attestation.into()),
+        }))
+    }
+
     async fn packet_attestation(
         &self,
         request: Request<PacketAttestationRequest>,
@@ -115,132 +360,1044 @@ where
         let packets = Packets::try_from_abi_encoded(&request_inner.packets)?;
         let commitment_type =
             CommitmentType::try_from(request_inner.commitment_type).map_err(AttestorError::from)?;
+        self.policy.check_batch_size(packets.len())?;
+        let transfers = self.policy.check_packets(&packets, commitment_type)?;
 
-        validate_height(&self.adapter, height).await?;
+        validate_height(self.adapter.as_ref(), height).await?;
 
         // Create unsigned attestation
         let unsigned_attestation =
-            create_packets_attestation(&self.adapter, packets, height, commitment_type).await?;
+            create_packets_attestation(self.adapter.as_ref(), packets, height, commitment_type)
+                .await?;
+        let admission = self.policy.admit(&transfers)?;
         let attested_data = unsigned_attestation.abi_encode();
 
         // Signed attestation
-        let attestation = sign_attestation(
+        let attestation = sign_and_publish(
             height,
             None,
             AttestationPayload::new(attested_data, AttestationType::Packet),
-            &self.signer,
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
         )
         .await?;
+        admission.commit();
 
         Ok(Response::from(attestation))
     }
-}
 
-/// Validate the block height is finalized
-async fn validate_height(
-    adapter: &impl AttestationAdapter,
-    height: u64,
-) -> Result<(), AttestorError> {
-    // Check that the request is for the finalized height
-    let finalized = adapter.get_last_height_at_configured_finality().await?;
+    async fn packet_batch_attestation(
+        &self,
+        request: Request<PacketBatchAttestationRequest>,
+    ) -> Result<Response<PacketBatchAttestationResponse>, Status> {
+        let request_inner = request.into_inner();
+        let height = request_inner.height;
+        let packets = Packets::try_from_abi_encoded(&request_inner.packets)?;
+        let commitment_type =
+            CommitmentType::try_from(request_inner.commitment_type).map_err(AttestorError::from)?;
+        if packets.is_empty() {
+            return Err(AttestorError::EmptyPacketBatch.into());
+        }
+        self.policy.check_batch_size(packets.len())?;
+        let transfers = self.policy.check_packets(&packets, commitment_type)?;
+
+        validate_height(self.adapter.as_ref(), height).await?;
+
+        // Create unsigned attestation over the root of the validated packets
+        let unsigned_packets =
+            create_packets_attestation(self.adapter.as_ref(), packets, height, commitment_type)
+                .await?
+                .packets;
+        let admission = self.policy.admit(&transfers)?;
+        let tree =
+            PacketMerkleTree::new(&unsigned_packets).ok_or(AttestorError::EmptyPacketBatch)?;
+        let attested_data = PacketBatchAttestation {
+            height,
+            root: tree.root(),
+            size: tree.len() as u64,
+        }
+        .abi_encode();
 
-    let lag = i64::try_from(finalized)
-        .unwrap_or(i64::MAX)
-        .saturating_sub(i64::try_from(height).unwrap_or(i64::MAX));
-    metrics::observe_height_lag(lag);
+        // Signed attestation
+        let attestation = sign_and_publish(
+            height,
+            None,
+            AttestationPayload::new(attested_data, AttestationType::PacketBatch),
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
+        )
+        .await?;
+        admission.commit();
 
-    if height > finalized {
-        metrics::inc_height_rejections();
-        error!(
-            requestedHeight = height,
-            finalizedHeight = finalized,
-            "requested height is not finalized"
-        );
-        return Err(AttestorError::BlockNotFinalized);
+        Ok(Response::new(PacketBatchAttestationResponse {
+            attestation: Some(attestation.into()),
+            proofs: inclusion_proofs(&tree, &unsigned_packets),
+        }))
     }
 
-    debug!(finalizedHeight = finalized, "height validation passed");
-    Ok(())
-}
+    async fn packet_attestation_by_sequence(
+        &self,
+        request: Request<PacketAttestationBySequenceRequest>,
+    ) -> Result<Response<PacketAttestationBySequenceResponse>, Status> {
+        let request_inner = request.into_inner();
+        let height = request_inner.height;
+        let commitment_type =
+            CommitmentType::try_from(request_inner.commitment_type).map_err(AttestorError::from)?;
+        self.policy.check_client(&request_inner.client_id)?;
+        self.policy
+            .check_batch_size(request_inner.sequences.len())?;
 
-async fn create_packets_attestation(
-    adapter: &impl AttestationAdapter,
-    packets: Packets,
-    height: u64,
-    commitment_type: CommitmentType,
-) -> Result<IAttestationMsgs::PacketAttestation, AttestorError> {
-    let futures = packets
-        .into_iter()
-        .map(|packet| create_single_packet_attestation(adapter, height, packet, commitment_type))
-        .collect::<FuturesOrdered<_>>();
-    let validations = futures.collect::<Vec<_>>().await;
+        validate_height(self.adapter.as_ref(), height).await?;
 
-    // We handle packets only if all are valid
-    let packets = validations.into_iter().collect::<Result<Vec<_>, _>>()?;
+        // Create unsigned attestation
+        let futures = request_inner
+            .sequences
+            .iter()
+            .map(|&sequence| {
+                create_sequence_attestation(
+                    self.adapter.as_ref(),
+                    &request_inner.client_id,
+                    height,
+                    sequence,
+                    commitment_type,
+                )
+            })
+            .collect::<FuturesOrdered<_>>();
+        let validations = futures.collect::<Vec<_>>().await;
+
+        // We handle sequences only if all are valid
+        let (packets, located): (Vec<_>, Vec<_>) = validations
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+        // Ports and payloads are only known for packets found on chain
+        if located.iter().any(Option::is_none) {
+            self.policy.check_unknown_payloads(commitment_type)?;
+        }
+        let transfers = self
+            .policy
+            .check_packets(located.iter().flatten(), commitment_type)?;
+        let admission = self.policy.admit(&transfers)?;
+        let attested_data = IAttestationMsgs::PacketAttestation { height, packets }.abi_encode();
 
-    Ok(IAttestationMsgs::PacketAttestation { height, packets })
-}
+        // Signed attestation
+        let attestation = sign_and_publish(
+            height,
+            None,
+            AttestationPayload::new(attested_data, AttestationType::Packet),
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
+        )
+        .await?;
+        admission.commit();
+
+        Ok(Response::new(PacketAttestationBySequenceResponse {
+            attestation: Some(attestation.into()),
+            packets: located
+                .into_iter()
+                .map(|packet| packet.map(|p| p.abi_encode()).unwrap_or_default())
+                .collect(),
+        }))
+    }
 
-/// Create unsigned packet attestation
-#[tracing::instrument(
-    skip(adapter, height, packet, commitment_type),
-    fields(clientId = packet.sourceClient, sequence = packet.sequence)
-)] // NOTE: we span here as packet attestation logs use decoded `Packet` fields
-async fn create_single_packet_attestation(
-    adapter: &impl AttestationAdapter,
-    height: u64,
-    packet: Packet,
-    commitment_type: CommitmentType,
-) -> Result<IAttestationMsgs::PacketCompact, AttestorError> {
-    match commitment_type {
-        CommitmentType::Packet => {
-            handle_packet_commitment(adapter, height, packet, commitment_type).await
+    async fn transaction_packet_attestation(
+        &self,
+        request: Request<TransactionPacketAttestationRequest>,
+    ) -> Result<Response<TransactionPacketAttestationResponse>, Status> {
+        let tx_hash = request.into_inner().tx_hash;
+
+        let (height, tx) = get_finalized_tx_packet_events(self.adapter.as_ref(), tx_hash).await?;
+
+        let (sent, acknowledged): (Vec<_>, Vec<_>) = tx
+            .events
+            .into_iter()
+            .partition(|event| event.kind == PacketEventKind::Send);
+        let sent: Vec<_> = sent.into_iter().map(|event| event.packet).collect();
+        let acknowledged: Vec<_> = acknowledged.into_iter().map(|event| event.packet).collect();
+        self.policy
+            .check_batch_size(sent.len().max(acknowledged.len()))?;
+        let mut transfers = self.policy.check_packets(&sent, CommitmentType::Packet)?;
+        transfers.append(
+            self.policy
+                .check_packets(&acknowledged, CommitmentType::Ack)?,
+        );
+        // The packets were emitted in a finalized transaction; commitments are
+        // validated before each attestation is signed
+        let admission = self.policy.admit(&transfers)?;
+
+        // Both attestations are validated and signed before either is
+        // published, so sinks never see an attestation of a failed request
+        let packet_payload =
+            packets_payload(self.adapter.as_ref(), &sent, height, CommitmentType::Packet).await?;
+        let ack_payload = packets_payload(
+            self.adapter.as_ref(),
+            &acknowledged,
+            height,
+            CommitmentType::Ack,
+        )
+        .await?;
+        let packet_attestation =
+            sign_packets_payload(height, packet_payload, &self.mode, self.signer.as_ref()).await?;
+        let ack_attestation =
+            sign_packets_payload(height, ack_payload, &self.mode, self.signer.as_ref()).await?;
+        admission.commit();
+        for attestation in packet_attestation.iter().chain(&ack_attestation) {
+            self.publisher.publish(attestation);
         }
-        CommitmentType::Ack => {
-            handle_ack_commitment(adapter, height, packet, commitment_type).await
+
+        Ok(Response::new(TransactionPacketAttestationResponse {
+            tx_height: tx.height,
+            sent_packets: sent.iter().map(SolValue::abi_encode).collect(),
+            acknowledged_packets: acknowledged.iter().map(SolValue::abi_encode).collect(),
+            packet_attestation: packet_attestation.map(Into::into),
+            ack_attestation: ack_attestation.map(Into::into),
+        }))
+    }
+
+    async fn unrelayed_packets(
+        &self,
+        request: Request<UnrelayedPacketsRequest>,
+    ) -> Result<Response<UnrelayedPacketsResponse>, Status> {
+        let UnrelayedPacketsRequest {
+            client_id,
+            from_height,
+            to_height,
+        } = request.into_inner();
+
+        if from_height > to_height {
+            error!(
+                fromHeight = from_height,
+                toHeight = to_height,
+                "invalid height range"
+            );
+            return Err(AttestorError::InvalidHeightRange {
+                from_height,
+                to_height,
+            }
+            .into());
         }
-        CommitmentType::Receipt => {
-            handle_receipt_commitment(adapter, height, packet, commitment_type).await
+        if to_height - from_height >= self.limits.max_unrelayed_scan_blocks {
+            error!(
+                fromHeight = from_height,
+                toHeight = to_height,
+                maxBlocks = self.limits.max_unrelayed_scan_blocks,
+                "height range too large"
+            );
+            return Err(AttestorError::HeightRangeTooLarge {
+                from_height,
+                to_height,
+                max_blocks: self.limits.max_unrelayed_scan_blocks,
+            }
+            .into());
         }
-    }
-}
 
-async fn handle_packet_commitment(
-    adapter: &impl AttestationAdapter,
-    height: u64,
-    packet: Packet,
-    commitment_type: CommitmentType,
-) -> Result<IAttestationMsgs::PacketCompact, AttestorError> {
-    let commitment_path = packet.commitment_path();
-    let expected_commitment = packet.commitment();
-    let client_id = packet.sourceClient.clone();
-    let sequence = packet.sequence;
+        // Commitments are checked at the finalized height, which must cover the range
+        let height = self
+            .adapter
+            .get_last_height_at_configured_finality()
+            .await
+            .map_err(AttestorError::from)?;
+        if to_height > height {
+            metrics::inc_height_rejections();
+            error!(
+                toHeight = to_height,
+                finalizedHeight = height,
+                "requested height range is not finalized"
+            );
+            return Err(AttestorError::BlockNotFinalized {
+                height: to_height,
+                finalized_height: height,
+            }
+            .into());
+        }
 
-    debug!("validating packet commitment");
+        let sent = self
+            .adapter
+            .get_send_packets(&client_id, from_height, to_height)
+            .await
+            .map_err(AttestorError::from)?;
 
-    // Get packet commitment from the chain
-    let commitment = adapter
-        .get_commitment(
-            client_id.clone(),
+        // Lookups stop being issued once enough unrelayed packets are found
+        let mut lookups = stream::iter(sent)
+            .map(|packet| find_unrelayed_packet(self.adapter.as_ref(), height, packet))
+            .buffered(self.limits.unrelayed_lookup_concurrency);
+        let mut packets = Vec::new();
+        let mut truncated = false;
+        while let Some(packet) = lookups.next().await {
+            let Some(packet) = packet? else {
+                continue;
+            };
+            if packets.len() == self.limits.max_unrelayed_packets {
+                debug!(
+                    maxPackets = self.limits.max_unrelayed_packets,
+                    "unrelayed packets truncated"
+                );
+                truncated = true;
+                break;
+            }
+            packets.push(packet);
+        }
+
+        Ok(Response::new(UnrelayedPacketsResponse {
             height,
-            sequence,
-            &commitment_path,
+            packets: packets.iter().map(SolValue::abi_encode).collect(),
+            truncated,
+        }))
+    }
+
+    async fn first_commitment_height(
+        &self,
+        request: Request<FirstCommitmentHeightRequest>,
+    ) -> Result<Response<HeightSearchResponse>, Status> {
+        let request_inner = request.into_inner();
+        let commitment_type =
+            CommitmentType::try_from(request_inner.commitment_type).map_err(AttestorError::from)?;
+
+        let height = find_first_commitment_height(
+            self.adapter.as_ref(),
+            &request_inner.client_id,
+            request_inner.sequence,
             commitment_type,
+            request_inner.min_height.unwrap_or(1),
+            request_inner.max_height,
         )
         .await?;
 
-    // Packet commitment is expected to exist
-    let commitment = commitment.ok_or_else(|| {
-        metrics::inc_commitment_failure("not_found");
-        error!("packet commitment not found on chain");
-        AttestorError::CommitmentNotFound {
-            client_id: client_id.clone(),
-            sequence,
+        let response = height_search_response(
+            self.adapter.as_ref(),
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
             height,
+            request_inner.sign,
+        )
+        .await?;
+        Ok(Response::new(response))
+    }
+
+    async fn subscribe_state_attestations(
+        &self,
+        request: Request<SubscribeStateAttestationsRequest>,
+    ) -> Result<Response<Self::SubscribeStateAttestationsStream>, Status> {
+        let request_inner = request.into_inner();
+        let interval = request_inner.interval.max(1);
+
+        let Ok(permit) = Arc::clone(&self.subscriptions).try_acquire_owned() else {
+            error!(
+                maxSubscriptions = self.limits.max_subscriptions,
+                "too many subscriptions"
+            );
+            return Err(AttestorError::TooManySubscriptions {
+                max_subscriptions: self.limits.max_subscriptions,
+            }
+            .into());
+        };
+
+        let finalized = self
+            .adapter
+            .get_last_height_at_configured_finality()
+            .await
+            .map_err(AttestorError::from)?;
+        if let Some(start_height) = request_inner.start_height
+            && finalized.saturating_sub(start_height) > self.limits.max_subscription_backfill_blocks
+        {
+            error!(
+                startHeight = start_height,
+                finalizedHeight = finalized,
+                maxBackfillBlocks = self.limits.max_subscription_backfill_blocks,
+                "subscription start height too far below the finalized head"
+            );
+            return Err(AttestorError::HeightRangeTooLarge {
+                from_height: start_height,
+                to_height: finalized,
+                max_blocks: self.limits.max_subscription_backfill_blocks,
+            }
+            .into());
         }
-    })?;
 
-    if expected_commitment == commitment {
+        let (sender, receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+        let stream = stream_state_attestations(
+            Arc::clone(&self.adapter),
+            self.mode.clone(),
+            Arc::clone(&self.signer),
+            self.publisher.clone(),
+            self.head.subscribe(),
+            request_inner.start_height,
+            interval,
+            finalized,
+            sender,
+        );
+        tokio::spawn(async move {
+            stream.await;
+            // The subscription slot is freed once the stream ends
+            drop(permit);
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn first_height_at_timestamp(
+        &self,
+        request: Request<FirstHeightAtTimestampRequest>,
+    ) -> Result<Response<HeightSearchResponse>, Status> {
+        let request_inner = request.into_inner();
+
+        let height = find_first_height_at_timestamp(
+            self.adapter.as_ref(),
+            request_inner.timestamp,
+            request_inner.min_height.unwrap_or(1),
+        )
+        .await?;
+
+        let response = height_search_response(
+            self.adapter.as_ref(),
+            &self.mode,
+            self.signer.as_ref(),
+            &self.publisher,
+            height,
+            request_inner.sign,
+        )
+        .await?;
+        Ok(Response::new(response))
+    }
+
+    async fn watched_packet_attestation(
+        &self,
+        request: Request<WatchedPacketAttestationRequest>,
+    ) -> Result<Response<WatchedPacketAttestationResponse>, Status> {
+        let request_inner = request.into_inner();
+        let commitment_type =
+            CommitmentType::try_from(request_inner.commitment_type).map_err(AttestorError::from)?;
+        self.policy.check_client(&request_inner.client_id)?;
+
+        let stored = find_watched_attestation(
+            self.store.as_deref(),
+            request_inner.client_id,
+            request_inner.sequence,
+            commitment_type,
+        )?;
+        // The watcher validated the commitment and counted the transfers
+        // before signing, so they are not counted again in a later window
+        let _ = self
+            .policy
+            .check_packets([&stored.packet], commitment_type)?;
+
+        Ok(Response::new(WatchedPacketAttestationResponse {
+            attestation: Some(stored.attestation),
+            packet: stored.packet.abi_encode(),
+        }))
+    }
+
+    async fn get_attestor_info(
+        &self,
+        _request: Request<GetAttestorInfoRequest>,
+    ) -> Result<Response<GetAttestorInfoResponse>, Status> {
+        // The chain doesn't change while running. The key identity is proven
+        // on first use, so it is served while signing is paused from then on
+        let chain_info = self.chain_info().await?;
+        let identity = self
+            .identity
+            .get_or_prove(self.signer.as_ref())
+            .await
+            .map_err(|e| match e {
+                AttestorError::SigningPaused => e,
+                e => AttestorError::SignerError(format!("identity not proven yet: {e}")),
+            })?;
+
+        Ok(Response::new(GetAttestorInfoResponse {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            adapter: self.adapter_name.to_string(),
+            chain_id: chain_info.chain_id.clone(),
+            router: chain_info.router.clone(),
+            finality: chain_info.finality.clone(),
+            signer: self.signer_name.to_string(),
+            signer_address: identity.address.clone(),
+            signature_scheme: api::SignatureScheme::from(self.signer.signature_scheme()).into(),
+            identity_challenge: IDENTITY_CHALLENGE.to_vec(),
+            identity_signature: identity.challenge_signature.clone(),
+        }))
+    }
+}
+
+/// Message signed to prove control of the attestor key. Attestation signing
+/// inputs are 33 or 65-byte tagged digests, so this signature can't be replayed
+/// as one.
+const IDENTITY_CHALLENGE: &[u8] = b"ibc-attestor identity challenge";
+
+/// Identity of the attestor key, proven by a signature over a fixed
+/// challenge
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignerIdentity {
+    /// The recovered address for secp256k1 keys, the base58 public key for
+    /// ed25519 keys and the hex public key for BLS12-381 keys
+    pub address: String,
+    /// Signature over the challenge, verifiable against `address`
+    pub challenge_signature: Vec<u8>,
+}
+
+impl SignerIdentity {
+    /// Sign the identity challenge and derive the identity of the key from
+    /// the signature.
+    ///
+    /// # Errors
+    /// Returns an error if the signer fails or its signature doesn't verify.
+    pub async fn prove(signer: &impl Signer) -> Result<Self, AttestorError> {
+        let signature = signer.sign(IDENTITY_CHALLENGE).await.map_err(|e| {
+            error!(error = %e, "failed to sign identity challenge");
+            AttestorError::signing_failed(&e)
+        })?;
+
+        Ok(Self {
+            address: signature_identity(&signature)?,
+            challenge_signature: signature.to_bytes(),
+        })
+    }
+}
+
+/// Identity of the attestor key, proven on first use and kept for the
+/// lifetime of the process, so the attestor starts while its signer is
+/// unreachable or paused
+#[derive(Debug, Default)]
+pub struct ProvenIdentity(OnceCell<SignerIdentity>);
+
+impl ProvenIdentity {
+    /// The identity of the key, signing the challenge with `signer` if it
+    /// isn't proven yet.
+    ///
+    /// # Errors
+    /// Returns an error if the identity isn't proven yet and `signer` fails
+    /// to prove it.
+    pub async fn get_or_prove(
+        &self,
+        signer: &impl Signer,
+    ) -> Result<&SignerIdentity, AttestorError> {
+        if let Some(identity) = self.0.get() {
+            return Ok(identity);
+        }
+        self.check(signer).await
+    }
+
+    /// Sign the challenge with `signer` again and check that it proves the
+    /// identity proven first, keeping it if none was proven yet.
+    ///
+    /// # Errors
+    /// Returns an error if `signer` fails to prove an identity, or proves
+    /// another key than the one proven first.
+    pub async fn check(&self, signer: &impl Signer) -> Result<&SignerIdentity, AttestorError> {
+        let proved = SignerIdentity::prove(signer).await?;
+        let identity = self
+            .0
+            .get_or_init(|| async {
+                info!(signer_address = %proved.address, "proved attestor key identity");
+                proved.clone()
+            })
+            .await;
+
+        if identity.address != proved.address {
+            error!(
+                expected = %identity.address,
+                actual = %proved.address,
+                "signer proved another key than the attestor identity"
+            );
+            return Err(AttestorError::SignerFault(format!(
+                "signer key changed from {} to {}",
+                identity.address, proved.address
+            )));
+        }
+        Ok(identity)
+    }
+}
+
+/// Derive the identity of the key that produced `signature` over
+/// [`IDENTITY_CHALLENGE`]
+fn signature_identity(signature: &AttestorSignature) -> Result<String, AttestorError> {
+    match signature {
+        AttestorSignature::Secp256k1(signature) => {
+            recover_address(IDENTITY_CHALLENGE, &signature.as_bytes())
+                .map(|address| address.to_string())
+                .map_err(|e| {
+                    error!(error = %e, "failed to recover signer address");
+                    AttestorError::SignerFault(e.to_string())
+                })
+        }
+        AttestorSignature::Ed25519 {
+            signature,
+            public_key,
+        } => public_key
+            .verify_strict(IDENTITY_CHALLENGE, signature)
+            .map(|()| Pubkey::new_from_array(public_key.to_bytes()).to_string())
+            .map_err(|e| {
+                error!(error = %e, "failed to verify identity challenge signature");
+                AttestorError::SignerFault(e.to_string())
+            }),
+        #[cfg(feature = "bls")]
+        AttestorSignature::Bls12381 {
+            signature,
+            public_key,
+        } => match bls::verify(IDENTITY_CHALLENGE, signature, public_key) {
+            Ok(true) => Ok(format!("0x{}", hex::encode(public_key))),
+            Ok(false) => {
+                error!("failed to verify identity challenge signature");
+                Err(AttestorError::SignerFault(
+                    "invalid identity challenge signature".to_string(),
+                ))
+            }
+            Err(e) => {
+                error!(error = %e, "failed to verify identity challenge signature");
+                Err(AttestorError::SignerFault(e.to_string()))
+            }
+        },
+    }
+}
+
+/// Look up a packet attestation pre-computed by the watcher
+fn find_watched_attestation(
+    store: Option<&AttestationStore>,
+    client_id: String,
+    sequence: u64,
+    commitment_type: CommitmentType,
+) -> Result<StoredAttestation, AttestorError> {
+    let store = store.ok_or(AttestorError::WatcherDisabled)?;
+
+    let key = StoreKey {
+        client_id,
+        sequence,
+        commitment_type,
+    };
+    store.get(&key).ok_or_else(|| {
+        debug!(
+            clientId = key.client_id,
+            sequence, "no pre-computed attestation stored"
+        );
+        AttestorError::AttestationNotFound {
+            client_id: key.client_id,
+            sequence,
+        }
+    })
+}
+
+/// Sign an attestation and queue it for delivery to the configured sinks
+async fn sign_and_publish(
+    height: u64,
+    timestamp: Option<u64>,
+    payload: AttestationPayload,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let attestation = sign_attestation(height, timestamp, payload, mode, signer).await?;
+    publisher.publish(&attestation);
+
+    Ok(attestation)
+}
+
+/// Sign a state attestation for the block at `height`
+async fn sign_state_attestation(
+    height: u64,
+    timestamp: u64,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let unsigned_attestation = IAttestationMsgs::StateAttestation { height, timestamp };
+    let attested_data = unsigned_attestation.abi_encode();
+
+    sign_and_publish(
+        height,
+        Some(timestamp),
+        AttestationPayload::new(attested_data, AttestationType::State),
+        mode,
+        signer,
+        publisher,
+    )
+    .await
+}
+
+/// Sign the consensus-relevant fields of a block header
+async fn sign_consensus_state_attestation(
+    header: &ConsensusHeader,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let unsigned_attestation = ConsensusStateAttestation {
+        height: header.height,
+        timestamp: header.timestamp,
+        blockHash: header.block_hash.into(),
+        root: header.root.into(),
+        validatorsHash: header.validators_hash.unwrap_or_default().into(),
+    };
+    let attested_data = unsigned_attestation.abi_encode();
+
+    sign_and_publish(
+        header.height,
+        Some(header.timestamp),
+        AttestationPayload::new(attested_data, AttestationType::ConsensusState),
+        mode,
+        signer,
+        publisher,
+    )
+    .await
+}
+
+/// Parse the contract state read of a request.
+fn contract_read(request: ContractStateAttestationRequest) -> Result<ContractRead, AttestorError> {
+    let address = Address::try_from(request.address.as_slice()).map_err(|_| {
+        AttestorError::InvalidContractRead(format!(
+            "address must be 20 bytes, got {}",
+            request.address.len()
+        ))
+    })?;
+
+    match request.read {
+        Some(contract_state_attestation_request::Read::Calldata(calldata)) => {
+            Ok(ContractRead::Call {
+                address,
+                calldata: calldata.into(),
+            })
+        }
+        Some(contract_state_attestation_request::Read::StorageSlot(slot)) => {
+            let slot = B256::try_from(slot.as_slice()).map_err(|_| {
+                AttestorError::InvalidContractRead(format!(
+                    "storage slot must be 32 bytes, got {}",
+                    slot.len()
+                ))
+            })?;
+            Ok(ContractRead::Storage { address, slot })
+        }
+        None => Err(AttestorError::InvalidContractRead(
+            "calldata or storage slot is required".to_string(),
+        )),
+    }
+}
+
+/// Sign the result of a contract state read at `height`
+async fn sign_contract_state_attestation(
+    height: u64,
+    read: &ContractRead,
+    result: Vec<u8>,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let (storage, input) = match read {
+        ContractRead::Call { calldata, .. } => (false, calldata.clone()),
+        ContractRead::Storage { slot, .. } => (true, slot.to_vec().into()),
+    };
+    let unsigned_attestation = ContractStateAttestation {
+        height,
+        target: read.address(),
+        storage,
+        input,
+        result: result.into(),
+    };
+    let attested_data = unsigned_attestation.abi_encode();
+
+    sign_and_publish(
+        height,
+        None,
+        AttestationPayload::new(attested_data, AttestationType::ContractState),
+        mode,
+        signer,
+        publisher,
+    )
+    .await
+}
+
+/// Sign the value stored under `key` of the module `store` at `height`
+async fn sign_store_key_attestation(
+    height: u64,
+    store: String,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let unsigned_attestation = StoreKeyAttestation {
+        height,
+        store,
+        key: key.into(),
+        value: value.into(),
+    };
+    let attested_data = unsigned_attestation.abi_encode();
+
+    sign_and_publish(
+        height,
+        None,
+        AttestationPayload::new(attested_data, AttestationType::StoreKey),
+        mode,
+        signer,
+        publisher,
+    )
+    .await
+}
+
+/// Whether the topics of `log` start with `topics`
+fn has_leading_topics(log: &EventLog, topics: &[Vec<u8>]) -> bool {
+    log.topics.len() >= topics.len()
+        && log
+            .topics
+            .iter()
+            .zip(topics)
+            .all(|(emitted, expected)| emitted.as_slice() == expected.as_slice())
+}
+
+/// Sign a claim that `log` was emitted in its block
+async fn sign_event_log_attestation(
+    log: &EventLog,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let unsigned_attestation = EventLogAttestation {
+        height: log.height,
+        blockHash: log.block_hash.into(),
+        txIndex: log.tx_index,
+        logIndex: log.log_index,
+        emitter: log.address,
+        topics: log.topics.clone(),
+        data: log.data.clone(),
+    };
+    let attested_data = unsigned_attestation.abi_encode();
+
+    sign_and_publish(
+        log.height,
+        None,
+        AttestationPayload::new(attested_data, AttestationType::EventLog),
+        mode,
+        signer,
+        publisher,
+    )
+    .await
+}
+
+/// Sign a claim that `height` is the latest finalized height of `chain_id` at
+/// wall-clock `timestamp`
+async fn sign_latest_height_attestation(
+    height: u64,
+    chain_id: &str,
+    timestamp: u64,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+) -> Result<SignedAttestation, AttestorError> {
+    let unsigned_attestation = LatestHeightAttestation {
+        height,
+        chainId: chain_id.to_string(),
+        timestamp,
+    };
+    let attested_data = unsigned_attestation.abi_encode();
+
+    sign_and_publish(
+        height,
+        Some(timestamp),
+        AttestationPayload::new(attested_data, AttestationType::LatestHeight),
+        mode,
+        signer,
+        publisher,
+    )
+    .await
+}
+
+/// Current UNIX time in seconds
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Push a signed state attestation for every `interval`-th finalized height,
+/// starting at `start_height` or the current finalized head.
+///
+/// Heights below the head are backfilled first. Backfilled heights below
+/// `live_from` are not published to the sinks, which received them when they
+/// were new. Sending blocks while the subscriber's buffer is full, so a slow
+/// subscriber delays attestation instead of growing memory. The stream ends
+/// after the first error or once the subscriber disconnects.
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip(adapter, mode, signer, publisher, head, sender))]
+async fn stream_state_attestations<A, S>(
+    adapter: Arc<A>,
+    mode: SigningMode,
+    signer: Arc<S>,
+    publisher: Publisher,
+    mut head: watch::Receiver<Option<u64>>,
+    start_height: Option<u64>,
+    interval: u64,
+    live_from: u64,
+    sender: mpsc::Sender<Result<StateAttestationResponse, Status>>,
+) where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    let mut next_height = start_height;
+    let no_sinks = Publisher::default();
+
+    loop {
+        // Copy out the height so the watch lock isn't held across awaits
+        let finalized = *head.borrow_and_update();
+        if let Some(finalized) = finalized {
+            let next = next_height.get_or_insert(finalized);
+
+            while *next <= finalized {
+                let publisher = if *next < live_from {
+                    &no_sinks
+                } else {
+                    &publisher
+                };
+                let result = match adapter.get_block_timestamp(*next).await {
+                    Ok(timestamp) => {
+                        sign_state_attestation(*next, timestamp, &mode, signer.as_ref(), publisher)
+                            .await
+                            .map(|attestation| StateAttestationResponse {
+                                attestation: Some(attestation.into()),
+                            })
+                    }
+                    Err(err) => Err(err.into()),
+                };
+                let failed = result.is_err();
+
+                if sender.send(result.map_err(Status::from)).await.is_err() {
+                    debug!("subscriber disconnected");
+                    return;
+                }
+                if failed {
+                    return;
+                }
+
+                debug!(height = *next, "streamed state attestation");
+                *next = next.saturating_add(interval);
+            }
+        }
+
+        tokio::select! {
+            () = sender.closed() => {
+                debug!("subscriber disconnected");
+                return;
+            }
+            changed = head.changed() => {
+                if changed.is_err() {
+                    error!("head tracker stopped, closing subscription");
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Validate the block height is finalized
+async fn validate_height(
+    adapter: &impl AttestationAdapter,
+    height: u64,
+) -> Result<(), AttestorError> {
+    // Check that the request is for the finalized height
+    let finalized = adapter.get_last_height_at_configured_finality().await?;
+
+    let lag = i64::try_from(finalized)
+        .unwrap_or(i64::MAX)
+        .saturating_sub(i64::try_from(height).unwrap_or(i64::MAX));
+    metrics::observe_height_lag(lag);
+
+    if height > finalized {
+        metrics::inc_height_rejections();
+        error!(
+            requestedHeight = height,
+            finalizedHeight = finalized,
+            "requested height is not finalized"
+        );
+        return Err(AttestorError::BlockNotFinalized {
+            height,
+            finalized_height: finalized,
+        });
+    }
+
+    debug!(finalizedHeight = finalized, "height validation passed");
+    Ok(())
+}
+
+async fn create_packets_attestation(
+    adapter: &impl AttestationAdapter,
+    packets: Packets,
+    height: u64,
+    commitment_type: CommitmentType,
+) -> Result<IAttestationMsgs::PacketAttestation, AttestorError> {
+    let futures = packets
+        .into_iter()
+        .map(|packet| create_single_packet_attestation(adapter, height, packet, commitment_type))
+        .collect::<FuturesOrdered<_>>();
+    let validations = futures.collect::<Vec<_>>().await;
+
+    // We handle packets only if all are valid
+    let packets = validations.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+    Ok(IAttestationMsgs::PacketAttestation { height, packets })
+}
+
+/// Create unsigned packet attestation
+#[tracing::instrument(
+    skip(adapter, height, packet, commitment_type),
+    fields(clientId = packet.sourceClient, sequence = packet.sequence)
+)] // NOTE: we span here as packet attestation logs use decoded `Packet` fields
+async fn create_single_packet_attestation(
+    adapter: &impl AttestationAdapter,
+    height: u64,
+    packet: Packet,
+    commitment_type: CommitmentType,
+) -> Result<IAttestationMsgs::PacketCompact, AttestorError> {
+    match commitment_type {
+        CommitmentType::Packet => {
+            handle_packet_commitment(adapter, height, packet, commitment_type).await
+        }
+        CommitmentType::Ack => {
+            handle_ack_commitment(adapter, height, packet, commitment_type).await
+        }
+        CommitmentType::Receipt => {
+            handle_receipt_commitment(adapter, height, packet, commitment_type).await
+        }
+    }
+}
+
+async fn handle_packet_commitment(
+    adapter: &impl AttestationAdapter,
+    height: u64,
+    packet: Packet,
+    commitment_type: CommitmentType,
+) -> Result<IAttestationMsgs::PacketCompact, AttestorError> {
+    let commitment_path = packet.commitment_path();
+    let expected_commitment = packet.commitment();
+    let client_id = packet.sourceClient.clone();
+    let sequence = packet.sequence;
+
+    debug!("validating packet commitment");
+
+    // Get packet commitment from the chain
+    let commitment = adapter
+        .get_commitment(
+            client_id.clone(),
+            height,
+            sequence,
+            &commitment_path,
+            commitment_type,
+        )
+        .await?;
+
+    // Packet commitment is expected to exist
+    let commitment = commitment.ok_or_else(|| {
+        metrics::inc_commitment_failure("not_found");
+        error!("packet commitment not found on chain");
+        AttestorError::CommitmentNotFound {
+            client_id: client_id.clone(),
+            sequence,
+            height,
+        }
+    })?;
+
+    if expected_commitment == commitment {
         debug!("packet commitment validated successfully");
         Ok(IAttestationMsgs::PacketCompact {
             path: keccak256(commitment_path),
@@ -253,14 +1410,12 @@ async fn handle_packet_commitment(
             actual = %hex::encode(commitment),
             "packet commitment mismatch"
         );
-        Err(AttestorError::InvalidCommitment {
-            reason: format!(
-                "Packet commitment mismatch for client_id={} seq={}: expected 0x{}, got 0x{}",
-                client_id,
-                sequence,
-                hex::encode(&expected_commitment),
-                hex::encode(commitment)
-            ),
+        Err(AttestorError::CommitmentMismatch {
+            client_id,
+            sequence,
+            height,
+            expected: hex::encode(&expected_commitment),
+            actual: hex::encode(commitment),
         })
     }
 }
@@ -344,54 +1499,527 @@ async fn handle_receipt_commitment(
                 actual = %hex::encode(commit),
                 "receipt commitment should be zero but found non-zero value"
             );
-            Err(AttestorError::InvalidCommitment {
-                reason: format!(
-                    "Receipt commitment should be zero for client_id={} seq={}: got 0x{}",
-                    client_id,
-                    sequence,
-                    hex::encode(commit)
-                ),
+            Err(AttestorError::CommitmentMismatch {
+                client_id,
+                sequence,
+                height,
+                expected: hex::encode([0; 32]),
+                actual: hex::encode(commit),
             })
         },
     )
 }
 
-impl From<SignedAttestation> for Response<StateAttestationResponse> {
-    fn from(signed: SignedAttestation) -> Self {
-        let attestation = Attestation {
-            height: signed.height,
-            timestamp: signed.timestamp,
-            attested_data: signed.attested_data,
-            signature: signed.signature,
-        };
+/// Create unsigned packet attestation for a commitment identified by client ID
+/// and sequence, returning the original packet if it could be located.
+#[tracing::instrument(
+    skip(adapter, client_id, height, commitment_type),
+    fields(clientId = client_id, sequence)
+)]
+async fn create_sequence_attestation(
+    adapter: &impl AttestationAdapter,
+    client_id: &str,
+    height: u64,
+    sequence: u64,
+    commitment_type: CommitmentType,
+) -> Result<(IAttestationMsgs::PacketCompact, Option<Packet>), AttestorError> {
+    let path_packet = path_only_packet(client_id, sequence);
 
-        Self::new(StateAttestationResponse {
-            attestation: Some(attestation),
-        })
+    match commitment_type {
+        CommitmentType::Packet => {
+            match locate_send_packet(adapter, client_id, sequence, height).await? {
+                Some(packet) => {
+                    let compact =
+                        handle_packet_commitment(adapter, height, packet.clone(), commitment_type)
+                            .await?;
+                    Ok((compact, Some(packet)))
+                }
+                None => {
+                    let compact = handle_unlocated_packet_commitment(
+                        adapter,
+                        height,
+                        path_packet,
+                        commitment_type,
+                    )
+                    .await?;
+                    Ok((compact, None))
+                }
+            }
+        }
+        CommitmentType::Ack => handle_ack_commitment(adapter, height, path_packet, commitment_type)
+            .await
+            .map(|compact| (compact, None)),
+        CommitmentType::Receipt => {
+            handle_receipt_commitment(adapter, height, path_packet, commitment_type)
+                .await
+                .map(|compact| (compact, None))
+        }
     }
 }
 
-impl From<SignedAttestation> for Response<PacketAttestationResponse> {
-    fn from(signed: SignedAttestation) -> Self {
-        let attestation = Attestation {
-            height: signed.height,
-            timestamp: signed.timestamp,
-            attested_data: signed.attested_data,
-            signature: signed.signature,
-        };
+/// Build a packet carrying only the fields commitment paths are derived from.
+///
+/// IBC v2 commitment paths depend solely on the client ID and sequence, so the
+/// same client ID is used on both ends and the remaining fields are empty.
+fn path_only_packet(client_id: &str, sequence: u64) -> Packet {
+    Packet {
+        sequence,
+        sourceClient: client_id.to_string(),
+        destClient: client_id.to_string(),
+        timeoutTimestamp: 0,
+        payloads: vec![],
+    }
+}
 
+/// Look up the original packet from the chain's `SendPacket` events.
+///
+/// Adapters that cannot search events report the packet as not located.
+async fn locate_send_packet(
+    adapter: &impl AttestationAdapter,
+    client_id: &str,
+    sequence: u64,
+    height: u64,
+) -> Result<Option<Packet>, AttestorError> {
+    match adapter.find_send_packet(client_id, sequence, height).await {
+        Ok(packet) => Ok(packet),
+        Err(AttestationAdapterError::Unsupported(operation)) => {
+            debug!(operation, "adapter does not support packet lookup");
+            Ok(None)
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Validate that a packet commitment exists when the original packet is unknown
+async fn handle_unlocated_packet_commitment(
+    adapter: &impl AttestationAdapter,
+    height: u64,
+    packet: Packet,
+    commitment_type: CommitmentType,
+) -> Result<IAttestationMsgs::PacketCompact, AttestorError> {
+    let commitment_path = packet.commitment_path();
+    let client_id = packet.sourceClient;
+    let sequence = packet.sequence;
+
+    debug!("validating packet commitment existence (packet not located)");
+
+    // Get packet commitment from the chain
+    let commitment = adapter
+        .get_commitment(
+            client_id.clone(),
+            height,
+            sequence,
+            &commitment_path,
+            commitment_type,
+        )
+        .await?;
+
+    // Packet commitment is expected to exist
+    let commitment = commitment.ok_or_else(|| {
+        metrics::inc_commitment_failure("not_found");
+        error!("packet commitment not found on chain");
+        AttestorError::CommitmentNotFound {
+            client_id,
+            sequence,
+            height,
+        }
+    })?;
+
+    Ok(IAttestationMsgs::PacketCompact {
+        path: keccak256(commitment_path),
+        commitment: commitment.into(),
+    })
+}
+
+/// Fetch the packet events of a transaction together with the finalized height
+/// they will be attested at, which must include the transaction's block.
+#[tracing::instrument(skip(adapter), fields(txHash = %tx_hash))]
+async fn get_finalized_tx_packet_events(
+    adapter: &impl AttestationAdapter,
+    tx_hash: String,
+) -> Result<(u64, TxPacketEvents), AttestorError> {
+    let Some(tx) = adapter.get_tx_packet_events(&tx_hash).await? else {
+        error!("transaction not found on chain");
+        return Err(AttestorError::TransactionNotFound { tx_hash });
+    };
+
+    let finalized = adapter.get_last_height_at_configured_finality().await?;
+    if tx.height > finalized {
+        metrics::inc_height_rejections();
+        error!(
+            txHeight = tx.height,
+            finalizedHeight = finalized,
+            "transaction block is not finalized"
+        );
+        return Err(AttestorError::BlockNotFinalized {
+            height: tx.height,
+            finalized_height: finalized,
+        });
+    }
+
+    debug!(
+        txHeight = tx.height,
+        finalizedHeight = finalized,
+        numEvents = tx.events.len(),
+        "transaction packet events retrieved"
+    );
+    Ok((finalized, tx))
+}
+
+/// Build the inclusion proof of each packet of the batch `tree` was built over
+fn inclusion_proofs(
+    tree: &PacketMerkleTree,
+    packets: &[IAttestationMsgs::PacketCompact],
+) -> Vec<PacketInclusionProof> {
+    packets
+        .iter()
+        .enumerate()
+        .map(|(index, packet)| PacketInclusionProof {
+            path: packet.path.to_vec(),
+            commitment: packet.commitment.to_vec(),
+            siblings: tree
+                .proof(index)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|sibling| sibling.to_vec())
+                .collect(),
+        })
+        .collect()
+}
+
+/// Sign a packet attestation of the given commitments, if there are any
+pub async fn attest_packets(
+    adapter: &impl AttestationAdapter,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+    packets: &[Packet],
+    height: u64,
+    commitment_type: CommitmentType,
+) -> Result<Option<Attestation>, AttestorError> {
+    let Some(payload) = packets_payload(adapter, packets, height, commitment_type).await? else {
+        return Ok(None);
+    };
+
+    let attestation = sign_and_publish(height, None, payload, mode, signer, publisher).await?;
+
+    Ok(Some(attestation.into()))
+}
+
+/// Validate the commitments of `packets` at `height` and build their unsigned
+/// attestation, or `None` if there are no packets
+async fn packets_payload(
+    adapter: &impl AttestationAdapter,
+    packets: &[Packet],
+    height: u64,
+    commitment_type: CommitmentType,
+) -> Result<Option<AttestationPayload>, AttestorError> {
+    if packets.is_empty() {
+        return Ok(None);
+    }
+
+    let unsigned_attestation =
+        create_packets_attestation(adapter, Packets(packets.to_vec()), height, commitment_type)
+            .await?;
+
+    Ok(Some(AttestationPayload::new(
+        unsigned_attestation.abi_encode(),
+        AttestationType::Packet,
+    )))
+}
+
+/// Sign a packet attestation built by [`packets_payload`] without publishing
+/// it
+async fn sign_packets_payload(
+    height: u64,
+    payload: Option<AttestationPayload>,
+    mode: &SigningMode,
+    signer: &impl Signer,
+) -> Result<Option<SignedAttestation>, AttestorError> {
+    match payload {
+        Some(payload) => sign_attestation(height, None, payload, mode, signer)
+            .await
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Return the packet if its commitment still exists at `height`
+#[tracing::instrument(
+    skip(adapter, height, packet),
+    fields(clientId = packet.sourceClient, sequence = packet.sequence)
+)]
+async fn find_unrelayed_packet(
+    adapter: &impl AttestationAdapter,
+    height: u64,
+    packet: Packet,
+) -> Result<Option<Packet>, AttestorError> {
+    let commitment = adapter
+        .get_commitment(
+            packet.sourceClient.clone(),
+            height,
+            packet.sequence,
+            &packet.commitment_path(),
+            CommitmentType::Packet,
+        )
+        .await?;
+
+    match commitment {
+        None => {
+            debug!("packet commitment cleared (relayed or timed out)");
+            Ok(None)
+        }
+        Some(commitment) if packet.commitment() == commitment => {
+            debug!("packet commitment pending");
+            Ok(Some(packet))
+        }
+        Some(commitment) => {
+            metrics::inc_commitment_failure("mismatch");
+            error!(
+                expected = %hex::encode(packet.commitment()),
+                actual = %hex::encode(commitment),
+                "packet commitment does not match sent packet, skipping"
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Lowest height a search may start from, bounded by the finalized height
+async fn finalized_search_bounds(
+    adapter: &impl AttestationAdapter,
+    min_height: u64,
+) -> Result<u64, AttestorError> {
+    let finalized = adapter.get_last_height_at_configured_finality().await?;
+    if min_height > finalized {
+        metrics::inc_height_rejections();
+        error!(
+            minHeight = min_height,
+            finalizedHeight = finalized,
+            "search lower bound is not finalized"
+        );
+        return Err(AttestorError::BlockNotFinalized {
+            height: min_height,
+            finalized_height: finalized,
+        });
+    }
+    Ok(finalized)
+}
+
+/// Binary search the earliest finalized height at which a commitment exists.
+///
+/// Commitments are searched by client ID and sequence; the commitment must
+/// exist at `max_height`, the finalized height by default, for the search to
+/// be monotonic. Bounding the search below the height at which the commitment
+/// was cleared finds commitments that were since relayed.
+#[tracing::instrument(skip(adapter), fields(clientId = client_id, sequence, commitmentType = ?commitment_type))]
+async fn find_first_commitment_height(
+    adapter: &impl AttestationAdapter,
+    client_id: &str,
+    sequence: u64,
+    commitment_type: CommitmentType,
+    min_height: u64,
+    max_height: Option<u64>,
+) -> Result<u64, AttestorError> {
+    if !adapter.supports_historical_state() {
+        error!("adapter cannot read historical commitments");
+        return Err(AttestationAdapterError::Unsupported(
+            "historical commitment search".to_string(),
+        )
+        .into());
+    }
+
+    let packet = path_only_packet(client_id, sequence);
+    let commitment_path = match commitment_type {
+        CommitmentType::Packet => packet.commitment_path(),
+        CommitmentType::Ack => packet.ack_commitment_path(),
+        CommitmentType::Receipt => {
+            error!("receipt commitments cannot be searched");
+            return Err(AttestationAdapterError::Unsupported(
+                "receipt commitment search".to_string(),
+            )
+            .into());
+        }
+    };
+
+    let finalized = finalized_search_bounds(adapter, min_height).await?;
+    let max_height = match max_height {
+        Some(max_height) if max_height > finalized => {
+            metrics::inc_height_rejections();
+            error!(
+                maxHeight = max_height,
+                finalizedHeight = finalized,
+                "search upper bound is not finalized"
+            );
+            return Err(AttestorError::BlockNotFinalized {
+                height: max_height,
+                finalized_height: finalized,
+            });
+        }
+        Some(max_height) if max_height < min_height => {
+            error!(
+                minHeight = min_height,
+                maxHeight = max_height,
+                "invalid height range"
+            );
+            return Err(AttestorError::InvalidHeightRange {
+                from_height: min_height,
+                to_height: max_height,
+            });
+        }
+        Some(max_height) => max_height,
+        None => finalized,
+    };
+
+    debug!(
+        minHeight = min_height,
+        maxHeight = max_height,
+        "searching first commitment height"
+    );
+    let height = find_first_height(min_height, max_height, |height| {
+        let commitment_path = &commitment_path;
+        async move {
+            let commitment = adapter
+                .get_commitment(
+                    client_id.to_string(),
+                    height,
+                    sequence,
+                    commitment_path,
+                    commitment_type,
+                )
+                .await?;
+            Ok::<_, AttestorError>(commitment.is_some())
+        }
+    })
+    .await?;
+
+    height.ok_or_else(|| {
+        error!(
+            maxHeight = max_height,
+            "commitment not found at search upper bound"
+        );
+        AttestorError::CommitmentNotFound {
+            client_id: client_id.to_string(),
+            sequence,
+            height: max_height,
+        }
+    })
+}
+
+/// Binary search the earliest finalized height whose block timestamp is at
+/// least `timestamp`.
+#[tracing::instrument(skip(adapter))]
+async fn find_first_height_at_timestamp(
+    adapter: &impl AttestationAdapter,
+    timestamp: u64,
+    min_height: u64,
+) -> Result<u64, AttestorError> {
+    let finalized = finalized_search_bounds(adapter, min_height).await?;
+
+    debug!(
+        minHeight = min_height,
+        finalizedHeight = finalized,
+        "searching first height at timestamp"
+    );
+    let height = find_first_height(min_height, finalized, |height| async move {
+        let block_timestamp = adapter.get_block_timestamp(height).await?;
+        Ok::<_, AttestorError>(block_timestamp >= timestamp)
+    })
+    .await?;
+
+    height.ok_or_else(|| {
+        error!(
+            finalizedHeight = finalized,
+            "no finalized block has reached timestamp"
+        );
+        AttestorError::TimestampNotReached {
+            timestamp,
+            finalized_height: finalized,
+        }
+    })
+}
+
+/// Build the response for a height search, signing a state attestation if
+/// requested.
+async fn height_search_response(
+    adapter: &impl AttestationAdapter,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    publisher: &Publisher,
+    height: u64,
+    sign: bool,
+) -> Result<HeightSearchResponse, AttestorError> {
+    let timestamp = adapter.get_block_timestamp(height).await?;
+
+    let attestation = if sign {
+        Some(
+            sign_state_attestation(height, timestamp, mode, signer, publisher)
+                .await?
+                .into(),
+        )
+    } else {
+        None
+    };
+
+    Ok(HeightSearchResponse {
+        height,
+        timestamp,
+        attestation,
+    })
+}
+
+impl From<SignedAttestation> for Attestation {
+    fn from(signed: SignedAttestation) -> Self {
+        Self {
+            height: signed.height,
+            timestamp: signed.timestamp,
+            attested_data: signed.attested_data,
+            signature: signed.signature,
+            signature_scheme: api::SignatureScheme::from(signed.signature_scheme).into(),
+        }
+    }
+}
+
+impl From<SignatureScheme> for api::SignatureScheme {
+    fn from(scheme: SignatureScheme) -> Self {
+        match scheme {
+            SignatureScheme::Secp256k1 => Self::Secp256k1,
+            SignatureScheme::Ed25519 => Self::Ed25519,
+            #[cfg(feature = "bls")]
+            SignatureScheme::Bls12381 => Self::Bls12381,
+        }
+    }
+}
+
+impl From<SignedAttestation> for Response<StateAttestationResponse> {
+    fn from(signed: SignedAttestation) -> Self {
+        Self::new(StateAttestationResponse {
+            attestation: Some(signed.into()),
+        })
+    }
+}
+
+impl From<SignedAttestation> for Response<PacketAttestationResponse> {
+    fn from(signed: SignedAttestation) -> Self {
         Self::new(PacketAttestationResponse {
-            attestation: Some(attestation),
+            attestation: Some(signed.into()),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::{collections::HashMap, time::Duration};
 
     use super::*;
-    use crate::adapter::AttestationAdapterError;
+    use crate::adapter::{AttestationAdapterError, PacketEvent};
+    use crate::head::HeadLimits;
+    use crate::pause::PauseSwitch;
+    use crate::policy::{PolicyConfig, PortPair};
+    use crate::signer::{local::LocalSigner, pausable::PausableSigner};
+    use crate::sink::{SinkEnum, broadcast::BroadcastSink};
+    use alloy_signer_local::PrivateKeySigner;
+    use ethereum_keys::attestation::SigningScheme;
     use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Payload;
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -406,6 +2034,12 @@ mod tests {
     struct TestAdapter {
         finalized_height: u64,
         commitments: HashMap<CommitmentKey, Option<[u8; 32]>>,
+        sent_packets: HashMap<(String, u64), Packet>,
+        transactions: HashMap<String, TxPacketEvents>,
+        event_logs: HashMap<String, EventLog>,
+        event_log_allowlist: Vec<Address>,
+        block_hashes: HashMap<u64, [u8; 32]>,
+        block_time: u64,
     }
 
     impl TestAdapter {
@@ -413,9 +2047,20 @@ mod tests {
             Self {
                 finalized_height,
                 commitments: HashMap::new(),
+                sent_packets: HashMap::new(),
+                transactions: HashMap::new(),
+                event_logs: HashMap::new(),
+                event_log_allowlist: Vec::new(),
+                block_hashes: HashMap::new(),
+                block_time: 0,
             }
         }
 
+        fn insert_sent_packet(&mut self, packet: Packet) {
+            self.sent_packets
+                .insert((packet.sourceClient.clone(), packet.sequence), packet);
+        }
+
         fn insert_commitment(
             &mut self,
             client_id: String,
@@ -446,8 +2091,26 @@ mod tests {
             Ok(self.finalized_height)
         }
 
-        async fn get_block_timestamp(&self, _height: u64) -> Result<u64, AttestationAdapterError> {
-            Ok(1_700_000_000)
+        async fn get_block_timestamp(&self, height: u64) -> Result<u64, AttestationAdapterError> {
+            Ok(1_700_000_000 + height * self.block_time)
+        }
+
+        async fn get_block_header(
+            &self,
+            height: u64,
+        ) -> Result<ConsensusHeader, AttestationAdapterError> {
+            let block_hash = self
+                .block_hashes
+                .get(&height)
+                .copied()
+                .ok_or(AttestationAdapterError::BlockNotFinalized)?;
+            Ok(ConsensusHeader {
+                height,
+                timestamp: 1_700_000_000 + height * self.block_time,
+                block_hash,
+                root: [0; 32],
+                validators_hash: None,
+            })
         }
 
         async fn get_commitment(
@@ -468,6 +2131,100 @@ mod tests {
 
             Ok(self.commitments.get(&key).copied().flatten())
         }
+
+        async fn find_send_packet(
+            &self,
+            client_id: &str,
+            sequence: u64,
+            _height: u64,
+        ) -> Result<Option<Packet>, AttestationAdapterError> {
+            Ok(self
+                .sent_packets
+                .get(&(client_id.to_string(), sequence))
+                .cloned())
+        }
+
+        async fn get_tx_packet_events(
+            &self,
+            tx_hash: &str,
+        ) -> Result<Option<TxPacketEvents>, AttestationAdapterError> {
+            Ok(self.transactions.get(tx_hash).cloned())
+        }
+
+        async fn get_event_log(
+            &self,
+            tx_hash: &str,
+            log_index: u64,
+        ) -> Result<Option<EventLog>, AttestationAdapterError> {
+            let Some(log) = self
+                .event_logs
+                .get(tx_hash)
+                .filter(|log| log.log_index == log_index)
+            else {
+                return Ok(None);
+            };
+            if !self.event_log_allowlist.contains(&log.address) {
+                return Err(AttestationAdapterError::NotAllowed(format!(
+                    "event log of {}",
+                    log.address
+                )));
+            }
+            Ok(Some(log.clone()))
+        }
+
+        async fn get_send_packets(
+            &self,
+            client_id: &str,
+            _from_height: u64,
+            _to_height: u64,
+        ) -> Result<Vec<Packet>, AttestationAdapterError> {
+            let mut packets = self
+                .sent_packets
+                .values()
+                .filter(|packet| packet.sourceClient == client_id)
+                .cloned()
+                .collect::<Vec<_>>();
+            packets.sort_by_key(|packet| packet.sequence);
+            Ok(packets)
+        }
+    }
+
+    fn test_service(
+        adapter: TestAdapter,
+        limits: RequestLimits,
+    ) -> AttestorService<TestAdapter, LocalSigner> {
+        test_service_with_policy(adapter, Policy::default(), Publisher::default(), limits)
+    }
+
+    fn test_service_with_policy(
+        adapter: TestAdapter,
+        policy: Policy,
+        publisher: Publisher,
+        limits: RequestLimits,
+    ) -> AttestorService<TestAdapter, LocalSigner> {
+        let adapter = Arc::new(adapter);
+        let head = HeadTracker::spawn(
+            Arc::clone(&adapter),
+            Duration::from_secs(1),
+            HeadLimits {
+                max_stall: Duration::from_secs(60),
+                max_clock_skew: Duration::from_secs(60),
+                max_block_age: None,
+            },
+        );
+        AttestorService::new(
+            adapter,
+            "test",
+            Arc::new(LocalSigner::new(PrivateKeySigner::random())),
+            "local",
+            Arc::new(ProvenIdentity::default()),
+            SigningMode::Tagged(SigningScheme::V1),
+            Arc::new(policy),
+            head,
+            None,
+            publisher,
+            limits,
+        )
     }
 
     fn test_packet(sequence: u64) -> Packet {
@@ -497,7 +2254,13 @@ mod tests {
     async fn validate_height_rejects_future_height() {
         let adapter = TestAdapter::with_finalized_height(10);
         let result = validate_height(&adapter, 11).await;
-        assert!(matches!(result, Err(AttestorError::BlockNotFinalized)));
+        assert!(matches!(
+            result,
+            Err(AttestorError::BlockNotFinalized {
+                height: 11,
+                finalized_height: 10
+            })
+        ));
     }
 
     #[tokio::test]
@@ -555,7 +2318,7 @@ mod tests {
         let result = handle_packet_commitment(&adapter, 50, packet, CommitmentType::Packet).await;
         assert!(matches!(
             result,
-            Err(AttestorError::InvalidCommitment { .. })
+            Err(AttestorError::CommitmentMismatch { .. })
         ));
     }
 
@@ -615,7 +2378,7 @@ mod tests {
         let result = handle_receipt_commitment(&adapter, 50, packet, CommitmentType::Receipt).await;
         assert!(matches!(
             result,
-            Err(AttestorError::InvalidCommitment { .. })
+            Err(AttestorError::CommitmentMismatch { .. })
         ));
     }
 
@@ -663,6 +2426,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inclusion_proofs_verify_against_tree_root() {
+        let packets = (0..5u8)
+            .map(|n| IAttestationMsgs::PacketCompact {
+                path: B256::repeat_byte(n),
+                commitment: B256::repeat_byte(n + 10),
+            })
+            .collect::<Vec<_>>();
+        let tree = PacketMerkleTree::new(&packets).unwrap();
+
+        let proofs = inclusion_proofs(&tree, &packets);
+        assert_eq!(proofs.len(), packets.len());
+        for (proof, packet) in proofs.iter().zip(&packets) {
+            assert_eq!(proof.path, packet.path.to_vec());
+            let siblings = proof
+                .siblings
+                .iter()
+                .map(|sibling| B256::from_slice(sibling))
+                .collect::<Vec<_>>();
+            assert!(crate::merkle::verify_proof(tree.root(), packet, &siblings));
+        }
+    }
+
     #[tokio::test]
     async fn create_packets_attestation_errors_if_any_packet_is_invalid() {
         let packet_a = test_packet(30);
@@ -700,7 +2486,1169 @@ mod tests {
             create_packets_attestation(&adapter, packets, 70, CommitmentType::Packet).await;
         assert!(matches!(
             result,
-            Err(AttestorError::InvalidCommitment { .. })
+            Err(AttestorError::CommitmentMismatch { .. })
         ));
     }
+
+    #[tokio::test]
+    async fn create_sequence_attestation_returns_located_packet() {
+        let packet = test_packet(40);
+        let commitment: [u8; 32] = packet
+            .commitment()
+            .try_into()
+            .expect("packet commitment must be 32 bytes");
+
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.insert_commitment(
+            packet.sourceClient.clone(),
+            50,
+            packet.sequence,
+            packet.commitment_path(),
+            CommitmentType::Packet,
+            Some(commitment),
+        );
+        adapter.insert_sent_packet(packet.clone());
+
+        let (compact, located) = create_sequence_attestation(
+            &adapter,
+            &packet.sourceClient,
+            50,
+            packet.sequence,
+            CommitmentType::Packet,
+        )
+        .await
+        .expect("sequence attestation must succeed");
+
+        assert_eq!(compact.path, keccak256(packet.commitment_path()));
+        assert_eq!(located, Some(packet));
+    }
+
+    #[tokio::test]
+    async fn create_sequence_attestation_attests_unlocated_packet_commitment() {
+        let packet = test_packet(41);
+
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.insert_commitment(
+            packet.sourceClient.clone(),
+            50,
+            packet.sequence,
+            packet.commitment_path(),
+            CommitmentType::Packet,
+            Some([5; 32]),
+        );
+
+        let (compact, located) = create_sequence_attestation(
+            &adapter,
+            &packet.sourceClient,
+            50,
+            packet.sequence,
+            CommitmentType::Packet,
+        )
+        .await
+        .expect("sequence attestation must succeed");
+
+        assert_eq!(compact.path, keccak256(packet.commitment_path()));
+        assert_eq!(compact.commitment.0, [5; 32]);
+        assert!(located.is_none());
+    }
+
+    #[tokio::test]
+    async fn create_sequence_attestation_rejects_located_packet_mismatch() {
+        let packet = test_packet(42);
+
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.insert_commitment(
+            packet.sourceClient.clone(),
+            50,
+            packet.sequence,
+            packet.commitment_path(),
+            CommitmentType::Packet,
+            Some([5; 32]),
+        );
+        adapter.insert_sent_packet(packet.clone());
+
+        let result = create_sequence_attestation(
+            &adapter,
+            &packet.sourceClient,
+            50,
+            packet.sequence,
+            CommitmentType::Packet,
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::CommitmentMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_sequence_attestation_uses_ack_path_of_client() {
+        let packet = test_packet(43);
+
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.insert_commitment(
+            packet.destClient.clone(),
+            50,
+            packet.sequence,
+            packet.ack_commitment_path(),
+            CommitmentType::Ack,
+            Some([7; 32]),
+        );
+
+        let (compact, located) = create_sequence_attestation(
+            &adapter,
+            &packet.destClient,
+            50,
+            packet.sequence,
+            CommitmentType::Ack,
+        )
+        .await
+        .expect("ack sequence attestation must succeed");
+
+        assert_eq!(compact.path, keccak256(packet.ack_commitment_path()));
+        assert!(located.is_none());
+    }
+
+    #[tokio::test]
+    async fn create_sequence_attestation_errors_when_missing() {
+        let adapter = TestAdapter::with_finalized_height(100);
+
+        let result =
+            create_sequence_attestation(&adapter, "src-client", 50, 44, CommitmentType::Packet)
+                .await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::CommitmentNotFound { .. })
+        ));
+    }
+
+    fn test_tx(height: u64) -> TxPacketEvents {
+        TxPacketEvents {
+            height,
+            events: vec![PacketEvent {
+                kind: PacketEventKind::Send,
+                packet: test_packet(50),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn get_finalized_tx_packet_events_returns_finalized_height() {
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter
+            .transactions
+            .insert("0xabc".to_string(), test_tx(90));
+
+        let (height, tx) = get_finalized_tx_packet_events(&adapter, "0xabc".to_string())
+            .await
+            .expect("finalized transaction must be returned");
+
+        assert_eq!(height, 100);
+        assert_eq!(tx.height, 90);
+        assert_eq!(tx.events.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn transaction_attestation_publishes_nothing_when_the_ack_fails() {
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        for packet in [test_packet(50), test_packet(52)] {
+            let commitment: [u8; 32] = packet
+                .commitment()
+                .try_into()
+                .expect("packet commitment must be 32 bytes");
+            adapter.insert_commitment(
+                packet.sourceClient.clone(),
+                100,
+                packet.sequence,
+                packet.commitment_path(),
+                CommitmentType::Packet,
+                Some(commitment),
+            );
+        }
+        // Packet 51 has no ack commitment
+        let events = |sequences: &[(PacketEventKind, u64)]| TxPacketEvents {
+            height: 90,
+            events: sequences
+                .iter()
+                .map(|&(kind, sequence)| PacketEvent {
+                    kind,
+                    packet: test_packet(sequence),
+                })
+                .collect(),
+        };
+        adapter.transactions.insert(
+            "0xabc".to_string(),
+            events(&[(PacketEventKind::Send, 50), (PacketEventKind::WriteAck, 51)]),
+        );
+        adapter
+            .transactions
+            .insert("0xdef".to_string(), events(&[(PacketEventKind::Send, 52)]));
+        let sink = BroadcastSink::new(4);
+        let mut published = sink.subscribe();
+        let service = test_service_with_policy(
+            adapter,
+            Policy::default(),
+            Publisher::spawn(vec![SinkEnum::Broadcast(sink)]),
+            RequestLimits::default(),
+        );
+        let request = |tx_hash: &str| {
+            Request::new(TransactionPacketAttestationRequest {
+                tx_hash: tx_hash.to_string(),
+            })
+        };
+
+        let status = service
+            .transaction_packet_attestation(request("0xabc"))
+            .await
+            .expect_err("a missing ack commitment must fail the request");
+        assert_eq!(status.code(), tonic::Code::NotFound);
+
+        // Attestations are published in order, so sinks must see the one of
+        // the next request first
+        let response = service
+            .transaction_packet_attestation(request("0xdef"))
+            .await
+            .expect("the send packet must be attested")
+            .into_inner();
+        let attestation = published
+            .recv()
+            .await
+            .expect("the attestation must be published");
+        assert_eq!(
+            attestation.attested_data,
+            response.packet_attestation.unwrap().attested_data
+        );
+    }
+
+    #[tokio::test]
+    async fn get_finalized_tx_packet_events_rejects_unfinalized_tx() {
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter
+            .transactions
+            .insert("0xabc".to_string(), test_tx(101));
+
+        let result = get_finalized_tx_packet_events(&adapter, "0xabc".to_string()).await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::BlockNotFinalized {
+                height: 101,
+                finalized_height: 100
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_finalized_tx_packet_events_errors_when_tx_missing() {
+        let adapter = TestAdapter::with_finalized_height(100);
+
+        let result = get_finalized_tx_packet_events(&adapter, "0xabc".to_string()).await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::TransactionNotFound { tx_hash }) if tx_hash == "0xabc"
+        ));
+    }
+
+    #[tokio::test]
+    async fn find_unrelayed_packet_returns_pending_packet() {
+        let packet = test_packet(60);
+        let commitment: [u8; 32] = packet
+            .commitment()
+            .try_into()
+            .expect("packet commitment must be 32 bytes");
+
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.insert_commitment(
+            packet.sourceClient.clone(),
+            100,
+            packet.sequence,
+            packet.commitment_path(),
+            CommitmentType::Packet,
+            Some(commitment),
+        );
+
+        let result = find_unrelayed_packet(&adapter, 100, packet.clone())
+            .await
+            .expect("lookup must succeed");
+        assert_eq!(result, Some(packet));
+    }
+
+    #[tokio::test]
+    async fn find_unrelayed_packet_skips_cleared_commitment() {
+        let adapter = TestAdapter::with_finalized_height(100);
+
+        let result = find_unrelayed_packet(&adapter, 100, test_packet(61))
+            .await
+            .expect("lookup must succeed");
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn find_unrelayed_packet_skips_mismatched_commitment() {
+        let packet = test_packet(62);
+
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.insert_commitment(
+            packet.sourceClient.clone(),
+            100,
+            packet.sequence,
+            packet.commitment_path(),
+            CommitmentType::Packet,
+            Some([9; 32]),
+        );
+
+        let result = find_unrelayed_packet(&adapter, 100, packet)
+            .await
+            .expect("lookup must succeed");
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn ack_sequences_are_rejected_when_ports_are_restricted() {
+        let packet = test_packet(70);
+        let adapter = || {
+            let mut adapter = TestAdapter::with_finalized_height(100);
+            adapter.insert_commitment(
+                packet.destClient.clone(),
+                50,
+                packet.sequence,
+                packet.ack_commitment_path(),
+                CommitmentType::Ack,
+                Some([7; 32]),
+            );
+            adapter
+        };
+        let request = || {
+            Request::new(PacketAttestationBySequenceRequest {
+                client_id: packet.destClient.clone(),
+                sequences: vec![packet.sequence],
+                height: 50,
+                commitment_type: CommitmentType::Ack.into(),
+            })
+        };
+
+        test_service(adapter(), RequestLimits::default())
+            .packet_attestation_by_sequence(request())
+            .await
+            .expect("ack must be attested without a port policy");
+
+        let policy = Policy::from(PolicyConfig {
+            allowed_port_pairs: vec![PortPair {
+                source_port: "transfer".to_string(),
+                dest_port: "transfer".to_string(),
+            }],
+            ..PolicyConfig::default()
+        });
+        let status = test_service_with_policy(
+            adapter(),
+            policy,
+            Publisher::default(),
+            RequestLimits::default(),
+        )
+        .packet_attestation_by_sequence(request())
+        .await
+        .expect_err("ack ports can't be checked against the policy");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn unrelayed_packets_rejects_oversized_ranges() {
+        let service = test_service(
+            TestAdapter::with_finalized_height(100),
+            RequestLimits {
+                max_unrelayed_scan_blocks: 10,
+                ..RequestLimits::default()
+            },
+        );
+        let request = |to_height| {
+            Request::new(UnrelayedPacketsRequest {
+                client_id: "src-client".to_string(),
+                from_height: 1,
+                to_height,
+            })
+        };
+
+        service
+            .unrelayed_packets(request(10))
+            .await
+            .expect("a range of 10 blocks must be scanned");
+        let status = service
+            .unrelayed_packets(request(11))
+            .await
+            .expect_err("a range of 11 blocks must be rejected");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn unrelayed_packets_are_truncated() {
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        for sequence in 1..=3 {
+            let packet = test_packet(sequence);
+            let commitment = packet
+                .commitment()
+                .try_into()
+                .expect("packet commitment must be 32 bytes");
+            adapter.insert_commitment(
+                packet.sourceClient.clone(),
+                100,
+                sequence,
+                packet.commitment_path(),
+                CommitmentType::Packet,
+                Some(commitment),
+            );
+            adapter.insert_sent_packet(packet);
+        }
+        let service = test_service(
+            adapter,
+            RequestLimits {
+                max_unrelayed_packets: 2,
+                unrelayed_lookup_concurrency: 2,
+                ..RequestLimits::default()
+            },
+        );
+
+        let response = service
+            .unrelayed_packets(Request::new(UnrelayedPacketsRequest {
+                client_id: "src-client".to_string(),
+                from_height: 1,
+                to_height: 100,
+            }))
+            .await
+            .expect("scan must succeed")
+            .into_inner();
+        assert!(response.truncated);
+        assert_eq!(
+            response.packets,
+            vec![test_packet(1).abi_encode(), test_packet(2).abi_encode()]
+        );
+    }
+
+    #[tokio::test]
+    async fn find_first_commitment_height_finds_earliest_height() {
+        let packet = test_packet(70);
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        for height in 42..=100 {
+            adapter.insert_commitment(
+                packet.destClient.clone(),
+                height,
+                packet.sequence,
+                packet.ack_commitment_path(),
+                CommitmentType::Ack,
+                Some([7; 32]),
+            );
+        }
+
+        let height = find_first_commitment_height(
+            &adapter,
+            &packet.destClient,
+            packet.sequence,
+            CommitmentType::Ack,
+            1,
+            None,
+        )
+        .await
+        .expect("search must succeed");
+        assert_eq!(height, 42);
+    }
+
+    #[tokio::test]
+    async fn find_first_commitment_height_finds_relayed_commitment_below_max_height() {
+        let packet = test_packet(73);
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        // Committed at 42 and cleared by the relayer at 60
+        for height in 42..60 {
+            adapter.insert_commitment(
+                packet.sourceClient.clone(),
+                height,
+                packet.sequence,
+                packet.commitment_path(),
+                CommitmentType::Packet,
+                Some([7; 32]),
+            );
+        }
+
+        let height = find_first_commitment_height(
+            &adapter,
+            &packet.sourceClient,
+            packet.sequence,
+            CommitmentType::Packet,
+            1,
+            Some(59),
+        )
+        .await
+        .expect("search must succeed");
+        assert_eq!(height, 42);
+
+        let result = find_first_commitment_height(
+            &adapter,
+            &packet.sourceClient,
+            packet.sequence,
+            CommitmentType::Packet,
+            1,
+            Some(101),
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::BlockNotFinalized { height: 101, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn find_first_commitment_height_errors_when_missing_at_finalized_height() {
+        let adapter = TestAdapter::with_finalized_height(100);
+
+        let result = find_first_commitment_height(
+            &adapter,
+            "src-client",
+            71,
+            CommitmentType::Packet,
+            1,
+            None,
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::CommitmentNotFound { height: 100, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn find_first_commitment_height_rejects_receipts() {
+        let adapter = TestAdapter::with_finalized_height(100);
+
+        let result = find_first_commitment_height(
+            &adapter,
+            "dst-client",
+            72,
+            CommitmentType::Receipt,
+            1,
+            None,
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::AdapterError(
+                AttestationAdapterError::Unsupported(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn find_first_height_at_timestamp_finds_earliest_height() {
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.block_time = 6;
+
+        // Block 51 is the first with timestamp >= 1_700_000_301
+        let height = find_first_height_at_timestamp(&adapter, 1_700_000_301, 1)
+            .await
+            .expect("search must succeed");
+        assert_eq!(height, 51);
+    }
+
+    #[tokio::test]
+    async fn find_first_height_at_timestamp_errors_when_not_reached() {
+        let mut adapter = TestAdapter::with_finalized_height(100);
+        adapter.block_time = 6;
+
+        let result = find_first_height_at_timestamp(&adapter, 1_700_000_601, 1).await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::TimestampNotReached {
+                finalized_height: 100,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn height_search_rejects_unfinalized_lower_bound() {
+        let adapter = TestAdapter::with_finalized_height(100);
+
+        let result = find_first_height_at_timestamp(&adapter, 0, 101).await;
+        assert!(matches!(
+            result,
+            Err(AttestorError::BlockNotFinalized { height: 101, .. })
+        ));
+    }
+
+    async fn next_streamed_height(
+        receiver: &mut mpsc::Receiver<Result<StateAttestationResponse, Status>>,
+    ) -> u64 {
+        receiver
+            .recv()
+            .await
+            .expect("stream must stay open")
+            .expect("attestation must succeed")
+            .attestation
+            .expect("attestation must be set")
+            .height
+    }
+
+    #[tokio::test]
+    async fn stream_state_attestations_backfills_then_follows_head() {
+        let adapter = Arc::new(TestAdapter::with_finalized_height(20));
+        let signer = Arc::new(LocalSigner::new(PrivateKeySigner::random()));
+        let (head_sender, head) = watch::channel(Some(20));
+        let (sender, mut receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+
+        tokio::spawn(stream_state_attestations(
+            adapter,
+            SigningMode::Tagged(SigningScheme::V1),
+            signer,
+            Publisher::default(),
+            head,
+            Some(10),
+            5,
+            20,
+            sender,
+        ));
+
+        assert_eq!(next_streamed_height(&mut receiver).await, 10);
+        assert_eq!(next_streamed_height(&mut receiver).await, 15);
+        assert_eq!(next_streamed_height(&mut receiver).await, 20);
+
+        // Heights between intervals are skipped
+        head_sender.send(Some(24)).unwrap();
+        head_sender.send(Some(26)).unwrap();
+        assert_eq!(next_streamed_height(&mut receiver).await, 25);
+    }
+
+    #[tokio::test]
+    async fn stream_state_attestations_starts_at_head_by_default() {
+        let adapter = Arc::new(TestAdapter::with_finalized_height(20));
+        let signer = Arc::new(LocalSigner::new(PrivateKeySigner::random()));
+        let (_head_sender, head) = watch::channel(Some(20));
+        let (sender, mut receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+
+        tokio::spawn(stream_state_attestations(
+            adapter,
+            SigningMode::Tagged(SigningScheme::V1),
+            signer,
+            Publisher::default(),
+            head,
+            None,
+            1,
+            20,
+            sender,
+        ));
+
+        assert_eq!(next_streamed_height(&mut receiver).await, 20);
+    }
+
+    #[tokio::test]
+    async fn stream_state_attestations_stops_when_subscriber_disconnects() {
+        let adapter = Arc::new(TestAdapter::with_finalized_height(20));
+        let signer = Arc::new(LocalSigner::new(PrivateKeySigner::random()));
+        let (_head_sender, head) = watch::channel(None);
+        let (sender, receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+
+        let task = tokio::spawn(stream_state_attestations(
+            adapter,
+            SigningMode::Tagged(SigningScheme::V1),
+            signer,
+            Publisher::default(),
+            head,
+            None,
+            1,
+            20,
+            sender,
+        ));
+        drop(receiver);
+
+        task.await.expect("stream task must stop cleanly");
+    }
+
+    #[tokio::test]
+    async fn stream_state_attestations_does_not_publish_backfill() {
+        let adapter = Arc::new(TestAdapter::with_finalized_height(20));
+        let signer = Arc::new(LocalSigner::new(PrivateKeySigner::random()));
+        let (head_sender, head) = watch::channel(Some(20));
+        let (sender, mut receiver) = mpsc::channel(SUBSCRIPTION_BUFFER);
+        let sink = BroadcastSink::new(4);
+        let mut published = sink.subscribe();
+
+        tokio::spawn(stream_state_attestations(
+            adapter,
+            SigningMode::Tagged(SigningScheme::V1),
+            signer,
+            Publisher::spawn(vec![SinkEnum::Broadcast(sink)]),
+            head,
+            Some(18),
+            1,
+            20,
+            sender,
+        ));
+
+        for height in 18..=20 {
+            assert_eq!(next_streamed_height(&mut receiver).await, height);
+        }
+        head_sender
+            .send(Some(21))
+            .expect("stream must follow the head");
+        assert_eq!(next_streamed_height(&mut receiver).await, 21);
+
+        for height in [20, 21] {
+            let attestation = published
+                .recv()
+                .await
+                .expect("live heights must be published");
+            assert_eq!(attestation.height, height);
+        }
+    }
+
+    #[tokio::test]
+    async fn subscriptions_are_bounded() {
+        let service = test_service(
+            TestAdapter::with_finalized_height(5_000),
+            RequestLimits {
+                max_subscription_backfill_blocks: 100,
+                max_subscriptions: 1,
+                ..RequestLimits::default()
+            },
+        );
+        let request = |start_height| {
+            Request::new(SubscribeStateAttestationsRequest {
+                start_height,
+                interval: 1,
+            })
+        };
+
+        let status = service
+            .subscribe_state_attestations(request(Some(4_899)))
+            .await
+            .expect_err("a start height 101 blocks below the head must be rejected");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let stream = service
+            .subscribe_state_attestations(request(Some(4_900)))
+            .await
+            .expect("a start height 100 blocks below the head must be accepted");
+        let status = service
+            .subscribe_state_attestations(request(None))
+            .await
+            .expect_err("a second subscription must be rejected");
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        drop(stream);
+    }
+
+    #[test]
+    fn find_watched_attestation_requires_watcher() {
+        let result =
+            find_watched_attestation(None, "src-client".to_string(), 1, CommitmentType::Packet);
+        assert!(matches!(result, Err(AttestorError::WatcherDisabled)));
+    }
+
+    #[test]
+    fn find_watched_attestation_returns_stored_attestation() {
+        let store = AttestationStore::new(10);
+        let packet = test_packet(1);
+        store.insert(
+            StoreKey {
+                client_id: "src-client".to_string(),
+                sequence: 1,
+                commitment_type: CommitmentType::Packet,
+            },
+            StoredAttestation {
+                packet: packet.clone(),
+                attestation: Attestation {
+                    height: 100,
+                    timestamp: None,
+                    attested_data: vec![1],
+                    signature: vec![2],
+                    signature_scheme: 0,
+                },
+            },
+        );
+
+        let stored = find_watched_attestation(
+            Some(&store),
+            "src-client".to_string(),
+            1,
+            CommitmentType::Packet,
+        )
+        .expect("attestation must be stored");
+        assert_eq!(stored.packet, packet);
+        assert_eq!(stored.attestation.height, 100);
+
+        let result = find_watched_attestation(
+            Some(&store),
+            "src-client".to_string(),
+            1,
+            CommitmentType::Ack,
+        );
+        assert!(matches!(
+            result,
+            Err(AttestorError::AttestationNotFound { sequence: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn contract_read_parses_call_and_storage_requests() {
+        let call = contract_read(ContractStateAttestationRequest {
+            height: 10,
+            address: vec![0xaa; 20],
+            read: Some(contract_state_attestation_request::Read::Calldata(vec![
+                0x12, 0x34, 0x56, 0x78,
+            ])),
+        })
+        .unwrap();
+        assert_eq!(
+            call,
+            ContractRead::Call {
+                address: Address::repeat_byte(0xaa),
+                calldata: vec![0x12, 0x34, 0x56, 0x78].into(),
+            }
+        );
+
+        let storage = contract_read(ContractStateAttestationRequest {
+            height: 10,
+            address: vec![0xaa; 20],
+            read: Some(contract_state_attestation_request::Read::StorageSlot(
+                vec![3; 32],
+            )),
+        })
+        .unwrap();
+        assert_eq!(
+            storage,
+            ContractRead::Storage {
+                address: Address::repeat_byte(0xaa),
+                slot: B256::repeat_byte(3),
+            }
+        );
+    }
+
+    #[test]
+    fn contract_read_rejects_malformed_requests() {
+        for request in [
+            ContractStateAttestationRequest {
+                height: 10,
+                address: vec![0xaa; 19],
+                read: Some(contract_state_attestation_request::Read::Calldata(vec![])),
+            },
+            ContractStateAttestationRequest {
+                height: 10,
+                address: vec![0xaa; 20],
+                read: Some(contract_state_attestation_request::Read::StorageSlot(
+                    vec![3; 31],
+                )),
+            },
+            ContractStateAttestationRequest {
+                height: 10,
+                address: vec![0xaa; 20],
+                read: None,
+            },
+        ] {
+            assert!(matches!(
+                contract_read(request),
+                Err(AttestorError::InvalidContractRead(_))
+            ));
+        }
+    }
+
+    fn test_event_log() -> EventLog {
+        EventLog {
+            height: 42,
+            block_hash: [1; 32],
+            tx_index: 2,
+            log_index: 7,
+            address: Address::repeat_byte(0xaa),
+            topics: vec![B256::repeat_byte(3), B256::repeat_byte(4)],
+            data: vec![5; 64].into(),
+        }
+    }
+
+    #[test]
+    fn event_log_topics_are_matched_by_prefix() {
+        let log = test_event_log();
+
+        assert!(has_leading_topics(&log, &[]));
+        assert!(has_leading_topics(&log, &[vec![3; 32]]));
+        assert!(has_leading_topics(&log, &[vec![3; 32], vec![4; 32]]));
+        assert!(!has_leading_topics(&log, &[vec![4; 32]]));
+        assert!(!has_leading_topics(&log, &[vec![3; 31]]));
+        assert!(!has_leading_topics(
+            &log,
+            &[vec![3; 32], vec![4; 32], vec![5; 32]]
+        ));
+    }
+
+    #[tokio::test]
+    #[allow(clippy::too_many_lines)]
+    async fn attestations_are_signed_for_their_domain() {
+        let key = PrivateKeySigner::random();
+        let expected = key.address();
+        let signer = LocalSigner::new(key);
+        let mode = SigningMode::Tagged(SigningScheme::V1);
+        let publisher = Publisher::default();
+        let read = ContractRead::Storage {
+            address: Address::repeat_byte(0xaa),
+            slot: B256::repeat_byte(3),
+        };
+        let header = ConsensusHeader {
+            height: 42,
+            timestamp: 1_700_000_000,
+            block_hash: [1; 32],
+            root: [2; 32],
+            validators_hash: None,
+        };
+
+        // (attestation, its domain, its timestamp, the expected attested data)
+        let cases = [
+            (
+                sign_contract_state_attestation(42, &read, vec![7; 32], &mode, &signer, &publisher)
+                    .await,
+                AttestationType::ContractState,
+                None,
+                ContractStateAttestation {
+                    height: 42,
+                    target: Address::repeat_byte(0xaa),
+                    storage: true,
+                    input: vec![3; 32].into(),
+                    result: vec![7; 32].into(),
+                }
+                .abi_encode(),
+            ),
+            (
+                sign_store_key_attestation(
+                    42,
+                    "bank".to_string(),
+                    b"\x00uatom".to_vec(),
+                    b"1000".to_vec(),
+                    &mode,
+                    &signer,
+                    &publisher,
+                )
+                .await,
+                AttestationType::StoreKey,
+                None,
+                StoreKeyAttestation {
+                    height: 42,
+                    store: "bank".to_string(),
+                    key: b"\x00uatom".to_vec().into(),
+                    value: b"1000".to_vec().into(),
+                }
+                .abi_encode(),
+            ),
+            (
+                sign_event_log_attestation(&test_event_log(), &mode, &signer, &publisher).await,
+                AttestationType::EventLog,
+                None,
+                EventLogAttestation {
+                    height: 42,
+                    blockHash: B256::repeat_byte(1),
+                    txIndex: 2,
+                    logIndex: 7,
+                    emitter: Address::repeat_byte(0xaa),
+                    topics: vec![B256::repeat_byte(3), B256::repeat_byte(4)],
+                    data: vec![5; 64].into(),
+                }
+                .abi_encode(),
+            ),
+            (
+                sign_consensus_state_attestation(&header, &mode, &signer, &publisher).await,
+                AttestationType::ConsensusState,
+                Some(1_700_000_000),
+                ConsensusStateAttestation {
+                    height: 42,
+                    timestamp: 1_700_000_000,
+                    blockHash: B256::repeat_byte(1),
+                    root: B256::repeat_byte(2),
+                    validatorsHash: B256::ZERO,
+                }
+                .abi_encode(),
+            ),
+            (
+                sign_latest_height_attestation(
+                    42,
+                    "test-chain",
+                    1_700_000_000,
+                    &mode,
+                    &signer,
+                    &publisher,
+                )
+                .await,
+                AttestationType::LatestHeight,
+                Some(1_700_000_000),
+                LatestHeightAttestation {
+                    height: 42,
+                    chainId: "test-chain".to_string(),
+                    timestamp: 1_700_000_000,
+                }
+                .abi_encode(),
+            ),
+        ];
+
+        for (attestation, attestation_type, timestamp, attested_data) in cases {
+            let attestation = attestation.unwrap();
+            assert_eq!(attestation.attestation_type, attestation_type);
+            assert_eq!(attestation.height, 42, "{attestation_type:?}");
+            assert_eq!(attestation.timestamp, timestamp, "{attestation_type:?}");
+            assert_eq!(
+                attestation.attested_data, attested_data,
+                "{attestation_type:?}"
+            );
+
+            let signing_input =
+                AttestationPayload::new(attestation.attested_data.clone(), attestation_type)
+                    .tagged_signing_input();
+            assert_eq!(
+                recover_address(&signing_input, &attestation.signature).unwrap(),
+                expected,
+                "{attestation_type:?}"
+            );
+        }
+    }
+
+    fn event_log_service(
+        finalized_height: u64,
+        allowlist: Vec<Address>,
+        canonical_block_hash: [u8; 32],
+    ) -> AttestorService<TestAdapter, LocalSigner> {
+        let mut adapter = TestAdapter::with_finalized_height(finalized_height);
+        adapter
+            .event_logs
+            .insert("0x01".to_string(), test_event_log());
+        adapter.event_log_allowlist = allowlist;
+        adapter.block_hashes.insert(42, canonical_block_hash);
+        test_service(adapter, RequestLimits::default())
+    }
+
+    fn event_log_request(topics: Vec<Vec<u8>>) -> Request<EventLogAttestationRequest> {
+        Request::new(EventLogAttestationRequest {
+            tx_hash: "0x01".to_string(),
+            log_index: 7,
+            topics,
+        })
+    }
+
+    #[tokio::test]
+    async fn event_log_attestation_signs_allowlisted_finalized_log() {
+        let service = event_log_service(100, vec![Address::repeat_byte(0xaa)], [1; 32]);
+
+        let response = service
+            .event_log_attestation(event_log_request(vec![vec![3; 32]]))
+            .await
+            .expect("an allowlisted, finalized log must be attested")
+            .into_inner();
+        let attestation = response.attestation.expect("attestation must be set");
+        assert_eq!(attestation.height, 42);
+    }
+
+    #[tokio::test]
+    async fn event_log_attestation_rejects_emitter_outside_allowlist() {
+        let service = event_log_service(100, vec![Address::repeat_byte(0xbb)], [1; 32]);
+
+        let status = service
+            .event_log_attestation(event_log_request(Vec::new()))
+            .await
+            .expect_err("a log of another emitter must be rejected");
+        assert_eq!(status.code(), tonic::Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn event_log_attestation_rejects_topic_mismatch() {
+        let service = event_log_service(100, vec![Address::repeat_byte(0xaa)], [1; 32]);
+
+        let status = service
+            .event_log_attestation(event_log_request(vec![vec![4; 32]]))
+            .await
+            .expect_err("a log with other topics must be rejected");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn event_log_attestation_rejects_log_of_reorged_block() {
+        let service = event_log_service(100, vec![Address::repeat_byte(0xaa)], [9; 32]);
+
+        let status = service
+            .event_log_attestation(event_log_request(Vec::new()))
+            .await
+            .expect_err("a log outside the canonical block must be rejected");
+        assert_eq!(status.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn event_log_attestation_rejects_unfinalized_log() {
+        let service = event_log_service(41, vec![Address::repeat_byte(0xaa)], [1; 32]);
+
+        let status = service
+            .event_log_attestation(event_log_request(Vec::new()))
+            .await
+            .expect_err("a log above the finalized height must be rejected");
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+    }
+
+    #[tokio::test]
+    async fn signer_identity_returns_key_address() {
+        let key = PrivateKeySigner::random();
+        let expected = key.address();
+        let signer = LocalSigner::new(key);
+
+        let identity = SignerIdentity::prove(&signer).await.unwrap();
+        assert_eq!(identity.address, expected.to_string());
+        assert_eq!(
+            recover_address(IDENTITY_CHALLENGE, &identity.challenge_signature).unwrap(),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn signer_identity_returns_base58_ed25519_key() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[5; 32]);
+        let expected = Pubkey::new_from_array(key.verifying_key().to_bytes());
+        let signer = LocalSigner::new_ed25519(key);
+
+        let identity = SignerIdentity::prove(&signer).await.unwrap();
+        assert_eq!(identity.address, expected.to_string());
+    }
+
+    #[tokio::test]
+    async fn identity_is_proven_once_signing_resumes() {
+        let key = PrivateKeySigner::random();
+        let expected = key.address().to_string();
+        let pause = PauseSwitch::default();
+        let signer = PausableSigner::new(LocalSigner::new(key), pause.clone());
+        let identity = ProvenIdentity::default();
+
+        let _ = pause.pause("incident".to_string());
+        assert!(matches!(
+            identity.get_or_prove(&signer).await,
+            Err(AttestorError::SigningPaused)
+        ));
+
+        let _ = pause.resume();
+        assert_eq!(
+            identity.get_or_prove(&signer).await.unwrap().address,
+            expected
+        );
+
+        // Kept once proven, so it is served while paused again
+        let _ = pause.pause("incident".to_string());
+        assert_eq!(
+            identity.get_or_prove(&signer).await.unwrap().address,
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn identity_check_fails_once_the_signer_key_changes() {
+        let key = PrivateKeySigner::random();
+        let expected = key.address().to_string();
+        let identity = ProvenIdentity::default();
+        identity.check(&LocalSigner::new(key)).await.unwrap();
+
+        let result = identity
+            .check(&LocalSigner::new(PrivateKeySigner::random()))
+            .await;
+        assert!(matches!(result, Err(AttestorError::SignerFault(_))));
+        // The identity proven first is kept
+        assert_eq!(
+            identity
+                .get_or_prove(&LocalSigner::new(PrivateKeySigner::random()))
+                .await
+                .unwrap()
+                .address,
+            expected
+        );
+    }
 }
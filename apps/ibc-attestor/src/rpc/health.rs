@@ -1,10 +1,20 @@
-use std::net::SocketAddr;
+use std::{
+    collections::BTreeMap,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
-use tokio::{net::TcpStream, sync::broadcast};
+use serde::Serialize;
+use serde_json::json;
+use tokio::{net::TcpStream, sync::broadcast, time::timeout};
 use tracing::{error, info};
 use warp::{Filter, Reply, http::StatusCode};
 
-use crate::metrics;
+use super::attestor::ProvenIdentity;
+use crate::{
+    adapter::AttestationAdapter, head::HeadTracker, metrics, pause::PauseSwitch, signer::Signer,
+};
 
 async fn check_grpc(grpc_addr: SocketAddr) -> StatusCode {
     match TcpStream::connect(grpc_addr).await {
@@ -19,14 +29,269 @@ async fn check_grpc(grpc_addr: SocketAddr) -> StatusCode {
     }
 }
 
+/// Report the gRPC server status together with the pause state. A paused
+/// attestor is still healthy, it must keep serving reads and metrics.
+async fn healthz(grpc_addr: SocketAddr, pause: PauseSwitch) -> impl Reply {
+    let status = check_grpc(grpc_addr).await;
+    warp::reply::with_status(
+        warp::reply::json(&json!({ "signing_paused": pause.is_paused() })),
+        status,
+    )
+}
+
 fn make_healthz_filter(
     grpc_addr: SocketAddr,
-) -> impl Filter<Extract = (StatusCode,), Error = warp::Rejection> + Clone + Send {
+    pause: PauseSwitch,
+) -> impl Filter<Extract = (impl Reply,), Error = warp::Rejection> + Clone + Send {
     warp::get()
         .and(warp::path("healthz"))
         .and(warp::path::end())
-        .map(move || grpc_addr)
-        .then(check_grpc)
+        .then(move || healthz(grpc_addr, pause.clone()))
+}
+
+fn make_livez_filter()
+-> impl Filter<Extract = (impl Reply,), Error = warp::Rejection> + Clone + Send {
+    warp::get()
+        .and(warp::path("livez"))
+        .and(warp::path::end())
+        .map(|| warp::reply::json(&json!({ "live": true })))
+}
+
+/// How long a signer check result is reused before signing a new probe
+/// message, so frequent `/readyz` hits and the gRPC health loop do not sign
+/// on every evaluation
+const SIGNER_CHECK_TTL: Duration = Duration::from_secs(30);
+
+/// Result of a single readiness check
+#[derive(Clone, Debug, Serialize)]
+struct Check {
+    ok: bool,
+    detail: String,
+}
+
+impl Check {
+    const fn pass(detail: String) -> Self {
+        Self { ok: true, detail }
+    }
+
+    const fn fail(detail: String) -> Self {
+        Self { ok: false, detail }
+    }
+}
+
+/// Readiness of the attestor with the breakdown of its checks
+#[derive(Debug, Serialize)]
+struct Readiness {
+    ready: bool,
+    checks: BTreeMap<&'static str, Check>,
+}
+
+/// Checks whether the attestor can serve attestations: the gRPC server
+/// accepts connections, the chain RPC answers, the finalized head advances
+/// with a block timestamp close to the local clock and the signer signs a
+/// probe message that verifies as the attestor identity.
+pub struct ReadinessProbe<A, S> {
+    grpc_addr: SocketAddr,
+    adapter: Arc<A>,
+    signer: Arc<S>,
+    identity: Arc<ProvenIdentity>,
+    pause: PauseSwitch,
+    head: HeadTracker,
+    deadline: Duration,
+    signer_check: Mutex<Option<(Instant, Check)>>,
+}
+
+impl<A, S> ReadinessProbe<A, S>
+where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    /// Create a probe failing checks that take longer than `deadline`. The
+    /// head checks report the watchdog status of `head`, and the signer check
+    /// fails unless the signer proves `identity`.
+    #[must_use]
+    pub const fn new(
+        grpc_addr: SocketAddr,
+        adapter: Arc<A>,
+        signer: Arc<S>,
+        identity: Arc<ProvenIdentity>,
+        pause: PauseSwitch,
+        head: HeadTracker,
+        deadline: Duration,
+    ) -> Self {
+        Self {
+            grpc_addr,
+            adapter,
+            signer,
+            identity,
+            pause,
+            head,
+            deadline,
+            signer_check: Mutex::new(None),
+        }
+    }
+
+    async fn check(&self) -> Readiness {
+        let (grpc, mut checks) = tokio::join!(self.check_grpc(), self.check_dependencies());
+        checks.insert("grpc", grpc);
+        Readiness {
+            ready: checks.values().all(|check| check.ok),
+            checks,
+        }
+    }
+
+    /// Returns `true` if the chain RPC, the finalized head and the signer pass
+    /// their checks. The gRPC server is not checked, as callers of its health
+    /// service reached it already.
+    pub(crate) async fn is_serving(&self) -> bool {
+        self.check_dependencies()
+            .await
+            .values()
+            .all(|check| check.ok)
+    }
+
+    async fn check_dependencies(&self) -> BTreeMap<&'static str, Check> {
+        let (adapter, signer) = tokio::join!(self.check_adapter(), self.check_signer());
+        let (head, clock) = self.check_head();
+        BTreeMap::from([
+            ("adapter", adapter),
+            ("clock", clock),
+            ("head", head),
+            ("signer", signer),
+        ])
+    }
+
+    async fn check_grpc(&self) -> Check {
+        if check_grpc(self.grpc_addr).await == StatusCode::OK {
+            Check::pass("accepting connections".to_string())
+        } else {
+            Check::fail("not accepting connections".to_string())
+        }
+    }
+
+    async fn check_adapter(&self) -> Check {
+        match timeout(
+            self.deadline,
+            self.adapter.get_last_height_at_configured_finality(),
+        )
+        .await
+        {
+            Ok(Ok(height)) => Check::pass(format!("finalized height {height}")),
+            Ok(Err(e)) => {
+                error!(error = %e, "readiness check failed: adapter error");
+                Check::fail(e.to_string())
+            }
+            Err(_) => {
+                error!("readiness check failed: adapter timed out");
+                Check::fail(self.timed_out())
+            }
+        }
+    }
+
+    /// Report the head tracker's watchdog, which logs its own transitions.
+    fn check_head(&self) -> (Check, Check) {
+        let status = self.head.status();
+        let stalled_secs = status.stalled_for.as_secs();
+        let head = match (status.height, status.stalled) {
+            (Some(height), true) => Check::fail(format!(
+                "finalized height {height} has not advanced for {stalled_secs}s"
+            )),
+            (None, true) => Check::fail(format!("no finalized height after {stalled_secs}s")),
+            (Some(_), false) => Check::pass(format!("advanced {stalled_secs}s ago")),
+            (None, false) => Check::pass("waiting for the first finalized height".to_string()),
+        };
+
+        let clock = match status.block_age_secs {
+            None => Check::pass("finalized block timestamp unknown".to_string()),
+            Some(age) => {
+                let detail = if age < 0 {
+                    format!(
+                        "finalized block is {}s ahead of the local clock",
+                        age.unsigned_abs()
+                    )
+                } else {
+                    format!("finalized block is {age}s old")
+                };
+                if status.block_time_ok {
+                    Check::pass(detail)
+                } else {
+                    Check::fail(detail)
+                }
+            }
+        };
+        (head, clock)
+    }
+
+    async fn check_signer(&self) -> Check {
+        // The kill switch refuses the probe, but a paused attestor still
+        // serves reads
+        if self.pause.is_paused() {
+            return Check::pass("signing paused".to_string());
+        }
+
+        let cached = self
+            .signer_check
+            .lock()
+            .expect("signer check lock poisoned")
+            .as_ref()
+            .filter(|(checked_at, _)| checked_at.elapsed() < SIGNER_CHECK_TTL)
+            .map(|(_, check)| check.clone());
+        if let Some(check) = cached {
+            return check;
+        }
+
+        let check = self.prove_signer().await;
+        *self
+            .signer_check
+            .lock()
+            .expect("signer check lock poisoned") = Some((Instant::now(), check.clone()));
+        check
+    }
+
+    async fn prove_signer(&self) -> Check {
+        match timeout(self.deadline, self.identity.check(self.signer.as_ref())).await {
+            Ok(Ok(identity)) => Check::pass(format!("signed as {}", identity.address)),
+            Ok(Err(e)) => {
+                error!(error = %e, "readiness check failed: signer error");
+                Check::fail(e.to_string())
+            }
+            Err(_) => {
+                error!("readiness check failed: signer timed out");
+                Check::fail(self.timed_out())
+            }
+        }
+    }
+
+    fn timed_out(&self) -> String {
+        format!("timed out after {}ms", self.deadline.as_millis())
+    }
+}
+
+async fn readyz<A, S>(probe: Arc<ReadinessProbe<A, S>>) -> impl Reply
+where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    let readiness = probe.check().await;
+    let status = if readiness.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    warp::reply::with_status(warp::reply::json(&readiness), status)
+}
+
+fn make_readyz_filter<A, S>(
+    probe: Arc<ReadinessProbe<A, S>>,
+) -> impl Filter<Extract = (impl Reply,), Error = warp::Rejection> + Clone + Send
+where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    warp::get()
+        .and(warp::path("readyz"))
+        .and(warp::path::end())
+        .then(move || readyz(Arc::clone(&probe)))
 }
 
 fn make_metrics_filter()
@@ -46,23 +311,35 @@ fn make_metrics_filter()
 
 /// Start the HTTP health server.
 ///
-/// Exposes a `GET /healthz` endpoint that returns 200 OK when the gRPC server
-/// is accepting connections, or 503 Service Unavailable when it is not ready,
-/// and a `GET /metrics` endpoint that returns the current Prometheus metrics.
-pub async fn start(
+/// Exposes:
+/// - `GET /healthz`: 200 OK when the gRPC server is accepting connections, or
+///   503 Service Unavailable when it is not ready, with a
+///   `{"signing_paused": bool}` body
+/// - `GET /livez`: 200 OK while the process serves HTTP
+/// - `GET /readyz`: 200 OK when every check of `probe` passes, 503 Service
+///   Unavailable otherwise, with the result of each check
+/// - `GET /metrics`: the current Prometheus metrics
+pub async fn start<A, S>(
     health_addr: SocketAddr,
-    grpc_addr: SocketAddr,
+    pause: PauseSwitch,
+    probe: Arc<ReadinessProbe<A, S>>,
     mut shutdown_rx: broadcast::Receiver<()>,
-) {
+) where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    let grpc_addr = probe.grpc_addr;
     info!(
         health_addr = %health_addr,
         grpc_addr = %grpc_addr,
         "starting HTTP health server"
     );
 
-    let healthz = make_healthz_filter(grpc_addr).map(Reply::into_response);
+    let healthz = make_healthz_filter(grpc_addr, pause).map(Reply::into_response);
+    let livez = make_livez_filter().map(Reply::into_response);
+    let readyz = make_readyz_filter(probe).map(Reply::into_response);
     let metrics_route = make_metrics_filter().map(Reply::into_response);
-    let routes = healthz.or(metrics_route);
+    let routes = healthz.or(livez).or(readyz).or(metrics_route);
 
     let shutdown_signal = async move {
         let _ = shutdown_rx.recv().await;
@@ -3,10 +3,19 @@ use tracing::info;
 
 use super::api::attestation_service_server::AttestationService;
 use super::api::{
-    LatestHeightRequest, LatestHeightResponse, PacketAttestationRequest, PacketAttestationResponse,
-    StateAttestationRequest, StateAttestationResponse,
+    ConsensusStateAttestationRequest, ConsensusStateAttestationResponse,
+    ContractStateAttestationRequest, ContractStateAttestationResponse, EventLogAttestationRequest,
+    EventLogAttestationResponse, FirstCommitmentHeightRequest, FirstHeightAtTimestampRequest,
+    GetAttestorInfoRequest, GetAttestorInfoResponse, HeightSearchResponse, LatestHeightRequest,
+    LatestHeightResponse, PacketAttestationBySequenceRequest, PacketAttestationBySequenceResponse,
+    PacketAttestationRequest, PacketAttestationResponse, PacketBatchAttestationRequest,
+    PacketBatchAttestationResponse, StateAttestationRequest, StateAttestationResponse,
+    StoreKeyAttestationRequest, StoreKeyAttestationResponse, SubscribeStateAttestationsRequest,
+    TransactionPacketAttestationRequest, TransactionPacketAttestationResponse,
+    UnrelayedPacketsRequest, UnrelayedPacketsResponse, WatchedPacketAttestationRequest,
+    WatchedPacketAttestationResponse,
 };
-use super::attestor::AttestorService;
+use super::attestor::{AttestorService, StateAttestationStream};
 use crate::adapter::AttestationAdapter;
 use crate::metrics;
 use crate::signer::Signer;
@@ -47,7 +56,9 @@ where
     A: AttestationAdapter,
     S: Signer,
 {
-    #[tracing::instrument(skip(self, request), fields(adapter = self.inner.adapter_name(), signer = self.inner.signer_name()))]
+    type SubscribeStateAttestationsStream = StateAttestationStream;
+
+    #[tracing::instrument(skip(self, request), fields(adapter = self.inner.adapter_name(), signer = self.inner.signer_name(), sign = request.get_ref().sign))]
     async fn latest_height(
         &self,
         request: Request<LatestHeightRequest>,
@@ -110,6 +121,161 @@ where
         result
     }
 
+    #[tracing::instrument(skip(self, request), fields(adapter = self.inner.adapter_name(), signer = self.inner.signer_name(), height = request.get_ref().height))]
+    async fn consensus_state_attestation(
+        &self,
+        request: Request<ConsensusStateAttestationRequest>,
+    ) -> Result<Response<ConsensusStateAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "consensus_state_attestation",
+            self.inner.consensus_state_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                let timestamp = response
+                    .get_ref()
+                    .attestation
+                    .as_ref()
+                    .and_then(|a| a.timestamp);
+                info!(
+                    timestamp = timestamp,
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            height = request.get_ref().height,
+            address = %hex::encode(&request.get_ref().address),
+        )
+    )]
+    async fn contract_state_attestation(
+        &self,
+        request: Request<ContractStateAttestationRequest>,
+    ) -> Result<Response<ContractStateAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "contract_state_attestation",
+            self.inner.contract_state_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                info!(
+                    resultLen = response.get_ref().result.len(),
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            height = request.get_ref().height,
+            store = %request.get_ref().store,
+            key = %hex::encode(&request.get_ref().key),
+        )
+    )]
+    async fn store_key_attestation(
+        &self,
+        request: Request<StoreKeyAttestationRequest>,
+    ) -> Result<Response<StoreKeyAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "store_key_attestation",
+            self.inner.store_key_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                info!(
+                    valueLen = response.get_ref().value.len(),
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            txHash = %request.get_ref().tx_hash,
+            logIndex = request.get_ref().log_index,
+        )
+    )]
+    async fn event_log_attestation(
+        &self,
+        request: Request<EventLogAttestationRequest>,
+    ) -> Result<Response<EventLogAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "event_log_attestation",
+            self.inner.event_log_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                let height = response.get_ref().attestation.as_ref().map(|a| a.height);
+                info!(height = height, durationMs = duration_ms, status = "ok",);
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
     #[tracing::instrument(
         skip(self, request),
         fields(
@@ -143,4 +309,354 @@ where
 
         result
     }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            height = request.get_ref().height,
+            numPackets = request.get_ref().packets.len(),
+            commitmentType = ?request.get_ref().commitment_type(),
+        )
+    )]
+    async fn packet_batch_attestation(
+        &self,
+        request: Request<PacketBatchAttestationRequest>,
+    ) -> Result<Response<PacketBatchAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "packet_batch_attestation",
+            self.inner.packet_batch_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(_) => {
+                info!(durationMs = duration_ms, status = "ok",);
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            clientId = request.get_ref().client_id,
+            height = request.get_ref().height,
+            numSequences = request.get_ref().sequences.len(),
+            commitmentType = ?request.get_ref().commitment_type(),
+        )
+    )]
+    async fn packet_attestation_by_sequence(
+        &self,
+        request: Request<PacketAttestationBySequenceRequest>,
+    ) -> Result<Response<PacketAttestationBySequenceResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "packet_attestation_by_sequence",
+            self.inner.packet_attestation_by_sequence(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                let num_located = response
+                    .get_ref()
+                    .packets
+                    .iter()
+                    .filter(|packet| !packet.is_empty())
+                    .count();
+                info!(
+                    numLocated = num_located,
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            txHash = request.get_ref().tx_hash,
+        )
+    )]
+    async fn transaction_packet_attestation(
+        &self,
+        request: Request<TransactionPacketAttestationRequest>,
+    ) -> Result<Response<TransactionPacketAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "transaction_packet_attestation",
+            self.inner.transaction_packet_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                let response = response.get_ref();
+                info!(
+                    txHeight = response.tx_height,
+                    numSentPackets = response.sent_packets.len(),
+                    numAcknowledgedPackets = response.acknowledged_packets.len(),
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            clientId = request.get_ref().client_id,
+            fromHeight = request.get_ref().from_height,
+            toHeight = request.get_ref().to_height,
+        )
+    )]
+    async fn unrelayed_packets(
+        &self,
+        request: Request<UnrelayedPacketsRequest>,
+    ) -> Result<Response<UnrelayedPacketsResponse>, Status> {
+        let (result, elapsed) =
+            metrics::track_rpc("unrelayed_packets", self.inner.unrelayed_packets(request)).await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                info!(
+                    height = response.get_ref().height,
+                    numPackets = response.get_ref().packets.len(),
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            clientId = request.get_ref().client_id,
+            sequence = request.get_ref().sequence,
+            commitmentType = ?request.get_ref().commitment_type(),
+            minHeight = request.get_ref().min_height,
+        )
+    )]
+    async fn first_commitment_height(
+        &self,
+        request: Request<FirstCommitmentHeightRequest>,
+    ) -> Result<Response<HeightSearchResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "first_commitment_height",
+            self.inner.first_commitment_height(request),
+        )
+        .await;
+        log_height_search(&result, elapsed.as_millis());
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            timestamp = request.get_ref().timestamp,
+            minHeight = request.get_ref().min_height,
+        )
+    )]
+    async fn first_height_at_timestamp(
+        &self,
+        request: Request<FirstHeightAtTimestampRequest>,
+    ) -> Result<Response<HeightSearchResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "first_height_at_timestamp",
+            self.inner.first_height_at_timestamp(request),
+        )
+        .await;
+        log_height_search(&result, elapsed.as_millis());
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            startHeight = request.get_ref().start_height,
+            interval = request.get_ref().interval,
+        )
+    )]
+    async fn subscribe_state_attestations(
+        &self,
+        request: Request<SubscribeStateAttestationsRequest>,
+    ) -> Result<Response<Self::SubscribeStateAttestationsStream>, Status> {
+        // NOTE: only the subscription is tracked, attestations are pushed afterwards
+        let (result, elapsed) = metrics::track_rpc(
+            "subscribe_state_attestations",
+            self.inner.subscribe_state_attestations(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(_) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "ok",
+                    "subscription opened"
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(
+        skip(self, request),
+        fields(
+            adapter = self.inner.adapter_name(),
+            signer = self.inner.signer_name(),
+            clientId = request.get_ref().client_id,
+            sequence = request.get_ref().sequence,
+            commitmentType = ?request.get_ref().commitment_type(),
+        )
+    )]
+    async fn watched_packet_attestation(
+        &self,
+        request: Request<WatchedPacketAttestationRequest>,
+    ) -> Result<Response<WatchedPacketAttestationResponse>, Status> {
+        let (result, elapsed) = metrics::track_rpc(
+            "watched_packet_attestation",
+            self.inner.watched_packet_attestation(request),
+        )
+        .await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                info!(
+                    height = response
+                        .get_ref()
+                        .attestation
+                        .as_ref()
+                        .map(|attestation| attestation.height),
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+
+    #[tracing::instrument(skip(self, request), fields(adapter = self.inner.adapter_name(), signer = self.inner.signer_name()))]
+    async fn get_attestor_info(
+        &self,
+        request: Request<GetAttestorInfoRequest>,
+    ) -> Result<Response<GetAttestorInfoResponse>, Status> {
+        let (result, elapsed) =
+            metrics::track_rpc("get_attestor_info", self.inner.get_attestor_info(request)).await;
+        let duration_ms = elapsed.as_millis();
+
+        match &result {
+            Ok(response) => {
+                info!(
+                    chainId = response.get_ref().chain_id,
+                    signerAddress = response.get_ref().signer_address,
+                    durationMs = duration_ms,
+                    status = "ok",
+                );
+            }
+            Err(e) => {
+                info!(
+                    durationMs = duration_ms,
+                    status = "error",
+                    error = %e,
+                );
+            }
+        }
+
+        result
+    }
+}
+
+fn log_height_search(result: &Result<Response<HeightSearchResponse>, Status>, duration_ms: u128) {
+    match result {
+        Ok(response) => {
+            info!(
+                height = response.get_ref().height,
+                signed = response.get_ref().attestation.is_some(),
+                durationMs = duration_ms,
+                status = "ok",
+            );
+        }
+        Err(e) => {
+            info!(
+                durationMs = duration_ms,
+                status = "error",
+                error = %e,
+            );
+        }
+    }
 }
@@ -9,6 +9,7 @@ pub mod api {
         tonic::include_file_descriptor_set!("ibc_attestor_descriptor");
 }
 
+mod admin;
 mod attestor;
 /// HTTP health server for readiness probes
 pub mod health;
@@ -17,6 +18,8 @@ mod middleware;
 /// Server implementation and assembly
 pub mod server;
 
+pub(crate) use attestor::attest_packets;
+pub use attestor::{ProvenIdentity, SignerIdentity};
 pub use interceptor::tracing_interceptor;
 pub use middleware::LoggingMiddleware;
 
@@ -1,16 +1,40 @@
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::sync::broadcast;
 use tonic::transport::Server;
-use tracing::{error, info};
+use tonic_health::{ServingStatus, server::HealthReporter};
+use tracing::{error, info, warn};
 
-use super::{LoggingMiddleware, RpcError, attestor::AttestorService, tracing_interceptor};
+use super::{
+    LoggingMiddleware, RpcError,
+    admin::{AdminAuth, AttestorAdmin},
+    attestor::{AttestorService, ProvenIdentity},
+    health::ReadinessProbe,
+    tracing_interceptor,
+};
 use crate::adapter::AttestationAdapter;
+use crate::attestation_payload::SigningMode;
+use crate::config::RequestLimits;
+use crate::head::HeadTracker;
+use crate::pause::PauseSwitch;
+use crate::policy::Policy;
 use crate::rpc::api::FILE_DESCRIPTOR_SET;
-use crate::rpc::api::attestation_service_server::AttestationServiceServer;
+use crate::rpc::api::admin_service_server::AdminServiceServer;
+use crate::rpc::api::attestation_service_server::{self, AttestationServiceServer};
 use crate::signer::Signer;
+use crate::sink::Publisher;
+use crate::watcher::AttestationStore;
 
-/// Start the gRPC server with attestation and reflection services.
+/// How often the status of the gRPC health service is refreshed
+const GRPC_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Start the gRPC server with attestation, reflection and
+/// `grpc.health.v1.Health` services. If an `admin_token` is configured, the
+/// admin service controlling `pause` is served on its own listener at
+/// `admin_addr`. The health service reports `SERVING` while the adapter and
+/// signer checks of `probe` pass.
 ///
 /// # Errors
 /// Returns [`RpcError::ServerError`] if the server fails to start or encounters
@@ -19,13 +43,25 @@ use crate::signer::Signer;
 /// # Panics
 /// Panics if the embedded protobuf file descriptor set is invalid. This should
 /// never occur in practice as it's validated at compile time.
+#[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip_all, fields(listen_addr = %listen_addr, adapter = adapter_name))]
 pub async fn start<A, S>(
     listen_addr: SocketAddr,
-    adapter: A,
+    adapter: Arc<A>,
     adapter_name: &'static str,
-    signer: S,
+    signer: Arc<S>,
     signer_name: &'static str,
+    identity: Arc<ProvenIdentity>,
+    mode: SigningMode,
+    policy: Arc<Policy>,
+    head: HeadTracker,
+    store: Option<Arc<AttestationStore>>,
+    publisher: Publisher,
+    limits: RequestLimits,
+    pause: PauseSwitch,
+    admin_addr: SocketAddr,
+    admin_token: Option<String>,
+    probe: Arc<ReadinessProbe<A, S>>,
     mut shutdown_rx: broadcast::Receiver<()>,
 ) -> Result<(), RpcError>
 where
@@ -47,23 +83,59 @@ where
         .build_v1()
         .expect("building reflection service should never fail with valid embedded descriptor set");
 
-    let attestation_service = AttestorService::new(adapter, adapter_name, signer, signer_name);
+    let attestation_service = AttestorService::new(
+        adapter,
+        adapter_name,
+        signer,
+        signer_name,
+        identity,
+        mode,
+        policy,
+        head,
+        store,
+        publisher,
+        limits,
+    );
     let logging_service = LoggingMiddleware::new(attestation_service);
+    let (health_reporter, health_service) = tonic_health::server::health_reporter();
+    tokio::spawn(report_health(
+        probe,
+        health_reporter,
+        shutdown_rx.resubscribe(),
+    ));
+    let admin_service = admin_token.map(|token| {
+        AdminServiceServer::with_interceptor(AttestorAdmin::new(pause), AdminAuth::new(&token))
+    });
+    let mut admin_shutdown_rx = shutdown_rx.resubscribe();
+    let admin_server = async move {
+        let Some(admin_service) = admin_service else {
+            return Ok(());
+        };
+        info!(admin_addr = %admin_addr, "admin service enabled, listening for requests");
+        Server::builder()
+            .add_service(admin_service)
+            .serve_with_shutdown(admin_addr, async move {
+                let _ = admin_shutdown_rx.recv().await;
+                info!("admin server received shutdown signal");
+            })
+            .await
+    };
 
     info!(listen_addr = %listen_addr, "gRPC server ready, listening for requests");
 
     // Serve with graceful shutdown
-    let serve_result = Server::builder()
+    let grpc_server = Server::builder()
         .add_service(AttestationServiceServer::with_interceptor(
             logging_service,
             tracing_interceptor,
         ))
+        .add_service(health_service)
         .add_service(reflection_service)
         .serve_with_shutdown(listen_addr, async move {
             let _ = shutdown_rx.recv().await;
             info!("gRPC server received shutdown signal");
-        })
-        .await;
+        });
+    let serve_result = tokio::try_join!(grpc_server, admin_server).map(|_| ());
 
     match serve_result {
         Ok(()) => {
@@ -76,3 +148,53 @@ where
         }
     }
 }
+
+/// Drive the gRPC health service from the readiness checks until shutdown.
+/// The overall status (empty service name) and the attestation service share
+/// the same status.
+async fn report_health<A, S>(
+    probe: Arc<ReadinessProbe<A, S>>,
+    reporter: HealthReporter,
+    mut shutdown_rx: broadcast::Receiver<()>,
+) where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    let mut ticker = tokio::time::interval(GRPC_HEALTH_CHECK_INTERVAL);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // Not serving until the first checks pass
+    set_health_status(&reporter, ServingStatus::NotServing).await;
+    let mut current = ServingStatus::NotServing;
+
+    loop {
+        tokio::select! {
+            _ = shutdown_rx.recv() => {
+                // Connections still open while the server stops see it going away
+                set_health_status(&reporter, ServingStatus::NotServing).await;
+                return;
+            }
+            _ = ticker.tick() => {}
+        }
+
+        let status = if probe.is_serving().await {
+            ServingStatus::Serving
+        } else {
+            ServingStatus::NotServing
+        };
+        if status != current {
+            if status == ServingStatus::Serving {
+                info!("gRPC health status changed to serving");
+            } else {
+                warn!("gRPC health status changed to not serving");
+            }
+            set_health_status(&reporter, status).await;
+            current = status;
+        }
+    }
+}
+
+async fn set_health_status(reporter: &HealthReporter, status: ServingStatus) {
+    for service in ["", attestation_service_server::SERVICE_NAME] {
+        reporter.set_service_status(service, status).await;
+    }
+}
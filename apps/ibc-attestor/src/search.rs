@@ -0,0 +1,94 @@
+use std::future::Future;
+
+/// Find the lowest height in `low..=high` at which `predicate` holds.
+///
+/// `predicate` must be monotonic over the range: once it holds at some height
+/// it must hold at every greater height. Returns `Ok(None)` if the range is
+/// empty or the predicate does not hold at `high`.
+///
+/// # Errors
+/// Returns the first error produced by `predicate`.
+pub async fn find_first_height<E, F, Fut>(
+    mut low: u64,
+    mut high: u64,
+    mut predicate: F,
+) -> Result<Option<u64>, E>
+where
+    E: Send,
+    F: FnMut(u64) -> Fut + Send,
+    Fut: Future<Output = Result<bool, E>> + Send,
+{
+    if low > high || !predicate(high).await? {
+        return Ok(None);
+    }
+
+    // Invariant: the predicate holds at `high`
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if predicate(mid).await? {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    Ok(Some(high))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::{Ready, ready};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn at_least(threshold: u64, height: u64) -> Ready<Result<bool, ()>> {
+        ready(Ok(height >= threshold))
+    }
+
+    #[tokio::test]
+    async fn finds_first_matching_height() {
+        for threshold in 10..=20 {
+            let found = find_first_height(10, 20, |height| at_least(threshold, height))
+                .await
+                .unwrap();
+            assert_eq!(found, Some(threshold));
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_predicate_never_holds() {
+        let found = find_first_height(10, 20, |height| at_least(21, height))
+            .await
+            .unwrap();
+        assert_eq!(found, None);
+    }
+
+    #[tokio::test]
+    async fn returns_none_for_empty_range() {
+        let found = find_first_height(20, 10, |height| at_least(0, height))
+            .await
+            .unwrap();
+        assert_eq!(found, None);
+    }
+
+    #[tokio::test]
+    async fn uses_logarithmic_number_of_probes() {
+        let probes = AtomicU32::new(0);
+        let found = find_first_height(0, 1_000_000, |height| {
+            probes.fetch_add(1, Ordering::Relaxed);
+            at_least(123_456, height)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(found, Some(123_456));
+        assert!(probes.load(Ordering::Relaxed) <= 22);
+    }
+
+    #[tokio::test]
+    async fn propagates_predicate_errors() {
+        let result = find_first_height(0, 10, |_| async { Err::<bool, _>("boom") }).await;
+        assert_eq!(result, Err("boom"));
+    }
+}
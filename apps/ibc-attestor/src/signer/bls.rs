@@ -0,0 +1,322 @@
+//! BLS12-381 keys and verification helpers.
+//!
+//! Signatures of several attestors over the same attestation can be
+//! aggregated into a single signature, verified against the aggregate of
+//! their public keys. Aggregate verification is only sound for public keys
+//! registered together with a [proof of possession](BlsKey::proof_of_possession).
+//!
+//! Built on `blst` with the minimal public key size variant of the IETF
+//! `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` ciphersuite, so signatures
+//! and proofs verify with any implementation of that ciphersuite.
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    os::unix::fs::OpenOptionsExt,
+    path::Path,
+};
+
+use blst::{
+    BLST_ERROR,
+    min_pk::{AggregateSignature, PublicKey, SecretKey, Signature},
+};
+
+use super::SignerError;
+
+/// Default BLS key file name
+pub const DEFAULT_BLS_KEY_NAME: &str = "ibc-attestor-bls.key";
+
+/// Length of a compressed G1 public key
+pub const PUBLIC_KEY_LENGTH: usize = 48;
+
+/// Length of a compressed G2 signature
+pub const SIGNATURE_LENGTH: usize = 96;
+
+/// Domain separation tag of signatures
+const SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Domain separation tag of proofs of possession, so a proof can't be
+/// replayed as a signature over the public key
+const PROOF_OF_POSSESSION_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// A BLS12-381 secret key together with its public key
+#[derive(Clone)]
+pub struct BlsKey {
+    secret_key: SecretKey,
+    public_key: PublicKey,
+}
+
+impl BlsKey {
+    /// Generate a new random key from the OS random source.
+    ///
+    /// # Errors
+    /// Returns [`SignerError::LocalError`] if the OS random source fails.
+    pub fn random() -> Result<Self, SignerError> {
+        let mut ikm = [0; 32];
+        getrandom::fill(&mut ikm)
+            .map_err(|e| SignerError::LocalError(format!("os random source: {e}")))?;
+        // KeyGen always derives a valid scalar from 32 bytes of key material
+        let secret_key = SecretKey::key_gen(&ikm, &[])
+            .map_err(|e| SignerError::LocalError(format!("bls key generation: {e:?}")))?;
+        ikm.fill(0);
+        Ok(Self::from_secret_key(secret_key))
+    }
+
+    /// Load a key from its 32-byte big-endian secret scalar.
+    ///
+    /// # Errors
+    /// Returns [`SignerError::ConfigError`] if the bytes are not a valid
+    /// secret key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignerError> {
+        let secret_key = SecretKey::from_bytes(bytes)
+            .map_err(|e| SignerError::ConfigError(format!("invalid bls secret key: {e:?}")))?;
+        Ok(Self::from_secret_key(secret_key))
+    }
+
+    fn from_secret_key(secret_key: SecretKey) -> Self {
+        let public_key = secret_key.sk_to_pk();
+        Self {
+            secret_key,
+            public_key,
+        }
+    }
+
+    /// Read a key file holding the hex-encoded secret key.
+    ///
+    /// # Errors
+    /// Returns [`SignerError::ConfigError`] if the file can't be read or
+    /// doesn't hold a valid secret key.
+    pub fn read_from_file(path: &Path) -> Result<Self, SignerError> {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            SignerError::ConfigError(format!("read bls key at {}: {e}", path.display()))
+        })?;
+        let bytes = hex::decode(contents.trim().trim_start_matches("0x"))
+            .map_err(|e| SignerError::ConfigError(format!("invalid bls key file: {e}")))?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Write the hex-encoded secret key to a new file at `path`, readable by
+    /// the owner only.
+    ///
+    /// # Errors
+    /// Returns [`SignerError::ConfigError`] if the file already exists or
+    /// can't be written.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), SignerError> {
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(path)
+            .and_then(|mut file| file.write_all(hex::encode(self.secret_key_bytes()).as_bytes()))
+            .map_err(|e| {
+                SignerError::ConfigError(format!("write bls key at {}: {e}", path.display()))
+            })
+    }
+
+    /// Returns the 32-byte secret scalar.
+    #[must_use]
+    pub fn secret_key_bytes(&self) -> [u8; 32] {
+        self.secret_key.to_bytes()
+    }
+
+    /// Returns the compressed public key.
+    #[must_use]
+    pub fn public_key_bytes(&self) -> [u8; PUBLIC_KEY_LENGTH] {
+        self.public_key.compress()
+    }
+
+    /// Sign `message`, returning the compressed signature.
+    #[must_use]
+    pub fn sign(&self, message: &[u8]) -> [u8; SIGNATURE_LENGTH] {
+        self.secret_key.sign(message, SIGNATURE_DST, &[]).compress()
+    }
+
+    /// Sign the public key, proving control of the secret key. Verifiers must
+    /// check it with [`verify_proof_of_possession`] before accepting the key
+    /// into an aggregate.
+    #[must_use]
+    pub fn proof_of_possession(&self) -> [u8; SIGNATURE_LENGTH] {
+        self.secret_key
+            .sign(&self.public_key_bytes(), PROOF_OF_POSSESSION_DST, &[])
+            .compress()
+    }
+}
+
+/// Decode a public key, rejecting the identity and points outside the
+/// subgroup
+fn decode_public_key(public_key: &[u8]) -> Result<PublicKey, SignerError> {
+    PublicKey::key_validate(public_key)
+        .map_err(|e| SignerError::InvalidSignature(format!("invalid bls public key: {e:?}")))
+}
+
+/// Decode a signature, rejecting points outside the subgroup
+fn decode_signature(signature: &[u8]) -> Result<Signature, SignerError> {
+    Signature::sig_validate(signature, false)
+        .map_err(|e| SignerError::InvalidSignature(format!("invalid bls signature: {e:?}")))
+}
+
+fn verify_with_dst(
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+    dst: &[u8],
+) -> Result<bool, SignerError> {
+    let signature = decode_signature(signature)?;
+    let public_key = decode_public_key(public_key)?;
+    Ok(signature.verify(false, message, dst, &[], &public_key, false) == BLST_ERROR::BLST_SUCCESS)
+}
+
+/// Verify a single signature over `message`.
+///
+/// # Errors
+/// Returns [`SignerError::InvalidSignature`] if the key or signature can't be
+/// decoded.
+pub fn verify(message: &[u8], signature: &[u8], public_key: &[u8]) -> Result<bool, SignerError> {
+    verify_with_dst(message, signature, public_key, SIGNATURE_DST)
+}
+
+/// Verify the proof of possession of `public_key`.
+///
+/// # Errors
+/// Returns [`SignerError::InvalidSignature`] if the key or proof can't be
+/// decoded.
+pub fn verify_proof_of_possession(public_key: &[u8], proof: &[u8]) -> Result<bool, SignerError> {
+    verify_with_dst(public_key, proof, public_key, PROOF_OF_POSSESSION_DST)
+}
+
+/// Aggregate signatures of several keys into a single signature.
+///
+/// # Errors
+/// Returns [`SignerError::InvalidSignature`] if a signature can't be decoded,
+/// or no signatures are given.
+pub fn aggregate_signatures<B: AsRef<[u8]>>(
+    signatures: &[B],
+) -> Result<[u8; SIGNATURE_LENGTH], SignerError> {
+    let signatures = signatures
+        .iter()
+        .map(|signature| decode_signature(signature.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let signatures = signatures.iter().collect::<Vec<_>>();
+    let aggregate = AggregateSignature::aggregate(&signatures, false)
+        .map_err(|e| SignerError::InvalidSignature(format!("invalid bls signature set: {e:?}")))?;
+    Ok(aggregate.to_signature().compress())
+}
+
+/// Verify an aggregate signature of `public_keys` over the same `message`.
+///
+/// The keys must have passed [`verify_proof_of_possession`], otherwise a
+/// rogue key can forge the aggregate.
+///
+/// # Errors
+/// Returns [`SignerError::InvalidSignature`] if a key or the signature can't
+/// be decoded, or no keys are given.
+pub fn verify_aggregate<B: AsRef<[u8]>>(
+    message: &[u8],
+    signature: &[u8],
+    public_keys: &[B],
+) -> Result<bool, SignerError> {
+    if public_keys.is_empty() {
+        return Err(SignerError::InvalidSignature(
+            "invalid bls public key set: no keys".to_string(),
+        ));
+    }
+    let public_keys = public_keys
+        .iter()
+        .map(|public_key| decode_public_key(public_key.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let public_keys = public_keys.iter().collect::<Vec<_>>();
+    let signature = decode_signature(signature)?;
+
+    Ok(
+        signature.fast_aggregate_verify(false, message, SIGNATURE_DST, &public_keys)
+            == BLST_ERROR::BLST_SUCCESS,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_verifies_for_signing_key_only() {
+        let key = BlsKey::random().unwrap();
+        let other = BlsKey::random().unwrap();
+        let signature = key.sign(b"attestation");
+
+        assert!(verify(b"attestation", &signature, &key.public_key_bytes()).unwrap());
+        assert!(!verify(b"attestation", &signature, &other.public_key_bytes()).unwrap());
+        assert!(!verify(b"other attestation", &signature, &key.public_key_bytes()).unwrap());
+    }
+
+    #[test]
+    fn proof_of_possession_binds_public_key() {
+        let key = BlsKey::random().unwrap();
+        let other = BlsKey::random().unwrap();
+        let proof = key.proof_of_possession();
+
+        assert!(verify_proof_of_possession(&key.public_key_bytes(), &proof).unwrap());
+        assert!(!verify_proof_of_possession(&other.public_key_bytes(), &proof).unwrap());
+        // A proof is not a signature over the bare public key
+        assert!(!verify(&key.public_key_bytes(), &proof, &key.public_key_bytes()).unwrap());
+    }
+
+    #[test]
+    fn aggregate_signature_verifies_against_all_keys() {
+        let keys = [
+            BlsKey::random().unwrap(),
+            BlsKey::random().unwrap(),
+            BlsKey::random().unwrap(),
+        ];
+        let message = b"same attestation";
+        let signatures = keys.iter().map(|key| key.sign(message)).collect::<Vec<_>>();
+        let public_keys = keys
+            .iter()
+            .map(BlsKey::public_key_bytes)
+            .collect::<Vec<_>>();
+
+        let aggregate = aggregate_signatures(&signatures).unwrap();
+        assert!(verify_aggregate(message, &aggregate, &public_keys).unwrap());
+        assert!(!verify_aggregate(message, &aggregate, &public_keys[..2]).unwrap());
+    }
+
+    #[test]
+    fn identity_public_key_is_rejected() {
+        let key = BlsKey::random().unwrap();
+        let signature = key.sign(b"attestation");
+        let mut identity = [0; PUBLIC_KEY_LENGTH];
+        identity[0] = 0xc0;
+
+        assert!(matches!(
+            verify(b"attestation", &signature, &identity),
+            Err(SignerError::InvalidSignature(_))
+        ));
+        assert!(matches!(
+            verify_aggregate(b"attestation", &signature, &[] as &[&[u8]]),
+            Err(SignerError::InvalidSignature(_))
+        ));
+    }
+
+    #[test]
+    fn key_round_trips_through_bytes() {
+        let key = BlsKey::random().unwrap();
+        let restored = BlsKey::from_bytes(&key.secret_key_bytes()).unwrap();
+        assert_eq!(restored.public_key_bytes(), key.public_key_bytes());
+    }
+
+    #[test]
+    fn key_file_is_private_and_never_overwritten() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let key = BlsKey::random().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bls.key");
+
+        key.write_to_file(&path).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        let restored = BlsKey::read_from_file(&path).unwrap();
+        let overwrite = BlsKey::random().unwrap().write_to_file(&path);
+
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(restored.public_key_bytes(), key.public_key_bytes());
+        assert!(matches!(overwrite, Err(SignerError::ConfigError(_))));
+    }
+}
@@ -1,36 +1,94 @@
-use std::path::PathBuf;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
-use alloy_primitives::Signature;
+use alloy_primitives::{B256, Signature};
+use alloy_signer::SignerSync;
 use alloy_signer_local::PrivateKeySigner;
 use async_trait::async_trait;
+use ed25519_dalek::{Signer as _, SigningKey};
 use ethereum_keys::{signature::sign as sync_sign, signer_local::read_from_keystore};
+use serde_json::Value;
 use tracing::info;
 
-use super::{Signer, SignerBuilder, SignerError};
+#[cfg(feature = "bls")]
+use super::bls::{BlsKey, DEFAULT_BLS_KEY_NAME};
+use super::{AttestorSignature, SignatureScheme, Signer, SignerBuilder, SignerError};
 
 /// Default keystore name
 pub const DEFAULT_KEYSTORE_NAME: &str = "ibc-attestor-keystore";
 
+/// Default Ed25519 keypair file name
+pub const DEFAULT_ED25519_KEYPAIR_NAME: &str = "ibc-attestor-ed25519.json";
+
 /// Configuration for building a local signer
 #[derive(Clone, Debug, serde::Deserialize)]
 pub struct LocalSignerConfig {
-    /// Path to keystore file or directory
+    /// Path to keystore file or directory. Ed25519 keys are read from a
+    /// keypair file in the Solana CLI format (JSON array of 64 bytes), BLS
+    /// keys from a file holding the hex-encoded secret key.
     pub keystore_path: PathBuf,
+    /// Signature scheme of the key
+    #[serde(default)]
+    pub scheme: SignatureScheme,
 }
 
-/// Local signer implementation using `PrivateKeySigner`
+/// Local signer implementation using `PrivateKeySigner` or an Ed25519
+/// `SigningKey`
 ///
 /// Wraps the existing synchronous signing logic in an async interface
 pub struct LocalSigner {
-    inner: PrivateKeySigner,
+    inner: LocalKey,
+}
+
+enum LocalKey {
+    Secp256k1(PrivateKeySigner),
+    Ed25519(SigningKey),
+    #[cfg(feature = "bls")]
+    Bls12381(BlsKey),
 }
 
 impl LocalSigner {
     /// Creates a new instance of [`LocalSigner`]
     #[must_use]
     pub const fn new(signer: PrivateKeySigner) -> Self {
-        Self { inner: signer }
+        Self {
+            inner: LocalKey::Secp256k1(signer),
+        }
+    }
+
+    /// Creates a new instance of [`LocalSigner`] signing with an Ed25519 key
+    #[must_use]
+    pub const fn new_ed25519(signing_key: SigningKey) -> Self {
+        Self {
+            inner: LocalKey::Ed25519(signing_key),
+        }
     }
+
+    /// Creates a new instance of [`LocalSigner`] signing with a BLS12-381 key
+    #[cfg(feature = "bls")]
+    #[must_use]
+    pub const fn new_bls(key: BlsKey) -> Self {
+        Self {
+            inner: LocalKey::Bls12381(key),
+        }
+    }
+}
+
+/// Read an Ed25519 keypair file in the Solana CLI format
+fn read_ed25519_keypair(path: &Path) -> Result<SigningKey, SignerError> {
+    let contents = fs::read_to_string(path).map_err(|e| {
+        SignerError::ConfigError(format!("read keypair at {}: {e}", path.display()))
+    })?;
+    let bytes: Vec<u8> = serde_json::from_str(&contents)
+        .map_err(|e| SignerError::ConfigError(format!("invalid keypair file: {e}")))?;
+    let keypair: [u8; 64] = bytes.try_into().map_err(|bytes: Vec<u8>| {
+        SignerError::ConfigError(format!("expected 64 keypair bytes, got {}", bytes.len()))
+    })?;
+
+    SigningKey::from_keypair_bytes(&keypair)
+        .map_err(|e| SignerError::ConfigError(format!("invalid keypair: {e}")))
 }
 
 impl SignerBuilder for LocalSigner {
@@ -43,7 +101,12 @@ impl SignerBuilder for LocalSigner {
 
     fn build(config: Self::Config) -> Result<Self::Signer, SignerError> {
         let keystore_path_with_file = if config.keystore_path.is_dir() {
-            config.keystore_path.join(DEFAULT_KEYSTORE_NAME)
+            config.keystore_path.join(match config.scheme {
+                SignatureScheme::Secp256k1 => DEFAULT_KEYSTORE_NAME,
+                SignatureScheme::Ed25519 => DEFAULT_ED25519_KEYPAIR_NAME,
+                #[cfg(feature = "bls")]
+                SignatureScheme::Bls12381 => DEFAULT_BLS_KEY_NAME,
+            })
         } else {
             config.keystore_path
         };
@@ -63,26 +126,84 @@ impl SignerBuilder for LocalSigner {
             keystore_path_with_file.to_string_lossy().to_string()
         };
 
-        info!(keystorePath = %with_expanded_home, "initalizing local signer");
+        info!(
+            keystorePath = %with_expanded_home,
+            scheme = config.scheme.as_str(),
+            "initalizing local signer"
+        );
 
-        let private_key_signer = read_from_keystore(PathBuf::from(with_expanded_home.clone()))
-            .map_err(|e| SignerError::ConfigError(e.to_string()))?;
+        let signer = match config.scheme {
+            SignatureScheme::Secp256k1 => {
+                read_from_keystore(PathBuf::from(with_expanded_home.clone()))
+                    .map(Self::new)
+                    .map_err(|e| SignerError::ConfigError(e.to_string()))?
+            }
+            SignatureScheme::Ed25519 => {
+                read_ed25519_keypair(Path::new(&with_expanded_home)).map(Self::new_ed25519)?
+            }
+            #[cfg(feature = "bls")]
+            SignatureScheme::Bls12381 => {
+                BlsKey::read_from_file(Path::new(&with_expanded_home)).map(Self::new_bls)?
+            }
+        };
 
         info!(
             keystorePath = %with_expanded_home,
             "local signer initialized successfully"
         );
 
-        Ok(Self::new(private_key_signer))
+        Ok(signer)
     }
 }
 
 #[async_trait]
 impl Signer for LocalSigner {
+    fn signature_scheme(&self) -> SignatureScheme {
+        match self.inner {
+            LocalKey::Secp256k1(_) => SignatureScheme::Secp256k1,
+            LocalKey::Ed25519(_) => SignatureScheme::Ed25519,
+            #[cfg(feature = "bls")]
+            LocalKey::Bls12381(_) => SignatureScheme::Bls12381,
+        }
+    }
+
     #[tracing::instrument(skip(self, message), fields(signer = "local", messageLen = message.len()))]
-    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
-        // Call the existing sync signing function
-        sync_sign(&self.inner, message).map_err(|e| SignerError::LocalError(e.to_string()))
+    async fn sign(&self, message: &[u8]) -> Result<AttestorSignature, SignerError> {
+        match &self.inner {
+            // Call the existing sync signing function
+            LocalKey::Secp256k1(signer) => sync_sign(signer, message)
+                .map(AttestorSignature::Secp256k1)
+                .map_err(|e| SignerError::LocalError(e.to_string())),
+            LocalKey::Ed25519(signing_key) => Ok(AttestorSignature::Ed25519 {
+                signature: signing_key.sign(message),
+                public_key: signing_key.verifying_key(),
+            }),
+            #[cfg(feature = "bls")]
+            LocalKey::Bls12381(key) => Ok(AttestorSignature::Bls12381 {
+                signature: key.sign(message),
+                public_key: key.public_key_bytes(),
+            }),
+        }
+    }
+
+    #[tracing::instrument(skip(self, digest, _typed_data), fields(signer = "local"))]
+    async fn sign_typed_data(
+        &self,
+        digest: &B256,
+        _typed_data: &Value,
+    ) -> Result<Signature, SignerError> {
+        match &self.inner {
+            LocalKey::Secp256k1(signer) => signer
+                .sign_hash_sync(digest)
+                .map_err(|e| SignerError::LocalError(e.to_string())),
+            LocalKey::Ed25519(_) => Err(SignerError::Unsupported(
+                "EIP-712 signing with an ed25519 key".to_string(),
+            )),
+            #[cfg(feature = "bls")]
+            LocalKey::Bls12381(_) => Err(SignerError::Unsupported(
+                "EIP-712 signing with a bls12_381 key".to_string(),
+            )),
+        }
     }
 }
 
@@ -97,7 +218,7 @@ mod tests {
         let message = b"test message";
 
         let signature = signer.sign(message).await.unwrap();
-        assert_eq!(signature.as_bytes().len(), 65);
+        assert_eq!(signature.to_bytes().len(), 65);
     }
 
     #[tokio::test]
@@ -110,4 +231,64 @@ mod tests {
         let sig2 = signer.sign(message).await.unwrap();
         assert_eq!(sig1, sig2);
     }
+
+    #[tokio::test]
+    async fn test_local_signer_sign_typed_data_signs_digest() {
+        let private_key_signer = PrivateKeySigner::random();
+        let signer = LocalSigner::new(private_key_signer.clone());
+        let digest = B256::repeat_byte(7);
+
+        let signature = signer.sign_typed_data(&digest, &Value::Null).await.unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&digest).unwrap(),
+            private_key_signer.address()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_local_signer_ed25519_signature_verifies() {
+        let signing_key = SigningKey::from_bytes(&[7; 32]);
+        let signer = LocalSigner::new_ed25519(signing_key.clone());
+        let message = b"test message";
+
+        let signature = signer.sign(message).await.unwrap();
+        let AttestorSignature::Ed25519 {
+            signature,
+            public_key,
+        } = signature
+        else {
+            panic!("expected an ed25519 signature");
+        };
+        assert_eq!(public_key, signing_key.verifying_key());
+        assert!(public_key.verify_strict(message, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_read_ed25519_keypair() {
+        let signing_key = SigningKey::from_bytes(&[7; 32]);
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(
+            file.path(),
+            serde_json::to_string(&signing_key.to_keypair_bytes().to_vec()).unwrap(),
+        )
+        .unwrap();
+
+        let read = read_ed25519_keypair(file.path()).unwrap();
+        assert_eq!(read.to_bytes(), signing_key.to_bytes());
+    }
+
+    #[cfg(feature = "bls")]
+    #[tokio::test]
+    async fn test_local_signer_bls_signature_verifies() {
+        let key = BlsKey::random().unwrap();
+        let signer = LocalSigner::new_bls(key.clone());
+        let message = b"test message";
+
+        let signature = signer.sign(message).await.unwrap();
+        assert_eq!(signature.scheme(), SignatureScheme::Bls12381);
+        assert!(
+            crate::signer::bls::verify(message, &signature.to_bytes(), &key.public_key_bytes())
+                .unwrap()
+        );
+    }
 }
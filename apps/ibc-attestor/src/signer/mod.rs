@@ -1,15 +1,98 @@
-use alloy_primitives::Signature;
+use alloy_primitives::{B256, Signature};
 use async_trait::async_trait;
+use ed25519_dalek::VerifyingKey;
+use serde::Deserialize;
+use serde_json::Value;
 
 use crate::metrics;
 use local::LocalSigner;
 use remote::RemoteSigner;
 
+/// BLS12-381 keys and aggregation helpers
+#[cfg(feature = "bls")]
+pub mod bls;
 /// Local signer implementation
 pub mod local;
+/// Signer wrapper honouring the operator kill switch
+pub mod pausable;
 /// Cosmos remote signer implementation
 pub mod remote;
 
+/// Signature scheme of an attestor key
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureScheme {
+    /// Recoverable ECDSA over secp256k1, verified by EVM light clients
+    #[default]
+    Secp256k1,
+    /// Ed25519, native to Solana
+    Ed25519,
+    /// BLS12-381 with aggregatable signatures
+    #[cfg(feature = "bls")]
+    #[serde(rename = "bls12_381")]
+    Bls12381,
+}
+
+impl SignatureScheme {
+    /// Returns the name of the scheme for logging and observability purposes.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Secp256k1 => "secp256k1",
+            Self::Ed25519 => "ed25519",
+            #[cfg(feature = "bls")]
+            Self::Bls12381 => "bls12_381",
+        }
+    }
+}
+
+/// Signature produced by a [`Signer`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AttestorSignature {
+    /// 65-byte ECDSA signature (r: 32, s: 32, v: 1), the key is recoverable
+    /// from it
+    Secp256k1(Signature),
+    /// 64-byte Ed25519 signature
+    Ed25519 {
+        /// The signature
+        signature: ed25519_dalek::Signature,
+        /// Public key of the signing key, as it can't be recovered
+        public_key: VerifyingKey,
+    },
+    /// 96-byte compressed BLS12-381 signature
+    #[cfg(feature = "bls")]
+    Bls12381 {
+        /// The signature
+        signature: [u8; bls::SIGNATURE_LENGTH],
+        /// Compressed public key of the signing key
+        public_key: [u8; bls::PUBLIC_KEY_LENGTH],
+    },
+}
+
+impl AttestorSignature {
+    /// Returns the scheme of the signature.
+    #[must_use]
+    pub const fn scheme(&self) -> SignatureScheme {
+        match self {
+            Self::Secp256k1(_) => SignatureScheme::Secp256k1,
+            Self::Ed25519 { .. } => SignatureScheme::Ed25519,
+            #[cfg(feature = "bls")]
+            Self::Bls12381 { .. } => SignatureScheme::Bls12381,
+        }
+    }
+
+    /// Returns the wire encoding of the signature.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Secp256k1(signature) => signature.as_bytes().to_vec(),
+            Self::Ed25519 { signature, .. } => signature.to_bytes().to_vec(),
+            #[cfg(feature = "bls")]
+            Self::Bls12381 { signature, .. } => signature.to_vec(),
+        }
+    }
+}
+
 /// Trait for signing attestation data
 ///
 /// This trait abstracts over local and remote signing implementations,
@@ -17,14 +100,37 @@ pub mod remote;
 /// signing service.
 #[async_trait]
 pub trait Signer: Send + Sync + 'static {
+    /// Returns the signature scheme of the key.
+    fn signature_scheme(&self) -> SignatureScheme;
+
     /// Sign a message and return the signature
     ///
     /// # Arguments
-    /// * `message` - Raw bytes to sign (will be SHA-256 hashed)
+    /// * `message` - Raw bytes to sign (SHA-256 hashed for secp256k1, signed as
+    ///   is for Ed25519 and BLS12-381)
+    ///
+    /// # Returns
+    /// * `AttestorSignature` - Signature in the scheme of the key
+    async fn sign(&self, message: &[u8]) -> Result<AttestorSignature, SignerError>;
+
+    /// Sign an EIP-712 digest
+    ///
+    /// # Arguments
+    /// * `digest` - EIP-712 signing hash, signed without further hashing
+    /// * `typed_data` - JSON typed data the digest was computed from, for
+    ///   signers that display or audit the signed content
     ///
     /// # Returns
     /// * `Signature` - 65-byte ECDSA signature (r: 32, s: 32, v: 1)
-    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError>;
+    ///
+    /// # Errors
+    /// Returns [`SignerError::Unsupported`] for Ed25519 and BLS12-381 keys, as
+    /// EIP-712 is defined for secp256k1 only.
+    async fn sign_typed_data(
+        &self,
+        digest: &B256,
+        typed_data: &Value,
+    ) -> Result<Signature, SignerError>;
 }
 
 /// Trait for building signer implementations
@@ -69,7 +175,14 @@ impl SignerEnum {
 
 #[async_trait]
 impl Signer for SignerEnum {
-    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+    fn signature_scheme(&self) -> SignatureScheme {
+        match self {
+            Self::Local(s) => s.signature_scheme(),
+            Self::Remote(s) => s.signature_scheme(),
+        }
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<AttestorSignature, SignerError> {
         let result = match self {
             Self::Local(s) => s.sign(message).await,
             Self::Remote(s) => s.sign(message).await,
@@ -77,6 +190,19 @@ impl Signer for SignerEnum {
         metrics::inc_signer_sign(if result.is_ok() { "ok" } else { "err" });
         result
     }
+
+    async fn sign_typed_data(
+        &self,
+        digest: &B256,
+        typed_data: &Value,
+    ) -> Result<Signature, SignerError> {
+        let result = match self {
+            Self::Local(s) => s.sign_typed_data(digest, typed_data).await,
+            Self::Remote(s) => s.sign_typed_data(digest, typed_data).await,
+        };
+        metrics::inc_signer_sign(if result.is_ok() { "ok" } else { "err" });
+        result
+    }
 }
 
 /// Errors that can occur during signing operations
@@ -101,4 +227,20 @@ pub enum SignerError {
     /// Bad or missing config
     #[error("Failed to build signer due to: {0}")]
     ConfigError(String),
+
+    /// Operation not available for the key's signature scheme
+    #[error("Unsupported by the signer: {0}")]
+    Unsupported(String),
+
+    /// Signing is paused by the operator
+    #[error("Signing is paused")]
+    Paused,
+}
+
+impl SignerError {
+    /// Whether the same request may succeed once the signer recovers.
+    #[must_use]
+    pub const fn is_transient(&self) -> bool {
+        matches!(self, Self::RemoteError(_) | Self::ConnectionError(_))
+    }
 }
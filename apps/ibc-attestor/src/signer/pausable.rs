@@ -0,0 +1,79 @@
+use alloy_primitives::{B256, Signature};
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::{AttestorSignature, SignatureScheme, Signer, SignerError};
+use crate::pause::PauseSwitch;
+
+/// Signer refusing to sign while its [`PauseSwitch`] is paused.
+///
+/// Wraps the configured signer so that every signing path, including the
+/// watcher, honours the operator kill switch.
+pub struct PausableSigner<S> {
+    inner: S,
+    switch: PauseSwitch,
+}
+
+impl<S> PausableSigner<S> {
+    /// Wrap `inner`, pausing it with `switch`.
+    #[must_use]
+    pub const fn new(inner: S, switch: PauseSwitch) -> Self {
+        Self { inner, switch }
+    }
+
+    fn check_paused(&self) -> Result<(), SignerError> {
+        if self.switch.is_paused() {
+            return Err(SignerError::Paused);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: Signer> Signer for PausableSigner<S> {
+    fn signature_scheme(&self) -> SignatureScheme {
+        self.inner.signature_scheme()
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<AttestorSignature, SignerError> {
+        self.check_paused()?;
+        self.inner.sign(message).await
+    }
+
+    async fn sign_typed_data(
+        &self,
+        digest: &B256,
+        typed_data: &Value,
+    ) -> Result<Signature, SignerError> {
+        self.check_paused()?;
+        self.inner.sign_typed_data(digest, typed_data).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::local::LocalSigner;
+    use alloy_signer_local::PrivateKeySigner;
+
+    #[tokio::test]
+    async fn signing_is_refused_while_paused() {
+        let switch = PauseSwitch::default();
+        let signer =
+            PausableSigner::new(LocalSigner::new(PrivateKeySigner::random()), switch.clone());
+        assert!(signer.sign(b"attestation").await.is_ok());
+
+        let _ = switch.pause("incident".to_string());
+        assert!(matches!(
+            signer.sign(b"attestation").await,
+            Err(SignerError::Paused)
+        ));
+        assert!(matches!(
+            signer.sign_typed_data(&B256::ZERO, &Value::Null).await,
+            Err(SignerError::Paused)
+        ));
+
+        let _ = switch.resume();
+        assert!(signer.sign(b"attestation").await.is_ok());
+    }
+}
@@ -1,8 +1,10 @@
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-use alloy_primitives::Signature;
+use alloy_primitives::{B256, Signature};
 use async_trait::async_trait;
+use ed25519_dalek::VerifyingKey;
+use serde_json::Value;
 use tonic::Status;
 use tonic::metadata::{Ascii, MetadataValue};
 use tonic::service::{Interceptor, interceptor::InterceptedService};
@@ -10,10 +12,10 @@ use tonic::transport::{Channel, Endpoint};
 use tracing::{Instrument, info, info_span};
 use url::Url;
 
-use super::{Signer, SignerBuilder, SignerError};
+use super::{AttestorSignature, SignatureScheme, Signer, SignerBuilder, SignerError};
 use crate::proto::signer::{
-    GetWalletRequest, PubKeyType, RecoverableMessage, SignRequest,
-    signer_service_client::SignerServiceClient,
+    GetWalletRequest, PubKeyType, RawMessage, RecoverableMessage, SignRequest, Wallet,
+    sign_request::Payload, sign_response, signer_service_client::SignerServiceClient,
 };
 
 /// Configuration for building a remote signer
@@ -30,6 +32,9 @@ pub struct RemoteSignerConfig {
     /// attached as `Authorization: Bearer <token>`.
     #[serde(default)]
     pub service_account_token_path: Option<PathBuf>,
+    /// Signature scheme of the wallet key
+    #[serde(default)]
+    pub scheme: SignatureScheme,
 }
 
 /// Remote signer implementation using gRPC client
@@ -41,6 +46,7 @@ pub struct RemoteSigner {
     endpoint: Url,
     wallet_id: String,
     service_account_token_path: Option<PathBuf>,
+    scheme: SignatureScheme,
 }
 
 impl RemoteSigner {
@@ -49,11 +55,13 @@ impl RemoteSigner {
         endpoint: Url,
         wallet_id: String,
         service_account_token_path: Option<PathBuf>,
+        scheme: SignatureScheme,
     ) -> Self {
         info!(
             endpoint = %endpoint,
             walletId = %wallet_id,
             authEnabled = service_account_token_path.is_some(),
+            scheme = scheme.as_str(),
             "remote signer configured (connection deferred until first use)"
         );
 
@@ -61,6 +69,7 @@ impl RemoteSigner {
             endpoint,
             wallet_id,
             service_account_token_path,
+            scheme,
         }
     }
 
@@ -94,21 +103,77 @@ impl SignerBuilder for RemoteSigner {
     }
 
     fn build(config: Self::Config) -> Result<Self::Signer, SignerError> {
+        #[cfg(feature = "bls")]
+        if config.scheme == SignatureScheme::Bls12381 {
+            return Err(SignerError::ConfigError(
+                "the remote signer doesn't support bls12_381 keys".to_string(),
+            ));
+        }
+
         Ok(Self::new(
             config.endpoint,
             config.wallet_id,
             config.service_account_token_path,
+            config.scheme,
         ))
     }
 }
 
 #[async_trait]
 impl Signer for RemoteSigner {
+    fn signature_scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+
     #[tracing::instrument(
         skip(self, message),
         fields(signer = "remote", walletId = %self.wallet_id, messageLen = message.len())
     )]
-    async fn sign(&self, message: &[u8]) -> Result<Signature, SignerError> {
+    async fn sign(&self, message: &[u8]) -> Result<AttestorSignature, SignerError> {
+        match self.scheme {
+            SignatureScheme::Secp256k1 => {
+                let (_, signature) = self
+                    .sign_payload(Payload::RecoverableMessage(RecoverableMessage {
+                        message: message.to_vec(),
+                    }))
+                    .await?;
+                recoverable_signature(signature).map(AttestorSignature::Secp256k1)
+            }
+            SignatureScheme::Ed25519 => {
+                let (wallet, signature) = self
+                    .sign_payload(Payload::RawMessage(RawMessage {
+                        message: message.to_vec(),
+                    }))
+                    .await?;
+                ed25519_signature(&wallet, message, signature)
+            }
+            #[cfg(feature = "bls")]
+            SignatureScheme::Bls12381 => Err(SignerError::Unsupported(
+                "bls12_381 signing with a remote signer".to_string(),
+            )),
+        }
+    }
+
+    async fn sign_typed_data(
+        &self,
+        _digest: &B256,
+        _typed_data: &Value,
+    ) -> Result<Signature, SignerError> {
+        // The signer service has no typed data payload, and a recoverable
+        // message would be hashed again before signing
+        Err(SignerError::Unsupported(
+            "EIP-712 signing with a remote signer".to_string(),
+        ))
+    }
+}
+
+impl RemoteSigner {
+    /// Request a signature over `payload` from the remote signer, returning
+    /// the signing wallet along with it
+    async fn sign_payload(
+        &self,
+        payload: Payload,
+    ) -> Result<(Wallet, sign_response::Signature), SignerError> {
         // Create a new client connection for this request
         let mut client = self
             .create_client()
@@ -118,7 +183,12 @@ impl Signer for RemoteSigner {
         // Fetch wallet information on each signing request
         let wallet_request = tonic::Request::new(GetWalletRequest {
             id: self.wallet_id.clone(),
-            pubkey_type: PubKeyType::Ethereum as i32,
+            pubkey_type: match self.scheme {
+                SignatureScheme::Secp256k1 => PubKeyType::Ethereum,
+                SignatureScheme::Ed25519 => PubKeyType::Solana,
+                #[cfg(feature = "bls")]
+                SignatureScheme::Bls12381 => PubKeyType::Raw,
+            } as i32,
         });
 
         let wallet = client
@@ -131,14 +201,8 @@ impl Signer for RemoteSigner {
             .ok_or_else(|| SignerError::RemoteError("wallet not found".to_string()))?;
 
         let request = tonic::Request::new(SignRequest {
-            wallet_id: wallet.id,
-            payload: Some(
-                crate::proto::signer::sign_request::Payload::RecoverableMessage(
-                    RecoverableMessage {
-                        message: message.to_vec(),
-                    },
-                ),
-            ),
+            wallet_id: wallet.id.clone(),
+            payload: Some(payload),
         });
 
         let response = client
@@ -152,32 +216,71 @@ impl Signer for RemoteSigner {
             .signature
             .ok_or_else(|| SignerError::RemoteError("no signature in response".to_string()))?;
 
-        // Extract raw signature bytes
-        let signature_bytes: Vec<_> = match signature {
-            crate::proto::signer::sign_response::Signature::RecoverableSignature(recoverable) => {
-                [recoverable.r, recoverable.s, recoverable.v]
-                    .into_iter()
-                    .flatten()
-                    .collect()
-            }
-            _ => {
-                return Err(SignerError::InvalidSignature(
-                    "expected resoverable signature".to_string(),
-                ));
-            }
-        };
+        Ok((wallet, signature))
+    }
+}
 
-        // Convert to 65-byte Signature
-        if signature_bytes.len() != 65 {
-            return Err(SignerError::InvalidSignature(format!(
-                "expected 65 bytes, got {}",
-                signature_bytes.len()
-            )));
+/// Parse a 65-byte recoverable signature out of a sign response
+fn recoverable_signature(signature: sign_response::Signature) -> Result<Signature, SignerError> {
+    // Extract raw signature bytes
+    let signature_bytes: Vec<_> = match signature {
+        sign_response::Signature::RecoverableSignature(recoverable) => {
+            [recoverable.r, recoverable.s, recoverable.v]
+                .into_iter()
+                .flatten()
+                .collect()
+        }
+        _ => {
+            return Err(SignerError::InvalidSignature(
+                "expected resoverable signature".to_string(),
+            ));
         }
+    };
 
-        Signature::try_from(signature_bytes.as_slice())
-            .map_err(|e| SignerError::InvalidSignature(e.to_string()))
+    // Convert to 65-byte Signature
+    if signature_bytes.len() != 65 {
+        return Err(SignerError::InvalidSignature(format!(
+            "expected 65 bytes, got {}",
+            signature_bytes.len()
+        )));
     }
+
+    Signature::try_from(signature_bytes.as_slice())
+        .map_err(|e| SignerError::InvalidSignature(e.to_string()))
+}
+
+/// Parse a 64-byte Ed25519 signature out of a sign response and check it
+/// against the wallet key, which the attestation has to carry
+fn ed25519_signature(
+    wallet: &Wallet,
+    message: &[u8],
+    signature: sign_response::Signature,
+) -> Result<AttestorSignature, SignerError> {
+    let sign_response::Signature::RawSignature(raw) = signature else {
+        return Err(SignerError::InvalidSignature(
+            "expected raw signature".to_string(),
+        ));
+    };
+    let signature = ed25519_dalek::Signature::from_slice(&raw.signature)
+        .map_err(|e| SignerError::InvalidSignature(e.to_string()))?;
+
+    let public_key: [u8; 32] = wallet.pubkey.as_slice().try_into().map_err(|_| {
+        SignerError::RemoteError(format!(
+            "expected 32-byte ed25519 wallet key, got {} bytes",
+            wallet.pubkey.len()
+        ))
+    })?;
+    let public_key = VerifyingKey::from_bytes(&public_key)
+        .map_err(|e| SignerError::RemoteError(format!("invalid wallet key: {e}")))?;
+
+    public_key
+        .verify_strict(message, &signature)
+        .map_err(|e| SignerError::InvalidSignature(e.to_string()))?;
+
+    Ok(AttestorSignature::Ed25519 {
+        signature,
+        public_key,
+    })
 }
 
 /// Read a Kubernetes `ServiceAccount` token from disk and parse it into a
@@ -221,3 +324,59 @@ impl Interceptor for AuthInterceptor {
         Ok(request)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer as _, SigningKey};
+
+    use super::*;
+    use crate::proto::signer::RawMessageSignature;
+
+    fn wallet(public_key: &VerifyingKey) -> Wallet {
+        Wallet {
+            pubkey: public_key.to_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn raw_signature(signature: &ed25519_dalek::Signature) -> sign_response::Signature {
+        sign_response::Signature::RawSignature(RawMessageSignature {
+            signature: signature.to_bytes().to_vec(),
+        })
+    }
+
+    #[test]
+    fn ed25519_signature_carries_wallet_key() {
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+        let message = b"attestation";
+        let signature = signing_key.sign(message);
+
+        let result = ed25519_signature(
+            &wallet(&signing_key.verifying_key()),
+            message,
+            raw_signature(&signature),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            AttestorSignature::Ed25519 {
+                signature,
+                public_key: signing_key.verifying_key(),
+            }
+        );
+    }
+
+    #[test]
+    fn ed25519_signature_rejects_signature_from_other_key() {
+        let signing_key = SigningKey::from_bytes(&[1; 32]);
+        let other_key = SigningKey::from_bytes(&[2; 32]);
+        let message = b"attestation";
+
+        let result = ed25519_signature(
+            &wallet(&signing_key.verifying_key()),
+            message,
+            raw_signature(&other_key.sign(message)),
+        );
+        assert!(matches!(result, Err(SignerError::InvalidSignature(_))));
+    }
+}
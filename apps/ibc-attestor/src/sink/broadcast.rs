@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use super::{AttestationSink, SinkError};
+use crate::attestation::SignedAttestation;
+
+/// Sink that forwards attestations to in-process subscribers.
+///
+/// Intended for library users embedding the attestor. Subscribers that fall
+/// more than `capacity` attestations behind miss the oldest ones, and
+/// publishing without subscribers is not an error.
+#[derive(Clone, Debug)]
+pub struct BroadcastSink {
+    sender: broadcast::Sender<SignedAttestation>,
+}
+
+impl BroadcastSink {
+    /// Create a sink buffering up to `capacity` attestations per subscriber.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Subscribe to attestations published after this call.
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<SignedAttestation> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl AttestationSink for BroadcastSink {
+    fn sink_name(&self) -> &'static str {
+        "broadcast"
+    }
+
+    async fn publish(&self, attestation: &SignedAttestation) -> Result<(), SinkError> {
+        // Only fails without subscribers, nobody is waiting for the attestation then
+        let _ = self.sender.send(attestation.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{attestation::AttestationType, signer::SignatureScheme};
+
+    fn attestation(height: u64) -> SignedAttestation {
+        SignedAttestation {
+            attestation_type: AttestationType::Packet,
+            height,
+            timestamp: None,
+            attested_data: vec![1],
+            signature: vec![2],
+            signature_scheme: SignatureScheme::Secp256k1,
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribers_receive_published_attestations() {
+        let sink = BroadcastSink::new(4);
+        let mut receiver = sink.subscribe();
+
+        sink.publish(&attestation(7)).await.unwrap();
+
+        assert_eq!(receiver.recv().await.unwrap().height, 7);
+    }
+
+    #[tokio::test]
+    async fn publishing_without_subscribers_succeeds() {
+        let sink = BroadcastSink::new(4);
+        assert!(sink.publish(&attestation(7)).await.is_ok());
+    }
+}
@@ -0,0 +1,98 @@
+use std::{fs::OpenOptions, path::PathBuf};
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::{fs::File, io::AsyncWriteExt, sync::Mutex};
+
+use super::{AttestationRecord, AttestationSink, SinkError};
+use crate::attestation::SignedAttestation;
+
+/// Configuration for the file sink.
+#[derive(Clone, Debug, Deserialize)]
+pub struct FileSinkConfig {
+    /// File attestations are appended to, created if missing
+    pub path: PathBuf,
+}
+
+/// Sink that appends each attestation to a file as a JSON line.
+#[derive(Debug)]
+pub struct FileSink {
+    file: Mutex<File>,
+}
+
+impl FileSink {
+    /// Open the configured file for appending.
+    ///
+    /// # Errors
+    /// Returns [`SinkError::ConfigError`] if the file cannot be opened.
+    pub fn new(config: &FileSinkConfig) -> Result<Self, SinkError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)
+            .map_err(|e| {
+                SinkError::ConfigError(format!("failed to open `{}`: {e}", config.path.display()))
+            })?;
+
+        Ok(Self {
+            file: Mutex::new(File::from_std(file)),
+        })
+    }
+}
+
+#[async_trait]
+impl AttestationSink for FileSink {
+    fn sink_name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn publish(&self, attestation: &SignedAttestation) -> Result<(), SinkError> {
+        let mut line = serde_json::to_vec(&AttestationRecord::from(attestation))?;
+        line.push(b'\n');
+
+        // Single write per line so concurrent readers never see partial records
+        let mut file = self.file.lock().await;
+        file.write_all(&line)
+            .await
+            .map_err(|e| SinkError::DeliveryError(e.to_string()))?;
+        file.flush()
+            .await
+            .map_err(|e| SinkError::DeliveryError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{attestation::AttestationType, signer::SignatureScheme};
+
+    #[tokio::test]
+    async fn publish_appends_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("attestations.jsonl");
+
+        let sink = FileSink::new(&FileSinkConfig { path: path.clone() }).unwrap();
+        for height in [1, 2] {
+            let attestation = SignedAttestation {
+                attestation_type: AttestationType::State,
+                height,
+                timestamp: Some(100),
+                attested_data: vec![],
+                signature: vec![],
+                signature_scheme: SignatureScheme::Secp256k1,
+            };
+            sink.publish(&attestation).await.unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let heights: Vec<u64> = contents
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<serde_json::Value>(line).unwrap()["height"]
+                    .as_u64()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(heights, vec![1, 2]);
+    }
+}
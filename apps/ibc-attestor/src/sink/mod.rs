@@ -0,0 +1,203 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::attestation::{AttestationType, SignedAttestation};
+use crate::signer::SignatureScheme;
+use broadcast::BroadcastSink;
+use file::{FileSink, FileSinkConfig};
+use webhook::{WebhookSink, WebhookSinkConfig};
+
+pub use publisher::Publisher;
+
+/// In-process broadcast channel sink
+pub mod broadcast;
+/// JSON lines file sink
+pub mod file;
+/// HTTP webhook sink
+pub mod webhook;
+
+mod publisher;
+
+/// Destination every signed attestation is pushed to.
+///
+/// Sinks are driven by the [`Publisher`], which delivers attestations in the
+/// background and retries failed deliveries, so implementations only need to
+/// attempt a single delivery.
+#[async_trait]
+pub trait AttestationSink: Send + Sync + 'static {
+    /// Returns the name of the sink for logging and observability purposes.
+    fn sink_name(&self) -> &'static str;
+
+    /// Deliver a single attestation.
+    ///
+    /// # Errors
+    /// Returns a [`SinkError`] if the attestation could not be delivered.
+    async fn publish(&self, attestation: &SignedAttestation) -> Result<(), SinkError>;
+}
+
+/// Configuration of a sink built from the config file.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SinkConfig {
+    /// HTTP webhook
+    Webhook(WebhookSinkConfig),
+    /// JSON lines file
+    File(FileSinkConfig),
+}
+
+/// Enum wrapping all concrete sink implementations.
+pub enum SinkEnum {
+    /// HTTP webhook sink
+    Webhook(WebhookSink),
+    /// JSON lines file sink
+    File(FileSink),
+    /// In-process broadcast channel sink
+    Broadcast(BroadcastSink),
+}
+
+impl SinkEnum {
+    /// Build the sink described by `config`.
+    ///
+    /// # Errors
+    /// Returns a [`SinkError`] if the sink cannot be constructed from the
+    /// provided configuration.
+    pub fn build(config: SinkConfig) -> Result<Self, SinkError> {
+        match config {
+            SinkConfig::Webhook(config) => WebhookSink::new(config).map(Self::Webhook),
+            SinkConfig::File(config) => FileSink::new(&config).map(Self::File),
+        }
+    }
+}
+
+#[async_trait]
+impl AttestationSink for SinkEnum {
+    fn sink_name(&self) -> &'static str {
+        match self {
+            Self::Webhook(s) => s.sink_name(),
+            Self::File(s) => s.sink_name(),
+            Self::Broadcast(s) => s.sink_name(),
+        }
+    }
+
+    async fn publish(&self, attestation: &SignedAttestation) -> Result<(), SinkError> {
+        match self {
+            Self::Webhook(s) => s.publish(attestation).await,
+            Self::File(s) => s.publish(attestation).await,
+            Self::Broadcast(s) => s.publish(attestation).await,
+        }
+    }
+}
+
+/// JSON representation of a [`SignedAttestation`] written by serializing sinks.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationRecord {
+    /// `state`, `packet`, `latest_height`, `packet_batch`, `consensus_state`,
+    /// `contract_state`, `store_key` or `event_log`
+    pub attestation_type: &'static str,
+    /// Block height being attested
+    pub height: u64,
+    /// Block timestamp for state attestations, wall-clock time for latest
+    /// height attestations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+    /// 0x-prefixed hex of the ABI-encoded attestation data
+    pub attested_data: String,
+    /// 0x-prefixed hex of the signature
+    pub signature: String,
+    /// `secp256k1`, `ed25519` or `bls12_381`
+    pub signature_scheme: &'static str,
+}
+
+impl From<&SignedAttestation> for AttestationRecord {
+    fn from(attestation: &SignedAttestation) -> Self {
+        Self {
+            attestation_type: match attestation.attestation_type {
+                AttestationType::State => "state",
+                AttestationType::Packet => "packet",
+                AttestationType::LatestHeight => "latest_height",
+                AttestationType::PacketBatch => "packet_batch",
+                AttestationType::ConsensusState => "consensus_state",
+                AttestationType::ContractState => "contract_state",
+                AttestationType::StoreKey => "store_key",
+                AttestationType::EventLog => "event_log",
+            },
+            height: attestation.height,
+            timestamp: attestation.timestamp,
+            attested_data: format!("0x{}", hex::encode(&attestation.attested_data)),
+            signature: format!("0x{}", hex::encode(&attestation.signature)),
+            signature_scheme: attestation.signature_scheme.as_str(),
+        }
+    }
+}
+
+/// Errors that can occur while publishing attestations
+#[derive(Debug, Error)]
+pub enum SinkError {
+    /// Transient delivery failure, the delivery is retried
+    #[error("Delivery failed: {0}")]
+    DeliveryError(String),
+
+    /// Destination rejected the attestation, the delivery is not retried
+    #[error("Delivery rejected: {0}")]
+    Rejected(String),
+
+    /// Failed to encode the attestation
+    #[error("Failed to encode attestation: {0}")]
+    EncodingError(#[from] serde_json::Error),
+
+    /// Bad or missing config
+    #[error("Failed to build sink due to: {0}")]
+    ConfigError(String),
+}
+
+impl SinkError {
+    /// Whether a later attempt may succeed.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::DeliveryError(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_hex_encodes_bytes() {
+        let attestation = SignedAttestation {
+            attestation_type: AttestationType::State,
+            height: 10,
+            timestamp: Some(1_700_000_000),
+            attested_data: vec![0xab, 0xcd],
+            signature: vec![0x01],
+            signature_scheme: SignatureScheme::Secp256k1,
+        };
+
+        let json = serde_json::to_value(AttestationRecord::from(&attestation)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "attestationType": "state",
+                "height": 10,
+                "timestamp": 1_700_000_000,
+                "attestedData": "0xabcd",
+                "signature": "0x01",
+                "signatureScheme": "secp256k1",
+            })
+        );
+    }
+
+    #[test]
+    fn sink_config_is_tagged_by_type() {
+        let config: SinkConfig = toml::from_str(
+            r#"
+            type = "file"
+            path = "/tmp/attestations.jsonl"
+            "#,
+        )
+        .unwrap();
+        assert!(matches!(config, SinkConfig::File(_)));
+    }
+}
@@ -0,0 +1,206 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicU8, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio::sync::mpsc;
+use tokio_retry::{RetryIf, strategy::ExponentialBackoff};
+use tracing::{debug, error, info, warn};
+
+use super::{AttestationSink, SinkEnum, SinkError};
+use crate::{attestation::SignedAttestation, metrics};
+
+/// Number of attestations queued per sink before new ones are dropped
+const QUEUE_CAPACITY: usize = 1_024;
+
+const MAX_ATTEMPTS: u8 = 5;
+const INITIAL_BACKOFF: u64 = 500;
+const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Pushes every signed attestation to the configured sinks.
+///
+/// Each sink has its own bounded queue and delivery task, so a slow or failing
+/// sink neither delays requests nor the other sinks. When a queue is full the
+/// attestation is dropped for that sink. A default publisher has no sinks and
+/// ignores all attestations.
+#[derive(Clone, Debug, Default)]
+pub struct Publisher {
+    queues: Vec<(&'static str, mpsc::Sender<Arc<SignedAttestation>>)>,
+}
+
+impl Publisher {
+    /// Spawn a delivery task per sink on the current tokio runtime.
+    ///
+    /// The tasks stop once the publisher and all its clones are dropped.
+    #[must_use]
+    pub fn spawn(sinks: Vec<SinkEnum>) -> Self {
+        let queues = sinks
+            .into_iter()
+            .map(|sink| {
+                let name = sink.sink_name();
+                info!(sink = name, "starting attestation sink");
+
+                let (sender, receiver) = mpsc::channel(QUEUE_CAPACITY);
+                tokio::spawn(deliver_queued(sink, receiver));
+                (name, sender)
+            })
+            .collect();
+
+        Self { queues }
+    }
+
+    /// Queue `attestation` for delivery to every sink without waiting.
+    pub fn publish(&self, attestation: &SignedAttestation) {
+        if self.queues.is_empty() {
+            return;
+        }
+
+        let attestation = Arc::new(attestation.clone());
+        for (name, sender) in &self.queues {
+            if sender.try_send(Arc::clone(&attestation)).is_err() {
+                warn!(
+                    sink = name,
+                    height = attestation.height,
+                    "attestation sink queue full, dropping attestation"
+                );
+                metrics::inc_sink_delivery(name, "dropped");
+            }
+        }
+    }
+}
+
+async fn deliver_queued(sink: SinkEnum, mut receiver: mpsc::Receiver<Arc<SignedAttestation>>) {
+    while let Some(attestation) = receiver.recv().await {
+        let result = if deliver_with_retry(&sink, &attestation).await.is_ok() {
+            "ok"
+        } else {
+            "failed"
+        };
+        metrics::inc_sink_delivery(sink.sink_name(), result);
+    }
+
+    debug!(sink = sink.sink_name(), "publisher dropped, stopping sink");
+}
+
+/// Deliver `attestation`, retrying transient failures with exponential backoff.
+async fn deliver_with_retry(
+    sink: &impl AttestationSink,
+    attestation: &SignedAttestation,
+) -> Result<(), SinkError> {
+    let retry_strategy = ExponentialBackoff::from_millis(INITIAL_BACKOFF)
+        .factor(2)
+        .max_delay(MAX_BACKOFF)
+        .take(usize::from(MAX_ATTEMPTS.saturating_sub(1)));
+
+    let attempts = AtomicU8::new(0);
+    let result = RetryIf::spawn(
+        retry_strategy,
+        || {
+            let attempt = attempts.fetch_add(1, Ordering::Relaxed).saturating_add(1);
+            debug!(
+                sink = sink.sink_name(),
+                height = attestation.height,
+                attempt,
+                maxAttempts = MAX_ATTEMPTS,
+                "delivering attestation"
+            );
+            sink.publish(attestation)
+        },
+        SinkError::is_retryable,
+    )
+    .await;
+
+    if let Err(error) = &result {
+        error!(
+            sink = sink.sink_name(),
+            height = attestation.height,
+            attempts = attempts.load(Ordering::Relaxed),
+            maxAttempts = MAX_ATTEMPTS,
+            error = %error,
+            "failed to deliver attestation"
+        );
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicU32;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::sink::broadcast::BroadcastSink;
+    use crate::{attestation::AttestationType, signer::SignatureScheme};
+
+    struct FlakySink {
+        failures: u32,
+        error: fn() -> SinkError,
+        calls: AtomicU32,
+    }
+
+    #[async_trait]
+    impl AttestationSink for FlakySink {
+        fn sink_name(&self) -> &'static str {
+            "flaky"
+        }
+
+        async fn publish(&self, _attestation: &SignedAttestation) -> Result<(), SinkError> {
+            if self.calls.fetch_add(1, Ordering::Relaxed) < self.failures {
+                Err((self.error)())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn attestation(height: u64) -> SignedAttestation {
+        SignedAttestation {
+            attestation_type: AttestationType::State,
+            height,
+            timestamp: Some(1_700_000_000),
+            attested_data: vec![1],
+            signature: vec![2],
+            signature_scheme: SignatureScheme::Secp256k1,
+        }
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried() {
+        let sink = FlakySink {
+            failures: 1,
+            error: || SinkError::DeliveryError("unavailable".to_string()),
+            calls: AtomicU32::new(0),
+        };
+
+        deliver_with_retry(&sink, &attestation(1)).await.unwrap();
+        assert_eq!(sink.calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn rejected_deliveries_are_not_retried() {
+        let sink = FlakySink {
+            failures: 1,
+            error: || SinkError::Rejected("bad request".to_string()),
+            calls: AtomicU32::new(0),
+        };
+
+        assert!(deliver_with_retry(&sink, &attestation(1)).await.is_err());
+        assert_eq!(sink.calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn publish_delivers_to_sinks() {
+        let sink = BroadcastSink::new(4);
+        let mut receiver = sink.subscribe();
+        let publisher = Publisher::spawn(vec![SinkEnum::Broadcast(sink)]);
+
+        publisher.publish(&attestation(5));
+
+        assert_eq!(receiver.recv().await.unwrap().height, 5);
+    }
+}
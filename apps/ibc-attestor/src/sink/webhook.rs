@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+use url::Url;
+
+use super::{AttestationRecord, AttestationSink, SinkError};
+use crate::attestation::SignedAttestation;
+
+/// Header carrying the hex encoded HMAC-SHA256 of the request body
+pub const SIGNATURE_HEADER: &str = "x-attestor-signature";
+
+/// Configuration for the webhook sink.
+#[derive(Clone, Debug, Deserialize)]
+pub struct WebhookSinkConfig {
+    /// Endpoint attestations are POSTed to as JSON
+    pub url: Url,
+    /// Shared secret used to sign request bodies. Unsigned if unset.
+    pub hmac_secret: Option<String>,
+    /// Request timeout in milliseconds
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+const fn default_timeout_ms() -> u64 {
+    5_000
+}
+
+/// Sink that POSTs each attestation to an HTTP endpoint.
+///
+/// When a secret is configured the body is signed with HMAC-SHA256 and the
+/// signature is sent in the [`SIGNATURE_HEADER`] header as `sha256=<hex>`, so
+/// receivers can authenticate the attestor.
+#[derive(Debug)]
+pub struct WebhookSink {
+    client: reqwest::Client,
+    url: Url,
+    hmac_secret: Option<Vec<u8>>,
+}
+
+impl WebhookSink {
+    /// Build the HTTP client for the configured endpoint.
+    ///
+    /// # Errors
+    /// Returns [`SinkError::ConfigError`] if the HTTP client cannot be built.
+    pub fn new(config: WebhookSinkConfig) -> Result<Self, SinkError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()
+            .map_err(|e| SinkError::ConfigError(e.to_string()))?;
+
+        Ok(Self {
+            client,
+            url: config.url,
+            hmac_secret: config.hmac_secret.map(String::into_bytes),
+        })
+    }
+}
+
+/// Sign `body` with HMAC-SHA256, formatted as the [`SIGNATURE_HEADER`] value.
+fn signature_header(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[async_trait]
+impl AttestationSink for WebhookSink {
+    fn sink_name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn publish(&self, attestation: &SignedAttestation) -> Result<(), SinkError> {
+        let body = serde_json::to_vec(&AttestationRecord::from(attestation))?;
+
+        let mut request = self
+            .client
+            .post(self.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(secret) = &self.hmac_secret {
+            request = request.header(SIGNATURE_HEADER, signature_header(secret, &body));
+        }
+
+        let response = request
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| SinkError::DeliveryError(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Err(SinkError::DeliveryError(format!(
+                "webhook returned {status}"
+            )))
+        } else {
+            Err(SinkError::Rejected(format!("webhook returned {status}")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_header_matches_rfc_4231_vector() {
+        // RFC 4231 test case 2
+        let header = signature_header(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            header,
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -0,0 +1,593 @@
+use std::sync::Arc;
+
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Packet;
+use serde::Deserialize;
+use tokio::{sync::watch, task::JoinHandle};
+use tracing::{debug, error, info, warn};
+
+use crate::{
+    AttestorError,
+    adapter::{AttestationAdapter, AttestationAdapterError},
+    attestation_payload::SigningMode,
+    config::ConfigError,
+    head::HeadTracker,
+    metrics,
+    policy::Policy,
+    rpc::{api::CommitmentType, attest_packets},
+    signer::Signer,
+    sink::Publisher,
+};
+
+pub use store::{AttestationStore, StoreKey, StoredAttestation};
+
+mod store;
+
+/// Configuration for the optional watcher that pre-computes packet attestations.
+#[derive(Clone, Debug, Deserialize)]
+pub struct WatcherConfig {
+    /// Client IDs to watch. Packets sent through a client (source client) and
+    /// acknowledgements written for a client (destination client) are attested.
+    pub client_ids: Vec<String>,
+
+    /// First height to scan. Defaults to the finalized height at startup.
+    pub start_height: Option<u64>,
+
+    /// Maximum number of blocks scanned before attestations are stored.
+    #[serde(default = "default_max_blocks_per_scan")]
+    pub max_blocks_per_scan: u64,
+
+    /// Maximum number of attestations kept in memory.
+    #[serde(default = "default_store_capacity")]
+    pub store_capacity: usize,
+}
+
+const fn default_max_blocks_per_scan() -> u64 {
+    100
+}
+
+const fn default_store_capacity() -> usize {
+    10_000
+}
+
+impl WatcherConfig {
+    pub(crate) fn validate(self) -> Result<Self, ConfigError> {
+        if self.client_ids.is_empty() {
+            return Err(ConfigError::InvalidWatcherConfig(
+                "`watcher.client_ids` must not be empty".to_string(),
+            ));
+        }
+        if self.max_blocks_per_scan == 0 {
+            return Err(ConfigError::InvalidWatcherConfig(
+                "`watcher.max_blocks_per_scan` must be positive".to_string(),
+            ));
+        }
+        if self.store_capacity == 0 {
+            return Err(ConfigError::InvalidWatcherConfig(
+                "`watcher.store_capacity` must be positive".to_string(),
+            ));
+        }
+
+        Ok(self)
+    }
+}
+
+/// Spawn the watcher task.
+///
+/// The watcher follows the finalized head, scans each new range of finalized
+/// blocks for `SendPacket` and `WriteAcknowledgement` events of the configured
+/// clients and stores a signed packet attestation for each of them. The
+/// attestations are made at the last height of the scanned range. Packets
+/// the `policy` rejects are skipped. A failed range is retried once the head
+/// advances again.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn<A, S>(
+    config: WatcherConfig,
+    adapter: Arc<A>,
+    mode: SigningMode,
+    signer: Arc<S>,
+    policy: Arc<Policy>,
+    publisher: Publisher,
+    head: &HeadTracker,
+    store: Arc<AttestationStore>,
+) -> JoinHandle<()>
+where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    info!(
+        clientIds = ?config.client_ids,
+        startHeight = ?config.start_height,
+        maxBlocksPerScan = config.max_blocks_per_scan,
+        storeCapacity = config.store_capacity,
+        "starting watcher"
+    );
+
+    tokio::spawn(watch_finalized_blocks(
+        config,
+        adapter,
+        mode,
+        signer,
+        policy,
+        publisher,
+        head.subscribe(),
+        store,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn watch_finalized_blocks<A, S>(
+    config: WatcherConfig,
+    adapter: Arc<A>,
+    mode: SigningMode,
+    signer: Arc<S>,
+    policy: Arc<Policy>,
+    publisher: Publisher,
+    mut head: watch::Receiver<Option<u64>>,
+    store: Arc<AttestationStore>,
+) where
+    A: AttestationAdapter,
+    S: Signer,
+{
+    let mut next_height = config.start_height;
+
+    loop {
+        // Copy out the height so the watch lock isn't held across awaits
+        let finalized = *head.borrow_and_update();
+        if let Some(finalized) = finalized {
+            let from_height = next_height.get_or_insert(finalized);
+
+            while *from_height <= finalized {
+                let to_height = from_height
+                    .saturating_add(config.max_blocks_per_scan - 1)
+                    .min(finalized);
+
+                if let Err(err) = scan_range(
+                    &config.client_ids,
+                    adapter.as_ref(),
+                    &mode,
+                    signer.as_ref(),
+                    &policy,
+                    &publisher,
+                    &store,
+                    *from_height,
+                    to_height,
+                )
+                .await
+                {
+                    // Retrying can't help if the adapter can't scan at all
+                    if matches!(
+                        err,
+                        AttestorError::AdapterError(AttestationAdapterError::Unsupported(_))
+                    ) {
+                        error!(error = %err, "watcher can't scan blocks, stopping watcher");
+                        return;
+                    }
+                    error!(
+                        fromHeight = *from_height,
+                        toHeight = to_height,
+                        error = %err,
+                        "watcher failed to scan blocks, retrying on next head"
+                    );
+                    break;
+                }
+
+                metrics::set_watcher_scanned_height(to_height);
+                *from_height = to_height.saturating_add(1);
+            }
+        }
+
+        if head.changed().await.is_err() {
+            info!("head tracker stopped, stopping watcher");
+            return;
+        }
+    }
+}
+
+/// Attest to all packet events of the watched clients in
+/// `from_height..=to_height`.
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip(client_ids, adapter, mode, signer, policy, publisher, store))]
+async fn scan_range(
+    client_ids: &[String],
+    adapter: &impl AttestationAdapter,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    policy: &Policy,
+    publisher: &Publisher,
+    store: &AttestationStore,
+    from_height: u64,
+    to_height: u64,
+) -> Result<(), AttestorError> {
+    for client_id in client_ids {
+        let sent = adapter
+            .get_send_packets(client_id, from_height, to_height)
+            .await?;
+        for packet in sent {
+            attest_and_store(
+                adapter,
+                mode,
+                signer,
+                policy,
+                publisher,
+                store,
+                to_height,
+                packet,
+                CommitmentType::Packet,
+            )
+            .await?;
+        }
+
+        let acknowledged = adapter
+            .get_write_ack_packets(client_id, from_height, to_height)
+            .await?;
+        for packet in acknowledged {
+            attest_and_store(
+                adapter,
+                mode,
+                signer,
+                policy,
+                publisher,
+                store,
+                to_height,
+                packet,
+                CommitmentType::Ack,
+            )
+            .await?;
+        }
+    }
+
+    debug!("scanned finalized blocks");
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    skip(adapter, mode, signer, policy, publisher, store, height, packet),
+    fields(sequence = packet.sequence)
+)]
+async fn attest_and_store(
+    adapter: &impl AttestationAdapter,
+    mode: &SigningMode,
+    signer: &impl Signer,
+    policy: &Policy,
+    publisher: &Publisher,
+    store: &AttestationStore,
+    height: u64,
+    packet: Packet,
+    commitment_type: CommitmentType,
+) -> Result<(), AttestorError> {
+    let kind = match commitment_type {
+        CommitmentType::Packet => "packet",
+        CommitmentType::Ack => "ack",
+        CommitmentType::Receipt => "receipt",
+    };
+
+    // Packets the policy would refuse to serve are neither signed nor
+    // published to sinks
+    let admission = match policy
+        .check_packets([&packet], commitment_type)
+        .and_then(|transfers| policy.admit(&transfers))
+    {
+        Ok(admission) => admission,
+        Err(err) => {
+            warn!(error = %err, "packet denied by policy, skipping");
+            metrics::inc_watcher_attestation(kind, "denied");
+            return Ok(());
+        }
+    };
+
+    let attestation = match attest_packets(
+        adapter,
+        mode,
+        signer,
+        publisher,
+        std::slice::from_ref(&packet),
+        height,
+        commitment_type,
+    )
+    .await
+    {
+        Ok(Some(attestation)) => {
+            admission.commit();
+            attestation
+        }
+        Ok(None) => return Ok(()),
+        // Packet was already relayed or timed out by the end of the range
+        Err(AttestorError::CommitmentNotFound { .. }) => {
+            debug!("commitment no longer exists, skipping");
+            metrics::inc_watcher_attestation(kind, "skipped");
+            return Ok(());
+        }
+        Err(err) => return Err(err),
+    };
+
+    let client_id = match commitment_type {
+        CommitmentType::Ack => packet.destClient.clone(),
+        CommitmentType::Packet | CommitmentType::Receipt => packet.sourceClient.clone(),
+    };
+    store.insert(
+        StoreKey {
+            client_id,
+            sequence: packet.sequence,
+            commitment_type,
+        },
+        StoredAttestation {
+            packet,
+            attestation,
+        },
+    );
+
+    debug!(height, "stored pre-computed attestation");
+    metrics::inc_watcher_attestation(kind, "ok");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use alloy_signer_local::PrivateKeySigner;
+    use ethereum_keys::attestation::SigningScheme;
+    use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Payload;
+
+    use super::*;
+    use crate::policy::{PolicyConfig, PortPair};
+    use crate::signer::local::LocalSigner;
+    use crate::sink::{SinkEnum, broadcast::BroadcastSink};
+
+    struct TestAdapter {
+        sent: Vec<(u64, Packet)>,
+        acknowledged: Vec<(u64, Packet)>,
+        commitments: HashMap<Vec<u8>, [u8; 32]>,
+    }
+
+    #[async_trait::async_trait]
+    impl AttestationAdapter for TestAdapter {
+        async fn get_last_height_at_configured_finality(
+            &self,
+        ) -> Result<u64, AttestationAdapterError> {
+            Ok(100)
+        }
+
+        async fn get_block_timestamp(&self, _height: u64) -> Result<u64, AttestationAdapterError> {
+            Ok(1_700_000_000)
+        }
+
+        async fn get_commitment(
+            &self,
+            _client_id: String,
+            _height: u64,
+            _sequence: u64,
+            commitment_path: &[u8],
+            _commitment_type: CommitmentType,
+        ) -> Result<Option<[u8; 32]>, AttestationAdapterError> {
+            Ok(self.commitments.get(commitment_path).copied())
+        }
+
+        async fn get_send_packets(
+            &self,
+            client_id: &str,
+            from_height: u64,
+            to_height: u64,
+        ) -> Result<Vec<Packet>, AttestationAdapterError> {
+            Ok(self
+                .sent
+                .iter()
+                .filter(|(height, packet)| {
+                    packet.sourceClient == client_id && (from_height..=to_height).contains(height)
+                })
+                .map(|(_, packet)| packet.clone())
+                .collect())
+        }
+
+        async fn get_write_ack_packets(
+            &self,
+            client_id: &str,
+            from_height: u64,
+            to_height: u64,
+        ) -> Result<Vec<Packet>, AttestationAdapterError> {
+            Ok(self
+                .acknowledged
+                .iter()
+                .filter(|(height, packet)| {
+                    packet.destClient == client_id && (from_height..=to_height).contains(height)
+                })
+                .map(|(_, packet)| packet.clone())
+                .collect())
+        }
+    }
+
+    fn test_packet(sequence: u64, source: &str, dest: &str) -> Packet {
+        Packet {
+            sequence,
+            sourceClient: source.to_string(),
+            destClient: dest.to_string(),
+            timeoutTimestamp: 1_700_000_600,
+            payloads: vec![Payload {
+                sourcePort: "transfer".to_string(),
+                destPort: "transfer".to_string(),
+                version: "ics20-1".to_string(),
+                encoding: "application/x-solidity-abi".to_string(),
+                value: vec![1, 2, 3].into(),
+            }],
+        }
+    }
+
+    fn key(client_id: &str, sequence: u64, commitment_type: CommitmentType) -> StoreKey {
+        StoreKey {
+            client_id: client_id.to_string(),
+            sequence,
+            commitment_type,
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_range_stores_attestations_for_watched_clients() {
+        let sent = test_packet(1, "watched", "remote");
+        let relayed = test_packet(2, "watched", "remote");
+        let other = test_packet(3, "other", "remote");
+        let acknowledged = test_packet(7, "remote", "watched");
+
+        let adapter = TestAdapter {
+            sent: vec![(10, sent.clone()), (11, relayed), (12, other)],
+            acknowledged: vec![(12, acknowledged.clone())],
+            commitments: HashMap::from([
+                (
+                    sent.commitment_path(),
+                    sent.commitment()
+                        .try_into()
+                        .expect("packet commitment must be 32 bytes"),
+                ),
+                (acknowledged.ack_commitment_path(), [4; 32]),
+            ]),
+        };
+
+        let signer = LocalSigner::new(PrivateKeySigner::random());
+        let store = AttestationStore::new(10);
+
+        let sink = BroadcastSink::new(4);
+        let mut published = sink.subscribe();
+        let publisher = Publisher::spawn(vec![SinkEnum::Broadcast(sink)]);
+
+        scan_range(
+            &["watched".to_string()],
+            &adapter,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &signer,
+            &Policy::default(),
+            &publisher,
+            &store,
+            10,
+            20,
+        )
+        .await
+        .expect("scan must succeed");
+
+        let stored = store
+            .get(&key("watched", 1, CommitmentType::Packet))
+            .expect("sent packet must be attested");
+        assert_eq!(stored.packet, sent);
+        assert_eq!(stored.attestation.height, 20);
+
+        let stored = store
+            .get(&key("watched", 7, CommitmentType::Ack))
+            .expect("acknowledged packet must be attested");
+        assert_eq!(stored.packet, acknowledged);
+
+        // Relayed packets and unwatched clients are skipped
+        assert_eq!(store.len(), 2);
+
+        // Pre-computed attestations are pushed to sinks as well
+        for _ in 0..2 {
+            assert_eq!(published.recv().await.unwrap().height, 20);
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_range_skips_packets_denied_by_policy() {
+        let sent = test_packet(1, "watched", "remote");
+        let adapter = TestAdapter {
+            sent: vec![(10, sent.clone())],
+            acknowledged: Vec::new(),
+            commitments: HashMap::from([(
+                sent.commitment_path(),
+                sent.commitment()
+                    .try_into()
+                    .expect("packet commitment must be 32 bytes"),
+            )]),
+        };
+        let policy = Policy::from(PolicyConfig {
+            allowed_port_pairs: vec![PortPair {
+                source_port: "gmpport".to_string(),
+                dest_port: "gmpport".to_string(),
+            }],
+            ..PolicyConfig::default()
+        });
+
+        let store = AttestationStore::new(10);
+        let sink = BroadcastSink::new(4);
+        let mut published = sink.subscribe();
+        let publisher = Publisher::spawn(vec![SinkEnum::Broadcast(sink)]);
+
+        scan_range(
+            &["watched".to_string()],
+            &adapter,
+            &SigningMode::Tagged(SigningScheme::V1),
+            &LocalSigner::new(PrivateKeySigner::random()),
+            &policy,
+            &publisher,
+            &store,
+            10,
+            20,
+        )
+        .await
+        .expect("denied packets must not fail the scan");
+
+        assert_eq!(store.len(), 0);
+        assert!(published.try_recv().is_err());
+    }
+
+    /// Adapter without event scans, as on Solana
+    struct UnsupportedAdapter;
+
+    #[async_trait::async_trait]
+    impl AttestationAdapter for UnsupportedAdapter {
+        async fn get_last_height_at_configured_finality(
+            &self,
+        ) -> Result<u64, AttestationAdapterError> {
+            Ok(100)
+        }
+
+        async fn get_block_timestamp(&self, _height: u64) -> Result<u64, AttestationAdapterError> {
+            Ok(1_700_000_000)
+        }
+
+        async fn get_commitment(
+            &self,
+            _client_id: String,
+            _height: u64,
+            _sequence: u64,
+            _commitment_path: &[u8],
+            _commitment_type: CommitmentType,
+        ) -> Result<Option<[u8; 32]>, AttestationAdapterError> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn watcher_stops_if_scans_are_unsupported() {
+        let config = WatcherConfig {
+            client_ids: vec!["watched".to_string()],
+            start_height: None,
+            max_blocks_per_scan: default_max_blocks_per_scan(),
+            store_capacity: default_store_capacity(),
+        };
+        // The head stays open, so only giving up stops the watcher
+        let (_head_sender, head) = watch::channel(Some(100));
+
+        let watcher = watch_finalized_blocks(
+            config,
+            Arc::new(UnsupportedAdapter),
+            SigningMode::Tagged(SigningScheme::V1),
+            Arc::new(LocalSigner::new(PrivateKeySigner::random())),
+            Arc::new(Policy::default()),
+            Publisher::default(),
+            head,
+            Arc::new(AttestationStore::new(10)),
+        );
+        tokio::time::timeout(std::time::Duration::from_secs(5), watcher)
+            .await
+            .expect("watcher must stop instead of retrying");
+    }
+
+    #[test]
+    fn validate_rejects_empty_client_ids() {
+        let config = WatcherConfig {
+            client_ids: vec![],
+            start_height: None,
+            max_blocks_per_scan: default_max_blocks_per_scan(),
+            store_capacity: default_store_capacity(),
+        };
+        assert!(config.validate().is_err());
+    }
+}
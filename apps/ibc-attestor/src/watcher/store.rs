@@ -0,0 +1,175 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+
+use ibc_eureka_solidity_types::ics26::IICS26RouterMsgs::Packet;
+
+use crate::rpc::api::{Attestation, CommitmentType};
+
+/// Identifies a pre-computed packet attestation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StoreKey {
+    /// Source client ID for packet commitments, destination client ID for ack
+    /// commitments
+    pub client_id: String,
+    /// Packet sequence
+    pub sequence: u64,
+    /// Attested commitment type
+    pub commitment_type: CommitmentType,
+}
+
+/// A pre-computed packet attestation together with the attested packet.
+#[derive(Clone, Debug)]
+pub struct StoredAttestation {
+    /// The attested packet
+    pub packet: Packet,
+    /// The signed packet attestation
+    pub attestation: Attestation,
+}
+
+/// Bounded in-memory store of pre-computed packet attestations.
+///
+/// Once full, the oldest entries are evicted first.
+#[derive(Debug)]
+pub struct AttestationStore {
+    capacity: usize,
+    inner: RwLock<StoreInner>,
+}
+
+#[derive(Debug, Default)]
+struct StoreInner {
+    entries: HashMap<StoreKey, StoredAttestation>,
+    order: VecDeque<StoreKey>,
+}
+
+impl AttestationStore {
+    /// Create an empty store holding at most `capacity` attestations.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: RwLock::new(StoreInner::default()),
+        }
+    }
+
+    /// Insert an attestation, replacing any previous one for the same key.
+    ///
+    /// # Panics
+    /// Panics if the store lock was poisoned by a panicking writer.
+    pub fn insert(&self, key: StoreKey, value: StoredAttestation) {
+        let mut inner = self.inner.write().expect("attestation store lock poisoned");
+
+        if inner.entries.insert(key.clone(), value).is_some() {
+            return;
+        }
+        inner.order.push_back(key);
+
+        while inner.order.len() > self.capacity {
+            if let Some(evicted) = inner.order.pop_front() {
+                inner.entries.remove(&evicted);
+            }
+        }
+    }
+
+    /// Look up the attestation for `key`.
+    ///
+    /// # Panics
+    /// Panics if the store lock was poisoned by a panicking writer.
+    #[must_use]
+    pub fn get(&self, key: &StoreKey) -> Option<StoredAttestation> {
+        self.inner
+            .read()
+            .expect("attestation store lock poisoned")
+            .entries
+            .get(key)
+            .cloned()
+    }
+
+    /// Number of stored attestations.
+    ///
+    /// # Panics
+    /// Panics if the store lock was poisoned by a panicking writer.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .expect("attestation store lock poisoned")
+            .entries
+            .len()
+    }
+
+    /// Returns true if no attestations are stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(sequence: u64) -> StoreKey {
+        StoreKey {
+            client_id: "client-0".to_string(),
+            sequence,
+            commitment_type: CommitmentType::Packet,
+        }
+    }
+
+    fn stored(height: u64) -> StoredAttestation {
+        StoredAttestation {
+            packet: Packet {
+                sequence: 1,
+                sourceClient: "client-0".to_string(),
+                destClient: "client-1".to_string(),
+                timeoutTimestamp: 0,
+                payloads: vec![],
+            },
+            attestation: Attestation {
+                height,
+                timestamp: None,
+                attested_data: vec![],
+                signature: vec![],
+                signature_scheme: 0,
+            },
+        }
+    }
+
+    #[test]
+    fn get_returns_inserted_attestation() {
+        let store = AttestationStore::new(10);
+        store.insert(key(1), stored(100));
+
+        assert_eq!(store.get(&key(1)).unwrap().attestation.height, 100);
+        assert!(store.get(&key(2)).is_none());
+
+        let ack_key = StoreKey {
+            commitment_type: CommitmentType::Ack,
+            ..key(1)
+        };
+        assert!(store.get(&ack_key).is_none());
+    }
+
+    #[test]
+    fn insert_replaces_existing_entry() {
+        let store = AttestationStore::new(10);
+        store.insert(key(1), stored(100));
+        store.insert(key(1), stored(200));
+
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.get(&key(1)).unwrap().attestation.height, 200);
+    }
+
+    #[test]
+    fn insert_evicts_oldest_entries_when_full() {
+        let store = AttestationStore::new(2);
+        store.insert(key(1), stored(100));
+        store.insert(key(2), stored(100));
+        store.insert(key(3), stored(100));
+
+        assert_eq!(store.len(), 2);
+        assert!(store.get(&key(1)).is_none());
+        assert!(store.get(&key(2)).is_some());
+        assert!(store.get(&key(3)).is_some());
+    }
+}
@@ -0,0 +1,197 @@
+use alloy_primitives::{Address, B256};
+use alloy_sol_types::{Eip712Domain, SolStruct, SolValue};
+use sha2::{Digest, Sha256};
+
+use crate::recover::{recover_address, recover_address_from_prehash};
+
+/// Versioned scheme the attestor builds signing inputs with.
+///
+/// The signature always covers `sha256(signing_input)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SigningScheme {
+    /// 33-byte `type_tag || sha256(data)`
+    #[default]
+    V1,
+    /// 65-byte `type_tag || domain_separator || sha256(data)`, binding the
+    /// signature to a single chain and light client
+    V2 {
+        /// See [`domain_separator`]
+        domain_separator: B256,
+    },
+}
+
+impl SigningScheme {
+    /// V2 scheme bound to the attested chain and the counterparty light client.
+    pub fn v2(chain_id: &str, client_id: &str) -> Self {
+        Self::V2 {
+            domain_separator: domain_separator(chain_id, client_id),
+        }
+    }
+
+    /// Build the message signed for ABI-encoded `data` of the attestation type
+    /// `type_tag`.
+    pub fn signing_input(&self, type_tag: u8, data: &[u8]) -> Vec<u8> {
+        let inner_hash = Sha256::digest(data);
+        match self {
+            Self::V1 => {
+                let mut input = Vec::with_capacity(33);
+                input.push(type_tag);
+                input.extend_from_slice(&inner_hash);
+                input
+            }
+            Self::V2 { domain_separator } => {
+                let mut input = Vec::with_capacity(65);
+                input.push(type_tag);
+                input.extend_from_slice(domain_separator.as_slice());
+                input.extend_from_slice(&inner_hash);
+                input
+            }
+        }
+    }
+
+    /// Recover the attestor address from a 65-byte signature over `data`.
+    pub fn recover_signer(
+        &self,
+        type_tag: u8,
+        data: &[u8],
+        signature_65: &[u8],
+    ) -> Result<Address, anyhow::Error> {
+        recover_address(&self.signing_input(type_tag, data), signature_65)
+    }
+}
+
+/// Compute the V2 domain separator: `sha256(abi.encode(chainId, clientId))`.
+///
+/// `chain_id` identifies the attested chain and `client_id` the light client
+/// verifying the attestations on the counterparty.
+pub fn domain_separator(chain_id: &str, client_id: &str) -> B256 {
+    let encoded = (chain_id.to_string(), client_id.to_string()).abi_encode_params();
+    B256::from_slice(&Sha256::digest(encoded))
+}
+
+/// Recover the attestor address from a 65-byte signature over the EIP-712
+/// signing hash of `message` under `domain`.
+///
+/// Used for attestations signed in EIP-712 mode, where the typed data digest
+/// is signed directly instead of a [`SigningScheme`] signing input.
+pub fn recover_eip712_signer<T: SolStruct>(
+    message: &T,
+    domain: &Eip712Domain,
+    signature_65: &[u8],
+) -> Result<Address, anyhow::Error> {
+    recover_address_from_prehash(&message.eip712_signing_hash(domain), signature_65)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn sign(signer: &PrivateKeySigner, message: &[u8]) -> Vec<u8> {
+        let prehash = B256::from_slice(&Sha256::digest(message));
+        signer.sign_hash_sync(&prehash).unwrap().as_bytes().to_vec()
+    }
+
+    #[test]
+    fn v1_signing_input_is_tag_and_data_hash() {
+        let input = SigningScheme::V1.signing_input(0x01, b"data");
+        assert_eq!(input.len(), 33);
+        assert_eq!(input[0], 0x01);
+        assert_eq!(&input[1..], Sha256::digest(b"data").as_slice());
+    }
+
+    #[test]
+    fn v2_signing_input_embeds_domain_separator() {
+        let scheme = SigningScheme::v2("1", "client-0");
+        let input = scheme.signing_input(0x02, b"data");
+        assert_eq!(input.len(), 65);
+        assert_eq!(input[0], 0x02);
+        assert_eq!(&input[1..33], domain_separator("1", "client-0").as_slice());
+        assert_eq!(&input[33..], Sha256::digest(b"data").as_slice());
+    }
+
+    #[test]
+    fn domain_separator_matches_solidity_abi_encoding() {
+        // abi.encode("a", "b")
+        let encoded = hex::decode(concat!(
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000000000000000000000000000000000000000000080",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "6100000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "6200000000000000000000000000000000000000000000000000000000000000",
+        ))
+        .unwrap();
+        assert_eq!(
+            domain_separator("a", "b"),
+            B256::from_slice(&Sha256::digest(encoded))
+        );
+    }
+
+    #[test]
+    fn v2_signatures_do_not_replay_across_domains() {
+        let signer = PrivateKeySigner::random();
+        let chain_a = SigningScheme::v2("1", "client-0");
+        let chain_b = SigningScheme::v2("2", "client-0");
+        let signature = sign(&signer, &chain_a.signing_input(0x01, b"data"));
+
+        assert_eq!(
+            chain_a.recover_signer(0x01, b"data", &signature).unwrap(),
+            signer.address()
+        );
+        assert_ne!(
+            chain_b.recover_signer(0x01, b"data", &signature).unwrap(),
+            signer.address()
+        );
+        assert_ne!(
+            SigningScheme::V1
+                .recover_signer(0x01, b"data", &signature)
+                .unwrap(),
+            signer.address()
+        );
+    }
+
+    #[test]
+    fn eip712_signature_recovers_only_in_its_domain() {
+        alloy_sol_types::sol! {
+            struct StateAttestation {
+                uint64 height;
+                uint64 timestamp;
+            }
+        }
+
+        let signer = PrivateKeySigner::random();
+        let message = StateAttestation {
+            height: 100,
+            timestamp: 1_700_000_000,
+        };
+        let domain = alloy_sol_types::eip712_domain! {
+            name: "ibc-attestor",
+            version: "1",
+            chain_id: 1,
+            verifying_contract: address!("0x0000000000000000000000000000000000000001"),
+        };
+        let signature = signer
+            .sign_hash_sync(&message.eip712_signing_hash(&domain))
+            .unwrap()
+            .as_bytes();
+
+        assert_eq!(
+            recover_eip712_signer(&message, &domain, &signature).unwrap(),
+            signer.address()
+        );
+
+        let other_domain = alloy_sol_types::eip712_domain! {
+            name: "ibc-attestor",
+            version: "1",
+            chain_id: 2,
+            verifying_contract: address!("0x0000000000000000000000000000000000000001"),
+        };
+        assert_ne!(
+            recover_eip712_signer(&message, &other_domain, &signature).unwrap(),
+            signer.address()
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod attestation;
 pub mod recover;
 
 #[cfg(feature = "signer")]